@@ -0,0 +1,82 @@
+#![deny(missing_docs)]
+
+//! A macro for naming number literals in English at compile time.
+//!
+//! # Example
+//!
+//! ```
+//! use num2english_macros::english;
+//! const TOTAL: &str = english!(1234);
+//! assert_eq!(TOTAL, "one thousand two hundred thirty-four");
+//! ```
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Lit, LitStr, Token,
+};
+
+/// A number literal with an optional leading minus sign.
+struct NumberLiteral {
+    negative: bool,
+    literal: Lit,
+}
+
+impl Parse for NumberLiteral {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let negative = input.parse::<Option<Token![-]>>()?.is_some();
+        let literal = input.parse()?;
+        Ok(NumberLiteral { negative, literal })
+    }
+}
+
+/// Expand a number literal to its name in English as a `&'static str`.
+///
+/// The literal is read exactly as written, as by `num2english::convert_str`, so it may be an
+/// integer or a float with a leading minus sign, underscores or an exponent. Anything else is a
+/// compile error.
+///
+/// # Examples
+/// ```
+/// use num2english_macros::english;
+/// assert_eq!(english!(42), "forty-two");
+/// assert_eq!(english!(-1.50), "negative one and fifty hundredths");
+/// assert_eq!(english!(1_000_000u32), "one million");
+/// assert_eq!(english!(0xFF), "two hundred fifty-five");
+/// ```
+///
+/// ```compile_fail
+/// use num2english_macros::english;
+/// let words = english!("twelve");
+/// ```
+#[proc_macro]
+pub fn english(input: TokenStream) -> TokenStream {
+    let NumberLiteral { negative, literal } = parse_macro_input!(input as NumberLiteral);
+    let digits = match &literal {
+        Lit::Int(int) => int.base10_digits(),
+        Lit::Float(float) => float.base10_digits(),
+        _ => {
+            return syn::Error::new(literal.span(), "expected a number literal")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let mut number = String::new();
+    if negative {
+        number.push('-');
+    }
+    number.push_str(digits);
+    let words = match num2english::convert_str(&number, &num2english::Options::default()) {
+        Ok(words) => words,
+        Err(error) => {
+            return syn::Error::new(literal.span(), error)
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let words = LitStr::new(&words, literal.span());
+    quote!(#words).into()
+}
@@ -0,0 +1,307 @@
+use crate::{Gender, Language};
+use alloc::{borrow::Cow, format, string::String};
+use core::fmt::{self, Write};
+
+/// The names of the numbers from one to ten that count a masculine noun.
+const UNITS: [&str; 10] = [
+    "واحد",
+    "اثنان",
+    "ثلاثة",
+    "أربعة",
+    "خمسة",
+    "ستة",
+    "سبعة",
+    "ثمانية",
+    "تسعة",
+    "عشرة",
+];
+
+/// The names of the numbers from one to ten that count a feminine noun.
+const FEMININE_UNITS: [&str; 10] = [
+    "واحدة",
+    "اثنتان",
+    "ثلاث",
+    "أربع",
+    "خمس",
+    "ست",
+    "سبع",
+    "ثماني",
+    "تسع",
+    "عشر",
+];
+
+/// The names of the numbers from eleven to nineteen that count a masculine noun.
+const TEENS: [&str; 9] = [
+    "أحد عشر",
+    "اثنا عشر",
+    "ثلاثة عشر",
+    "أربعة عشر",
+    "خمسة عشر",
+    "ستة عشر",
+    "سبعة عشر",
+    "ثمانية عشر",
+    "تسعة عشر",
+];
+
+/// The names of the numbers from eleven to nineteen that count a feminine noun.
+const FEMININE_TEENS: [&str; 9] = [
+    "إحدى عشرة",
+    "اثنتا عشرة",
+    "ثلاث عشرة",
+    "أربع عشرة",
+    "خمس عشرة",
+    "ست عشرة",
+    "سبع عشرة",
+    "ثماني عشرة",
+    "تسع عشرة",
+];
+
+/// The names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "عشرة",
+    "عشرون",
+    "ثلاثون",
+    "أربعون",
+    "خمسون",
+    "ستون",
+    "سبعون",
+    "ثمانون",
+    "تسعون",
+];
+
+/// The names of the hundreds from one hundred to nine hundred.
+const HUNDREDS: [&str; 9] = [
+    "مائة",
+    "مائتان",
+    "ثلاثمائة",
+    "أربعمائة",
+    "خمسمائة",
+    "ستمائة",
+    "سبعمائة",
+    "ثمانمائة",
+    "تسعمائة",
+];
+
+/// The singular, dual and plural names of each power of one thousand, in the short scale.
+const MAGNITUDES: [[&str; 3]; 11] = [
+    ["ألف", "ألفان", "آلاف"],
+    ["مليون", "مليونان", "ملايين"],
+    ["مليار", "ملياران", "مليارات"],
+    ["تريليون", "تريليونان", "تريليونات"],
+    ["كوادريليون", "كوادريليونان", "كوادريليونات"],
+    ["كوينتليون", "كوينتليونان", "كوينتليونات"],
+    ["سكستليون", "سكستليونان", "سكستليونات"],
+    ["سبتليون", "سبتليونان", "سبتليونات"],
+    ["أوكتليون", "أوكتليونان", "أوكتليونات"],
+    ["نونليون", "نونليونان", "نونليونات"],
+    ["ديسليون", "ديسليونان", "ديسليونات"],
+];
+
+/// Numbers in Arabic (e.g. 123 -> "مائة وثلاثة وعشرون").
+///
+/// The parts of a number are joined with "و", with the units before the tens. One and two agree
+/// with the [`gender`] of the noun being counted, and three to ten take the opposite gender
+/// (e.g. "ثلاثة كتب", "ثلاث سيارات"). Two thousands, millions and above are named in the dual
+/// (e.g. "ألفان"), three to ten in the plural (e.g. "ثلاثة آلاف"), and eleven to ninety-nine
+/// in the accusative singular (e.g. "أحد عشر ألفًا"). Numbers are written in the nominative case.
+///
+/// [`gender`]: #structfield.gender
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Arabic, Gender};
+/// let arabic = Arabic::default();
+/// assert_eq!(number_to_words(&123, &arabic), "مائة وثلاثة وعشرون");
+/// assert_eq!(number_to_words(&2_000, &arabic), "ألفان");
+/// assert_eq!(number_to_words(&5_000_000, &arabic), "خمسة ملايين");
+///
+/// let feminine = Arabic {
+///     gender: Gender::Feminine,
+/// };
+/// assert_eq!(number_to_words(&3, &feminine), "ثلاث");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Arabic {
+    /// The gender of the noun being counted. A neuter noun is counted as a masculine one.
+    pub gender: Gender,
+}
+
+impl Arabic {
+    /// Write a number from one to nine hundred ninety-nine, counting a noun of a gender. A final
+    /// "مائتان" is written "مائتا" if `construct`, as before a noun (e.g. "مائتا ألف").
+    fn write_hundreds_as<W: Write>(
+        &self,
+        out: &mut W,
+        number: u64,
+        feminine: bool,
+        construct: bool,
+    ) -> fmt::Result {
+        let hundreds = number / 100;
+        let rest = number % 100;
+        if hundreds > 0 {
+            match HUNDREDS[hundreds as usize - 1] {
+                "مائتان" if construct && rest == 0 => out.write_str("مائتا")?,
+                name => out.write_str(name)?,
+            }
+            if rest > 0 {
+                out.write_str(" و")?;
+            }
+        }
+
+        let (units, teens) = if feminine {
+            (&FEMININE_UNITS, &FEMININE_TEENS)
+        } else {
+            (&UNITS, &TEENS)
+        };
+        match rest {
+            0 => Ok(()),
+            1..=10 => out.write_str(units[rest as usize - 1]),
+            11..=19 => out.write_str(teens[rest as usize - 11]),
+            _ => {
+                // the units come before the tens (e.g. "ثلاثة وعشرون")
+                match rest % 10 {
+                    0 => {}
+                    1 if feminine => out.write_str("إحدى و")?,
+                    unit => write!(out, "{} و", units[unit as usize - 1])?,
+                }
+                out.write_str(TENS[rest as usize / 10 - 1])
+            }
+        }
+    }
+}
+
+impl Language for Arabic {
+    fn zero(&self) -> &str {
+        "صفر"
+    }
+
+    fn negative(&self) -> &str {
+        "سالب"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "فاصلة"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "مائة"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str> {
+        let Some([singular, dual, plural]) = MAGNITUDES.get(magnitude - 1) else {
+            // past the named powers, the largest name is counted (e.g. "ألف ديسليون")
+            let inner = self.magnitude_name(magnitude - MAGNITUDES.len(), group);
+            return Cow::Owned(format!("{} {}", inner, MAGNITUDES[MAGNITUDES.len() - 1][0]));
+        };
+        match (group, group % 100) {
+            (1, _) => Cow::Borrowed(singular),
+            (2, _) => Cow::Borrowed(dual),
+            (_, 3..=10) => Cow::Borrowed(plural),
+            (_, 11..=99) => {
+                let mut name = String::from(*singular);
+                name.push_str("ًا");
+                Cow::Owned(name)
+            }
+            _ => Cow::Borrowed(singular),
+        }
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        self.write_hundreds_as(out, number, self.gender == Gender::Feminine, false)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        if magnitude == 0 {
+            return self.write_hundreds(out, group as u64);
+        }
+        let name = self.magnitude_name(magnitude, group);
+        let hundreds = group / 100 * 100;
+        match group % 100 {
+            // one and two are named by the noun alone (e.g. "ألف", "ألفان", "مائة وألف")
+            1 | 2 if group > 100 => {
+                self.write_hundreds_as(out, hundreds as u64, false, false)?;
+                let name = self.magnitude_name(magnitude, group % 100);
+                write!(out, " و{}", name)
+            }
+            _ if group <= 2 => out.write_str(&name),
+            // "ألف" and above are masculine nouns (e.g. "ثلاثة آلاف")
+            _ => {
+                self.write_hundreds_as(out, group as u64, false, true)?;
+                write!(out, " {}", name)
+            }
+        }
+    }
+
+    fn write_group_separator<W: Write>(
+        &self,
+        out: &mut W,
+        _above: usize,
+        _magnitude: usize,
+        _group: u128,
+    ) -> fmt::Result {
+        out.write_str(" و")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Arabic, Gender};
+
+    #[test]
+    fn test_arabic() {
+        let arabic = Arabic::default();
+        for (number, words) in [
+            (0_i64, "صفر"),
+            (1, "واحد"),
+            (2, "اثنان"),
+            (3, "ثلاثة"),
+            (10, "عشرة"),
+            (11, "أحد عشر"),
+            (12, "اثنا عشر"),
+            (15, "خمسة عشر"),
+            (21, "واحد وعشرون"),
+            (23, "ثلاثة وعشرون"),
+            (100, "مائة"),
+            (123, "مائة وثلاثة وعشرون"),
+            (200, "مائتان"),
+            (1_000, "ألف"),
+            (1_001, "ألف وواحد"),
+            (2_000, "ألفان"),
+            (3_000, "ثلاثة آلاف"),
+            (11_000, "أحد عشر ألفًا"),
+            (100_000, "مائة ألف"),
+            (101_000, "مائة وألف"),
+            (200_000, "مائتا ألف"),
+            (1_000_000, "مليون"),
+            (2_000_000, "مليونان"),
+            (5_000_000, "خمسة ملايين"),
+            (2_000_000_000, "ملياران"),
+            (-4, "سالب أربعة"),
+        ] {
+            assert_eq!(number_to_words(&number, &arabic), words);
+        }
+        assert_eq!(number_to_words(&1.5, &arabic), "واحد فاصلة خمسة");
+
+        let feminine = Arabic {
+            gender: Gender::Feminine,
+        };
+        for (number, words) in [
+            (1_i64, "واحدة"),
+            (2, "اثنتان"),
+            (3, "ثلاث"),
+            (12, "اثنتا عشرة"),
+            (21, "إحدى وعشرون"),
+            (3_003, "ثلاثة آلاف وثلاث"),
+        ] {
+            assert_eq!(number_to_words(&number, &feminine), words);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{NumberToEnglish, Options};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display, Write};
+use num_traits::Num;
+
+/// Convert each number in a slice to English using the given [`Options`].
+///
+/// Every name is built in one scratch buffer that is reused across the slice, and each result
+/// is allocated once at its final size.
+///
+/// # Examples
+/// ```
+/// use num2english::{to_english_batch, Options};
+/// assert_eq!(
+///     to_english_batch(&[1, 20, 300], &Options::default()),
+///     ["one", "twenty", "three hundred"]
+/// );
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub fn to_english_batch<T: Num + Display>(numbers: &[T], options: &Options) -> Vec<String> {
+    let mut scratch = String::new();
+    let mut result = Vec::with_capacity(numbers.len());
+    for number in numbers {
+        scratch.clear();
+        number.to_english_into(&mut scratch, options);
+        result.push(String::from(scratch.as_str()));
+    }
+    result
+}
+
+/// Write each number in a slice to a [`fmt::Write`] sink in English using the given [`Options`],
+/// with a separator between them.
+///
+/// # Errors
+/// Returns an error if the sink fails to accept the words.
+///
+/// # Examples
+/// ```
+/// use num2english::{write_english_batch, Options};
+/// let mut column = String::new();
+/// write_english_batch(&mut column, &[7, 8, 9], "\n", &Options::default()).unwrap();
+/// assert_eq!(column, "seven\neight\nnine");
+/// ```
+///
+/// [`fmt::Write`]: https://doc.rust-lang.org/core/fmt/trait.Write.html
+/// [`Options`]: struct.Options.html
+pub fn write_english_batch<T: Num + Display, W: Write>(
+    out: &mut W,
+    numbers: &[T],
+    separator: &str,
+    options: &Options,
+) -> fmt::Result {
+    for (i, number) in numbers.iter().enumerate() {
+        if i > 0 {
+            out.write_str(separator)?;
+        }
+        number.write_english(out, options)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{to_english_batch, write_english_batch, Case, NumberToEnglish, Options};
+    use alloc::{string::String, vec::Vec};
+
+    #[test]
+    fn test_batch() {
+        let options = Options::default();
+        let empty: [u8; 0] = [];
+        assert!(to_english_batch(&empty, &options).is_empty());
+
+        let numbers = [0.5, -2.0, 1e21, 12.75];
+        let expected = numbers
+            .iter()
+            .map(|n| n.to_english())
+            .collect::<Vec<String>>();
+        assert_eq!(to_english_batch(&numbers, &options), expected);
+
+        let title = Options {
+            case: Case::Title,
+            ..Default::default()
+        };
+        let mut out = String::from("Amounts: ");
+        write_english_batch(&mut out, &[1_000_u32, 15], ", ", &title).unwrap();
+        assert_eq!(out, "Amounts: One Thousand, Fifteen");
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{convert_non_finite_to_english, convert_number_to_english, decimal::Decimal, Options};
+use alloc::string::String;
+use core::fmt::Write;
+use num_bigfloat::BigFloat;
+
+/// Convert a [`BigFloat`] to English using the given [`Options`].
+///
+/// The number is read from its exact mantissa and exponent rather than its `Display` output,
+/// so no digits are lost to formatting, and trailing zeros of the mantissa are dropped.
+///
+/// # Examples
+/// ```
+/// use num2english::{bigfloat_to_english, Options};
+/// use num_bigfloat::BigFloat;
+/// let number = BigFloat::parse("1234.5678").unwrap();
+/// assert_eq!(
+///     bigfloat_to_english(&number, &Options::default()),
+///     "one thousand two hundred thirty-four and five thousand six hundred seventy-eight ten-thousandths"
+/// );
+/// assert_eq!(
+///     bigfloat_to_english(&BigFloat::parse("1e-30").unwrap(), &Options::default()),
+///     "one nonillionth"
+/// );
+/// ```
+///
+/// [`BigFloat`]: https://docs.rs/num-bigfloat/latest/num_bigfloat/struct.BigFloat.html
+pub fn bigfloat_to_english(number: &BigFloat, options: &Options) -> String {
+    let Some(decimal) = bigfloat_to_decimal(number) else {
+        let name = if number.is_nan() {
+            "NaN"
+        } else if number.is_inf_neg() {
+            "-inf"
+        } else {
+            "inf"
+        };
+        let result = convert_non_finite_to_english(name, options).unwrap_or_default();
+        return options.case.apply(&result);
+    };
+
+    options.case.apply(&convert_number_to_english(
+        decimal.to_plain_string(),
+        options,
+    ))
+}
+
+/// Read the exact value of a finite [`BigFloat`] from its raw parts.
+fn bigfloat_to_decimal(number: &BigFloat) -> Option<Decimal> {
+    let (mantissa, _, sign, exponent) = number.to_raw_parts()?;
+
+    // the mantissa is stored as base 10000 parts, least significant first
+    let mut digits = String::with_capacity(mantissa.len() * 4);
+    for part in mantissa.iter().rev() {
+        let _ = write!(digits, "{:04}", part);
+    }
+
+    let mut decimal = Decimal {
+        negative: sign < 0,
+        digits,
+        exponent: exponent as i64,
+    };
+    decimal.normalize();
+    Some(decimal)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bigfloat_to_english, Case, Options};
+    use num_bigfloat::BigFloat;
+
+    #[test]
+    fn test_bigfloat() {
+        let english = |s| bigfloat_to_english(&BigFloat::parse(s).unwrap(), &Options::default());
+        assert_eq!(english("0"), "zero");
+        assert_eq!(english("-2"), "negative two");
+        assert_eq!(english("0.5"), "five tenths");
+        assert_eq!(english("1e21"), "one sextillion");
+        assert_eq!(english("1.5e-3"), "fifteen ten-thousandths");
+        assert_eq!(english("-1.5e-3"), "negative fifteen ten-thousandths");
+        assert_eq!(english("-2.25"), "negative two and twenty-five hundredths");
+        assert_eq!(
+            english("123456789012345678901234567890.5"),
+            "one hundred twenty-three octillion four hundred fifty-six septillion seven hundred \
+             eighty-nine sextillion twelve quintillion three hundred forty-five quadrillion six \
+             hundred seventy-eight trillion nine hundred one billion two hundred thirty-four \
+             million five hundred sixty-seven thousand eight hundred ninety and five tenths"
+        );
+
+        assert_eq!(
+            bigfloat_to_english(&BigFloat::from(0.25), &Options::default()),
+            "twenty-five hundredths"
+        );
+        assert_eq!(
+            bigfloat_to_english(&num_bigfloat::NAN, &Options::default()),
+            "not a number"
+        );
+        let upper = Options {
+            case: Case::Upper,
+            ..Default::default()
+        };
+        assert_eq!(
+            bigfloat_to_english(&num_bigfloat::INF_NEG, &upper),
+            "NEGATIVE INFINITY"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! Print the English names of numbers given as arguments, or read one per line from standard input.
+
+use num2english::{
+    convert_str, convert_str_currency, Currency, DecimalStyle, NumberToEnglish, Options,
+};
+use num_bigint::BigInt;
+use std::{
+    env,
+    io::{self, BufRead, Write},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+Usage: num2english [OPTIONS] [NUMBER]...
+
+Print the name of each NUMBER in English. With no NUMBER, read one number per line from standard input.
+
+Options:
+      --ordinal         read integers as ordinals (e.g. \"forty-second\")
+      --currency CODE   read amounts of money in USD, EUR or GBP
+      --british         join groups with \"and\" (e.g. \"one hundred and five\")
+      --point           read decimals digit by digit (e.g. \"one point two five\")
+      --mixed           read decimals as fractions in lowest terms (e.g. \"one and a quarter\")
+  -h, --help            print this help
+";
+
+/// How each number is read.
+enum Mode {
+    Cardinal,
+    Ordinal,
+    Currency(Currency),
+}
+
+fn main() -> ExitCode {
+    let mut mode = Mode::Cardinal;
+    let mut options = Options::default();
+    let mut numbers = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            "--ordinal" => mode = Mode::Ordinal,
+            "--currency" => match args.next().as_deref().and_then(Currency::from_code) {
+                Some(currency) => mode = Mode::Currency(currency),
+                None => return usage_error("--currency takes USD, EUR or GBP"),
+            },
+            "--british" => options.british_and = true,
+            "--point" => options.decimal_style = DecimalStyle::Point,
+            "--mixed" => options.decimal_style = DecimalStyle::Mixed,
+            "--" => numbers.extend(args.by_ref()),
+            // negative numbers are not flags (e.g. "-5")
+            flag if flag.starts_with("--") => {
+                return usage_error(&format!("unknown option {}", flag));
+            }
+            _ => numbers.push(arg),
+        }
+    }
+
+    let mut stdout = io::stdout().lock();
+    let mut ok = true;
+    let mut print = |number: &str| match convert(number, &mode, &options) {
+        Ok(words) => writeln!(stdout, "{}", words).is_ok(),
+        Err(error) => {
+            eprintln!("num2english: {}: {}", error, number.trim());
+            ok = false;
+            true
+        }
+    };
+
+    if numbers.is_empty() {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                return ExitCode::FAILURE;
+            };
+            if !line.trim().is_empty() && !print(&line) {
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        for number in &numbers {
+            if !print(number) {
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Convert one number written as text to English.
+fn convert(number: &str, mode: &Mode, options: &Options) -> Result<String, String> {
+    let number = number.trim();
+    match mode {
+        Mode::Cardinal => convert_str(number, options).map_err(|error| error.to_string()),
+        Mode::Ordinal => number
+            .parse::<BigInt>()
+            .map_err(|_| String::from("invalid integer"))
+            .and_then(|number| {
+                number
+                    .to_english_ordinal_with(options)
+                    .map_err(|error| error.to_string())
+            }),
+        Mode::Currency(currency) => {
+            convert_str_currency(number, currency, options).map_err(|error| error.to_string())
+        }
+    }
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("num2english: {}\n\n{}", message, USAGE);
+    ExitCode::from(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert, Mode};
+    use num2english::{Currency, DecimalStyle, Options};
+
+    #[test]
+    fn test_convert() {
+        let options = Options::default();
+        assert_eq!(
+            convert(" 1,234 ", &Mode::Cardinal, &options),
+            Ok("one thousand two hundred thirty-four".into())
+        );
+        assert_eq!(
+            convert("twelve", &Mode::Cardinal, &options),
+            Err("invalid number".into())
+        );
+        assert_eq!(
+            convert("42", &Mode::Ordinal, &options),
+            Ok("forty-second".into())
+        );
+        assert!(convert("4.2", &Mode::Ordinal, &options).is_err());
+        assert_eq!(
+            convert("3.50", &Mode::Currency(Currency::EUR), &options),
+            Ok("three euros and fifty cents".into())
+        );
+        assert_eq!(
+            convert(
+                "12345678901234567.89",
+                &Mode::Currency(Currency::USD),
+                &options
+            ),
+            Ok(
+                "twelve quadrillion three hundred forty-five trillion six hundred seventy-eight \
+                billion nine hundred one million two hundred thirty-four thousand five hundred \
+                sixty-seven dollars and eighty-nine cents"
+                    .into()
+            )
+        );
+        assert_eq!(
+            convert("1,000", &Mode::Currency(Currency::USD), &options),
+            Ok("one thousand dollars".into())
+        );
+
+        let point = Options {
+            decimal_style: DecimalStyle::Point,
+            british_and: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert("105.25", &Mode::Cardinal, &point),
+            Ok("one hundred and five point two five".into())
+        );
+        assert_eq!(
+            convert("105", &Mode::Ordinal, &point),
+            Ok("one hundred and fifth".into())
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{convert_number_to_english, round_decimal_places, DecimalStyle, Options, Rounding};
+use alloc::string::{String, ToString};
+
+/// How a data size is read.
+///
+/// # Examples
+/// ```
+/// use num2english::{bytes_to_english, ByteStyle};
+/// let binary = ByteStyle {
+///     binary: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(bytes_to_english(3_221_225_472, &binary), "three gibibytes");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ByteStyle {
+    /// Use powers of 1024 with binary prefixes (e.g. "kibibyte") instead of powers of 1000.
+    pub binary: bool,
+    /// The largest number of decimal places read, dropping any trailing zeros.
+    pub precision: u32,
+}
+
+impl Default for ByteStyle {
+    fn default() -> Self {
+        ByteStyle {
+            binary: false,
+            precision: 1,
+        }
+    }
+}
+
+/// The decimal prefixes for each power of one thousand.
+const DECIMAL_PREFIXES: [&str; 11] = [
+    "", "kilo", "mega", "giga", "tera", "peta", "exa", "zetta", "yotta", "ronna", "quetta",
+];
+
+/// The binary prefixes for each power of 1024.
+const BINARY_PREFIXES: [&str; 9] = [
+    "", "kibi", "mebi", "gibi", "tebi", "pebi", "exbi", "zebi", "yobi",
+];
+
+/// Read a number of bytes with the largest prefix that keeps the number at one or more.
+///
+/// The number is rounded half up to the style's precision and read in point style.
+///
+/// # Examples
+/// ```
+/// use num2english::{bytes_to_english, ByteStyle};
+/// assert_eq!(
+///     bytes_to_english(3_221_225_472, &ByteStyle::default()),
+///     "three point two gigabytes"
+/// );
+/// assert_eq!(bytes_to_english(1, &ByteStyle::default()), "one byte");
+/// ```
+pub fn bytes_to_english(bytes: u128, style: &ByteStyle) -> String {
+    let (base, prefixes) = if style.binary {
+        (1024, &BINARY_PREFIXES[..])
+    } else {
+        (1000, &DECIMAL_PREFIXES[..])
+    };
+
+    let mut index = 0;
+    let mut unit: u128 = 1;
+    while index + 1 < prefixes.len() && bytes / unit >= base {
+        unit *= base;
+        index += 1;
+    }
+
+    let places = style.precision as usize;
+    let mut number = round_quotient(bytes, unit, places);
+    // rounding up can reach the next prefix (e.g. 999,999 bytes -> "one megabyte")
+    let integer = number.split('.').next().unwrap_or_default();
+    if integer.parse::<u128>().is_ok_and(|n| n >= base) && index + 1 < prefixes.len() {
+        unit *= base;
+        index += 1;
+        number = round_quotient(bytes, unit, places);
+    }
+
+    let options = Options {
+        decimal_style: DecimalStyle::Point,
+        ..Default::default()
+    };
+    let mut result = convert_number_to_english(number.clone(), &options);
+    result.push(' ');
+    result.push_str(prefixes[index]);
+    result.push_str(if number == "1" { "byte" } else { "bytes" });
+    result
+}
+
+/// Divide by a unit and round half up to a number of decimal places, dropping any trailing zeros
+/// (e.g. 1050, 1000, 1 -> "1.1")
+fn round_quotient(dividend: u128, divisor: u128, places: usize) -> String {
+    let mut number = (dividend / divisor).to_string();
+    let mut remainder = dividend % divisor;
+    // one digit past the places is enough to round half up
+    if remainder != 0 {
+        number.push('.');
+    }
+    for _ in 0..=places {
+        if remainder == 0 {
+            break;
+        }
+        // the divisor is at most 1000^10, so ten times the remainder still fits
+        remainder *= 10;
+        number.push(char::from(b'0' + (remainder / divisor) as u8));
+        remainder %= divisor;
+    }
+    round_decimal_places(&number, places, Rounding::HalfUp)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bytes_to_english, ByteStyle};
+
+    #[test]
+    fn test_bytes() {
+        let style = ByteStyle::default();
+        assert_eq!(bytes_to_english(0, &style), "zero bytes");
+        assert_eq!(bytes_to_english(1, &style), "one byte");
+        assert_eq!(
+            bytes_to_english(999, &style),
+            "nine hundred ninety-nine bytes"
+        );
+        assert_eq!(bytes_to_english(1_000, &style), "one kilobyte");
+        assert_eq!(bytes_to_english(1_050, &style), "one point one kilobytes");
+        assert_eq!(bytes_to_english(999_999, &style), "one megabyte");
+        assert_eq!(
+            bytes_to_english(3_221_225_472, &style),
+            "three point two gigabytes"
+        );
+
+        let binary = ByteStyle {
+            binary: true,
+            precision: 2,
+        };
+        assert_eq!(bytes_to_english(1_024, &binary), "one kibibyte");
+        assert_eq!(bytes_to_english(1_536, &binary), "one point five kibibytes");
+        assert_eq!(bytes_to_english(3_221_225_472, &binary), "three gibibytes");
+        assert_eq!(
+            bytes_to_english(1_000_000, &binary),
+            "nine hundred seventy-six point five six kibibytes"
+        );
+
+        let whole = ByteStyle {
+            precision: 0,
+            ..Default::default()
+        };
+        assert_eq!(bytes_to_english(1_500_000, &whole), "two megabytes");
+    }
+}
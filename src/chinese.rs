@@ -0,0 +1,221 @@
+use crate::Language;
+use alloc::{borrow::Cow, format};
+use core::fmt::{self, Write};
+
+/// The standard names of the numbers from one to nine.
+const UNITS: [&str; 9] = ["一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+/// The standard names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "十", "二十", "三十", "四十", "五十", "六十", "七十", "八十", "九十",
+];
+
+/// The financial (大写) names of the numbers from one to nine.
+const FINANCIAL_UNITS: [&str; 9] = ["壹", "贰", "叁", "肆", "伍", "陆", "柒", "捌", "玖"];
+
+/// The financial (大写) names of the tens from ten to ninety.
+const FINANCIAL_TENS: [&str; 9] = [
+    "壹拾", "贰拾", "叁拾", "肆拾", "伍拾", "陆拾", "柒拾", "捌拾", "玖拾",
+];
+
+/// The names of each power of ten thousand.
+const MAGNITUDES: [&str; 12] = [
+    "万", "亿", "兆", "京", "垓", "秭", "穰", "沟", "涧", "正", "载", "极",
+];
+
+/// Numbers in Mandarin Chinese (e.g. 105 -> "一百零五").
+///
+/// Numbers are grouped by four digits, in powers of ten thousand (万, 亿 and above). A run of
+/// zeros inside a number is read as one "零", and trailing zeros are not read (e.g. 1005 ->
+/// "一千零五", 1500 -> "一千五百", 100005 -> "十万零五"). A leading ten is read without "一"
+/// (e.g. "十五", but "一百一十五").
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Chinese};
+/// assert_eq!(number_to_words(&105, &Chinese::default()), "一百零五");
+/// assert_eq!(number_to_words(&100_000_005, &Chinese::default()), "一亿零五");
+///
+/// let financial = Chinese {
+///     financial: true,
+/// };
+/// assert_eq!(number_to_words(&1_234, &financial), "壹仟贰佰叁拾肆");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Chinese {
+    /// Write the financial (大写) numerals used on cheques, which cannot be altered by adding
+    /// strokes (e.g. "壹佰零伍" instead of "一百零五"). A ten is always counted (e.g. "壹拾伍").
+    pub financial: bool,
+}
+
+impl Chinese {
+    /// The names of the thousands, hundreds and tens digits.
+    fn digit_names(&self) -> [&'static str; 3] {
+        if self.financial {
+            ["仟", "佰", "拾"]
+        } else {
+            ["千", "百", "十"]
+        }
+    }
+}
+
+impl Language for Chinese {
+    fn zero(&self) -> &str {
+        "零"
+    }
+
+    fn negative(&self) -> &str {
+        "负"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "点"
+    }
+
+    fn units(&self) -> &[&str] {
+        if self.financial {
+            &FINANCIAL_UNITS
+        } else {
+            &UNITS
+        }
+    }
+
+    fn tens(&self) -> &[&str] {
+        if self.financial {
+            &FINANCIAL_TENS
+        } else {
+            &TENS
+        }
+    }
+
+    fn hundred(&self) -> &str {
+        self.digit_names()[1]
+    }
+
+    fn magnitude_name(&self, magnitude: usize, _group: u128) -> Cow<'_, str> {
+        match MAGNITUDES.get(magnitude - 1) {
+            Some(name) => Cow::Borrowed(name),
+            // past the named powers, the largest name is counted (e.g. "万极")
+            None => {
+                let inner = self.magnitude_name(magnitude - MAGNITUDES.len(), 1);
+                Cow::Owned(format!("{}{}", inner, MAGNITUDES[MAGNITUDES.len() - 1]))
+            }
+        }
+    }
+
+    fn group_digits(&self, _magnitude: usize) -> u32 {
+        4
+    }
+
+    fn word_separator(&self) -> &str {
+        ""
+    }
+
+    fn tens_separator(&self) -> &str {
+        ""
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        self.write_group(out, number as u128, 0)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        let number = group as u64;
+        let names = self.digit_names();
+        let mut started = false;
+        let mut zero = false;
+        for (digit, name) in [
+            (number / 1000, names[0]),
+            (number / 100 % 10, names[1]),
+            (number / 10 % 10, names[2]),
+            (number % 10, ""),
+        ] {
+            if digit == 0 {
+                zero |= started;
+                continue;
+            }
+            if zero {
+                out.write_str("零")?;
+                zero = false;
+            }
+            // a leading ten is not counted, but one after the first group is, in the separator
+            if digit == 1 && name == "十" && !started {
+                out.write_str(name)?;
+            } else {
+                out.write_str(self.units()[digit as usize - 1])?;
+                out.write_str(name)?;
+            }
+            started = true;
+        }
+        if magnitude > 0 {
+            out.write_str(&self.magnitude_name(magnitude, group))?;
+        }
+        Ok(())
+    }
+
+    fn write_group_separator<W: Write>(
+        &self,
+        out: &mut W,
+        above: usize,
+        magnitude: usize,
+        group: u128,
+    ) -> fmt::Result {
+        // a group after a skipped group or digit starts with "零" (e.g. "一万零五", "一亿零一万")
+        if group < 1000 || above > magnitude + 1 {
+            out.write_str("零")?;
+        }
+        if !self.financial && (10..=19).contains(&group) {
+            out.write_str("一")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Chinese};
+
+    #[test]
+    fn test_chinese() {
+        let chinese = Chinese::default();
+        for (number, words) in [
+            (0_i64, "零"),
+            (10, "十"),
+            (15, "十五"),
+            (20, "二十"),
+            (105, "一百零五"),
+            (110, "一百一十"),
+            (1_005, "一千零五"),
+            (1_050, "一千零五十"),
+            (1_500, "一千五百"),
+            (10_000, "一万"),
+            (10_005, "一万零五"),
+            (10_015, "一万零一十五"),
+            (10_500, "一万零五百"),
+            (15_000, "一万五千"),
+            (100_000, "十万"),
+            (10_001_000, "一千万一千"),
+            (100_000_000, "一亿"),
+            (100_000_005, "一亿零五"),
+            (100_010_000, "一亿零一万"),
+            (123_456_789, "一亿二千三百四十五万六千七百八十九"),
+            (-5, "负五"),
+        ] {
+            assert_eq!(number_to_words(&number, &chinese), words);
+        }
+        assert_eq!(number_to_words(&2.5, &chinese), "二点五");
+
+        let financial = Chinese { financial: true };
+        for (number, words) in [
+            (10_i64, "壹拾"),
+            (105, "壹佰零伍"),
+            (1_234, "壹仟贰佰叁拾肆"),
+            (10_005, "壹万零伍"),
+            (10_015, "壹万零壹拾伍"),
+            (100_000, "壹拾万"),
+            (300_000_000, "叁亿"),
+        ] {
+            assert_eq!(number_to_words(&number, &financial), words);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{convert_number_to_english, expand_scientific, Options};
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+
+/// How a complex number is read.
+///
+/// # Examples
+/// ```
+/// use num2english::{complex_to_english, ComplexStyle};
+/// let engineering = ComplexStyle {
+///     imaginary_unit: "j",
+///     ..Default::default()
+/// };
+/// assert_eq!(complex_to_english(&3, &4, &engineering), "three plus four j");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ComplexStyle {
+    /// The name of the imaginary unit (e.g. "i", "j" or "times i").
+    pub imaginary_unit: &'static str,
+    /// The word joining a positive imaginary part (e.g. "plus").
+    pub plus_word: &'static str,
+    /// The word joining a negative imaginary part (e.g. "minus").
+    pub minus_word: &'static str,
+    /// The options used to read both parts.
+    pub options: Options,
+}
+
+impl Default for ComplexStyle {
+    fn default() -> Self {
+        ComplexStyle {
+            imaginary_unit: "i",
+            plus_word: "plus",
+            minus_word: "minus",
+            options: Options::default(),
+        }
+    }
+}
+
+/// Read a complex number from its real and imaginary parts, such as those of a `num_complex::Complex`.
+///
+/// A part that is zero is left out. An imaginary part of one is read as the unit alone when the
+/// unit is a single word (e.g. "i"), but is kept before a unit of several words (e.g. "one times i").
+///
+/// # Examples
+/// ```
+/// use num2english::{complex_to_english, ComplexStyle};
+/// let style = ComplexStyle::default();
+/// assert_eq!(complex_to_english(&3, &4, &style), "three plus four i");
+/// assert_eq!(complex_to_english(&3, &-4, &style), "three minus four i");
+/// assert_eq!(complex_to_english(&0, &-1, &style), "negative i");
+/// assert_eq!(complex_to_english(&2.5, &0.0, &style), "two and five tenths");
+/// ```
+///
+/// A `Complex` can be converted from its parts:
+/// ```ignore
+/// let z = num_complex::Complex::new(1, 2);
+/// assert_eq!(complex_to_english(&z.re, &z.im, &ComplexStyle::default()), "one plus two i");
+/// ```
+pub fn complex_to_english<T: Display>(real: &T, imaginary: &T, style: &ComplexStyle) -> String {
+    let options = &style.options;
+    let real = expand_scientific(real.to_string());
+    let imaginary = expand_scientific(imaginary.to_string());
+    let (negative, imaginary) = match imaginary.strip_prefix('-') {
+        Some(rest) => (true, String::from(rest)),
+        None => (false, imaginary),
+    };
+
+    let real_words = convert_number_to_english(real, options);
+    let imaginary_words = convert_number_to_english(imaginary, options);
+
+    if imaginary_words == options.zero_word {
+        return options.case.apply(&real_words);
+    }
+
+    let mut result = String::new();
+    let has_real = real_words != options.zero_word;
+
+    if has_real {
+        result.push_str(&real_words);
+        result.push(' ');
+        result.push_str(if negative {
+            style.minus_word
+        } else {
+            style.plus_word
+        });
+        result.push(' ');
+    } else if negative {
+        result.push_str(options.negative_word);
+        result.push(' ');
+    }
+
+    // a bare unit stands for one of itself, but "times i" still needs its count
+    let bare_unit = !style.imaginary_unit.contains(char::is_whitespace);
+    if !(bare_unit && imaginary_words == "one") {
+        result.push_str(&imaginary_words);
+        result.push(' ');
+    }
+    result.push_str(style.imaginary_unit);
+    options.case.apply(&result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{complex_to_english, Case, ComplexStyle, Options};
+
+    #[test]
+    fn test_complex() {
+        let style = ComplexStyle::default();
+        assert_eq!(complex_to_english(&0, &0, &style), "zero");
+        assert_eq!(complex_to_english(&-3, &0, &style), "negative three");
+        assert_eq!(complex_to_english(&0, &4, &style), "four i");
+        assert_eq!(complex_to_english(&1, &1, &style), "one plus i");
+        assert_eq!(
+            complex_to_english(&-1.5, &-2.0, &style),
+            "negative one and five tenths minus two i"
+        );
+
+        let spoken = ComplexStyle {
+            imaginary_unit: "times i",
+            plus_word: "and",
+            options: Options {
+                case: Case::Sentence,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            complex_to_english(&5, &12, &spoken),
+            "Five and twelve times i"
+        );
+        assert_eq!(complex_to_english(&3, &1, &spoken), "Three and one times i");
+        assert_eq!(complex_to_english(&0, &-1, &spoken), "Negative one times i");
+    }
+}
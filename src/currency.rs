@@ -0,0 +1,306 @@
+use crate::{
+    convert_integer_to_english, convert_non_finite_to_english, power_of_ten, round_decimal_places,
+    Options, Rounding,
+};
+use alloc::string::String;
+
+/// The names of a currency's major and minor units.
+///
+/// # Examples
+/// ```
+/// use num2english::{Currency, NumberToEnglish};
+/// assert_eq!(
+///     1234.56.to_english_currency(&Currency::USD),
+///     "one thousand two hundred thirty-four dollars and fifty-six cents"
+/// );
+/// assert_eq!(1.01.to_english_currency(&Currency::GBP), "one pound and one penny");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Currency {
+    /// The singular name of the major unit (e.g. "dollar").
+    pub major_singular: &'static str,
+    /// The plural name of the major unit (e.g. "dollars").
+    pub major_plural: &'static str,
+    /// The singular name of the minor unit (e.g. "cent").
+    pub minor_singular: &'static str,
+    /// The plural name of the minor unit (e.g. "cents").
+    pub minor_plural: &'static str,
+    /// The number of decimal places held by the minor unit (e.g. 2 for cents).
+    pub minor_digits: u32,
+}
+
+impl Currency {
+    /// United States dollars and cents.
+    pub const USD: Currency = Currency {
+        major_singular: "dollar",
+        major_plural: "dollars",
+        minor_singular: "cent",
+        minor_plural: "cents",
+        minor_digits: 2,
+    };
+
+    /// Euros and cents.
+    pub const EUR: Currency = Currency {
+        major_singular: "euro",
+        major_plural: "euros",
+        minor_singular: "cent",
+        minor_plural: "cents",
+        minor_digits: 2,
+    };
+
+    /// British pounds and pence.
+    pub const GBP: Currency = Currency {
+        major_singular: "pound",
+        major_plural: "pounds",
+        minor_singular: "penny",
+        minor_plural: "pence",
+        minor_digits: 2,
+    };
+
+    /// Find a built-in currency by its ISO 4217 code, ignoring case ("USD", "EUR" or "GBP").
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::Currency;
+    /// assert_eq!(Currency::from_code("eur"), Some(Currency::EUR));
+    /// assert_eq!(Currency::from_code("XYZ"), None);
+    /// ```
+    pub fn from_code(code: &str) -> Option<Currency> {
+        match code.to_ascii_uppercase().as_str() {
+            "USD" => Some(Currency::USD),
+            "EUR" => Some(Currency::EUR),
+            "GBP" => Some(Currency::GBP),
+            _ => None,
+        }
+    }
+}
+
+/// An amount of money split into the digits of its major and minor units.
+pub(crate) struct Amount {
+    pub(crate) negative: bool,
+    pub(crate) major: String,
+    pub(crate) minor: String,
+}
+
+/// Round a number string to the minor unit of a currency, rounding half away from zero.
+pub(crate) fn split_amount(number: &str, minor_digits: u32) -> Amount {
+    let unsigned = number.trim_start_matches('-');
+    let rounded = round_decimal_places(unsigned, minor_digits as usize, Rounding::HalfUp);
+    let (integer, decimal) = rounded.split_once('.').unwrap_or((&rounded, ""));
+
+    // parts that aren't written in digits are read as zero
+    let digits = |part: &str| match part.trim_start_matches('0') {
+        trimmed if !trimmed.is_empty() && part.bytes().all(|b| b.is_ascii_digit()) => {
+            String::from(trimmed)
+        }
+        _ => String::from("0"),
+    };
+    let major = digits(integer);
+    let mut minor = String::from(decimal);
+    for _ in decimal.len()..minor_digits as usize {
+        minor.push('0');
+    }
+    let minor = digits(&minor);
+
+    Amount {
+        negative: number.starts_with('-') && (major != "0" || minor != "0"),
+        major,
+        minor,
+    }
+}
+
+/// Name an amount that isn't finite, which has no minor unit to round to (e.g. "not a number",
+/// "infinity dollars")
+fn convert_non_finite_amount(
+    number: &str,
+    currency: &Currency,
+    options: &Options,
+) -> Option<String> {
+    let mut result = convert_non_finite_to_english(number, options)?;
+    if result.ends_with("infinity") {
+        result.push(' ');
+        result.push_str(currency.major_plural);
+    }
+    Some(result)
+}
+
+/// Convert a number to an amount of money in English (e.g. 1.5 -> "one dollar and fifty cents")
+pub(crate) fn convert_currency_to_english(
+    number: &str,
+    currency: &Currency,
+    options: &Options,
+) -> String {
+    if let Some(special) = convert_non_finite_amount(number, currency, options) {
+        return special;
+    }
+    let Amount {
+        negative,
+        major,
+        minor,
+    } = split_amount(number, currency.minor_digits);
+
+    let mut result = String::new();
+    if negative {
+        result.push_str(options.negative_word);
+        result.push(' ');
+    }
+
+    let has_minor = minor != "0";
+    let has_major = major != "0";
+
+    if has_major || !has_minor {
+        push_units(
+            &mut result,
+            &major,
+            currency.major_singular,
+            currency.major_plural,
+            options,
+        );
+    }
+
+    if has_minor {
+        if has_major {
+            result.push_str(" and ");
+        }
+        push_units(
+            &mut result,
+            &minor,
+            currency.minor_singular,
+            currency.minor_plural,
+            options,
+        );
+    }
+
+    result
+}
+
+/// Convert a number to the amount line of a check (e.g. 1234.56 -> "One thousand two hundred thirty-four and 56/100 Dollars")
+pub(crate) fn convert_check_to_english(
+    number: &str,
+    currency: &Currency,
+    options: &Options,
+) -> String {
+    let mut result = match convert_non_finite_amount(number, currency, options) {
+        Some(special) => special,
+        None => convert_check_amount(number, currency, options),
+    };
+
+    // the first letter of the amount line is capitalized
+    if let Some(first) = result.get(..1) {
+        let first = first.to_ascii_uppercase();
+        result.replace_range(..1, &first);
+    }
+    result
+}
+
+/// Convert a finite number to the amount line of a check, before it is capitalized.
+fn convert_check_amount(number: &str, currency: &Currency, options: &Options) -> String {
+    let Amount {
+        negative,
+        major,
+        minor,
+    } = split_amount(number, currency.minor_digits);
+
+    let mut result = String::new();
+    if negative {
+        result.push_str(options.negative_word);
+        result.push(' ');
+    }
+
+    if major == "0" {
+        result.push_str(options.zero_word);
+    } else {
+        result.push_str(&convert_integer_to_english(&major, options));
+    }
+
+    result.push_str(" and ");
+    for _ in minor.len()..currency.minor_digits as usize {
+        result.push('0');
+    }
+    result.push_str(&minor);
+    result.push('/');
+    result.push_str(&power_of_ten(currency.minor_digits as usize));
+
+    result.push(' ');
+    let mut unit = currency.major_plural.chars();
+    if let Some(first) = unit.next() {
+        result.extend(first.to_uppercase());
+        result.push_str(unit.as_str());
+    }
+
+    result
+}
+
+/// Push a count followed by its singular or plural unit name (e.g. "one dollar", "zero dollars")
+fn push_units(result: &mut String, count: &str, singular: &str, plural: &str, options: &Options) {
+    let unit = if count == "1" { singular } else { plural };
+
+    if count == "0" {
+        result.push_str(options.zero_word);
+    } else {
+        result.push_str(&convert_integer_to_english(count, options));
+    }
+    result.push(' ');
+    result.push_str(unit);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, NumberToEnglish};
+
+    #[test]
+    fn test_check() {
+        assert_eq!(
+            1234.56.to_english_check(&Currency::USD),
+            "One thousand two hundred thirty-four and 56/100 Dollars"
+        );
+        assert_eq!(1.to_english_check(&Currency::USD), "One and 00/100 Dollars");
+        assert_eq!(
+            0.07.to_english_check(&Currency::USD),
+            "Zero and 07/100 Dollars"
+        );
+        assert_eq!(
+            19.999.to_english_check(&Currency::USD),
+            "Twenty and 00/100 Dollars"
+        );
+        assert_eq!(
+            250.5.to_english_check(&Currency::GBP),
+            "Two hundred fifty and 50/100 Pounds"
+        );
+        assert_eq!(f64::NAN.to_english_check(&Currency::USD), "Not a number");
+    }
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(Currency::from_code("USD"), Some(Currency::USD));
+        assert_eq!(Currency::from_code("gbp"), Some(Currency::GBP));
+        assert_eq!(Currency::from_code("JPY"), None);
+        assert_eq!(Currency::from_code(""), None);
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            1234.56.to_english_currency(&Currency::USD),
+            "one thousand two hundred thirty-four dollars and fifty-six cents"
+        );
+        assert_eq!(1.to_english_currency(&Currency::USD), "one dollar");
+        assert_eq!(
+            1.01.to_english_currency(&Currency::USD),
+            "one dollar and one cent"
+        );
+        assert_eq!(0.5.to_english_currency(&Currency::USD), "fifty cents");
+        assert_eq!(0.to_english_currency(&Currency::USD), "zero dollars");
+        assert_eq!(2.999.to_english_currency(&Currency::EUR), "three euros");
+        assert_eq!(0.004.to_english_currency(&Currency::USD), "zero dollars");
+        assert_eq!(
+            (-0.25).to_english_currency(&Currency::GBP),
+            "negative twenty-five pence"
+        );
+        assert_eq!(f64::NAN.to_english_currency(&Currency::USD), "not a number");
+        assert_eq!(
+            f64::NEG_INFINITY.to_english_currency(&Currency::EUR),
+            "negative infinity euros"
+        );
+    }
+}
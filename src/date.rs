@@ -0,0 +1,120 @@
+use crate::{convert_hundreds_to_english, ordinal_words, year, Error, Options};
+use alloc::{format, string::String};
+
+/// The order of the day and month in a date.
+///
+/// # Examples
+/// ```
+/// use num2english::{date_to_english, DateOrder};
+/// assert_eq!(
+///     date_to_english(1976, 7, 4, DateOrder::MonthDay),
+///     Ok("July fourth, nineteen seventy-six".to_string())
+/// );
+/// assert_eq!(
+///     date_to_english(1976, 7, 4, DateOrder::DayMonth),
+///     Ok("the fourth of July, nineteen seventy-six".to_string())
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum DateOrder {
+    /// The month before the day, as is usual in the US (e.g. "July fourth").
+    #[default]
+    MonthDay,
+    /// The day before the month, as is usual in the UK (e.g. "the fourth of July").
+    DayMonth,
+}
+
+/// The names of the months of the year.
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Read a date from its year, month (1 to 12) and day of the month.
+///
+/// The year is read as a year (e.g. "nineteen seventy-six") and the day as an ordinal.
+/// Values from a date library can be passed in directly, such as `date.year()`, `date.month()`
+/// and `date.day()` from `chrono`.
+///
+/// # Examples
+/// ```
+/// use num2english::{date_to_english, DateOrder, Error};
+/// assert_eq!(
+///     date_to_english(2005, 12, 31, DateOrder::MonthDay),
+///     Ok("December thirty-first, two thousand five".to_string())
+/// );
+/// assert_eq!(date_to_english(2023, 2, 29, DateOrder::MonthDay), Err(Error::InvalidDate));
+/// ```
+pub fn date_to_english(year: i32, month: u32, day: u32, order: DateOrder) -> Result<String, Error> {
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(Error::InvalidDate);
+    }
+
+    let options = Options::default();
+    let month_name = MONTHS[month as usize - 1];
+    let day_name = ordinal_words(&convert_hundreds_to_english(u64::from(day), &options));
+    let year_name = year::convert_year_to_english(format!("{}", year), &options);
+
+    Ok(match order {
+        DateOrder::MonthDay => format!("{} {}, {}", month_name, day_name, year_name),
+        DateOrder::DayMonth => format!("the {} of {}, {}", day_name, month_name, year_name),
+    })
+}
+
+/// The number of days in a month of the Gregorian calendar.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{date_to_english, DateOrder, Error};
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_date() {
+        assert_eq!(
+            date_to_english(1976, 7, 4, DateOrder::MonthDay),
+            Ok("July fourth, nineteen seventy-six".to_string())
+        );
+        assert_eq!(
+            date_to_english(1976, 7, 4, DateOrder::DayMonth),
+            Ok("the fourth of July, nineteen seventy-six".to_string())
+        );
+        assert_eq!(
+            date_to_english(2000, 2, 29, DateOrder::MonthDay),
+            Ok("February twenty-ninth, two thousand".to_string())
+        );
+        assert_eq!(
+            date_to_english(1999, 11, 22, DateOrder::DayMonth),
+            Ok("the twenty-second of November, nineteen ninety-nine".to_string())
+        );
+        assert_eq!(
+            date_to_english(1900, 2, 29, DateOrder::MonthDay),
+            Err(Error::InvalidDate)
+        );
+        assert_eq!(
+            date_to_english(2024, 13, 1, DateOrder::MonthDay),
+            Err(Error::InvalidDate)
+        );
+        assert_eq!(
+            date_to_english(2024, 4, 31, DateOrder::MonthDay),
+            Err(Error::InvalidDate)
+        );
+    }
+}
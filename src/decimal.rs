@@ -0,0 +1,263 @@
+use alloc::{string::String, vec::Vec};
+
+/// The largest power of ten, above or below one, that a number is written out to in positional
+/// notation. Past it, writing and naming every digit would take far longer than the input
+/// suggests (e.g. "1e1000000").
+pub(crate) const MAX_EXPANDED_POWER: i64 = 10_000;
+
+/// An exact decimal number, written as a string of digits scaled by a power of ten.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct Decimal {
+    /// Whether the number is below zero.
+    pub(crate) negative: bool,
+    /// The significant digits of the number, without leading zeros ("0" for zero).
+    pub(crate) digits: String,
+    /// The power of ten the digits are multiplied by.
+    pub(crate) exponent: i64,
+}
+
+impl Decimal {
+    /// Parse a decimal number with an optional sign, decimal point and exponent (e.g. "-1.5e3").
+    pub(crate) fn parse(number: &str) -> Option<Decimal> {
+        let number = number.trim();
+        let (negative, number) = match number.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, number.strip_prefix('+').unwrap_or(number)),
+        };
+
+        let (mantissa, exponent) = match number.find(['e', 'E']) {
+            Some(i) => (&number[..i], number[i + 1..].parse::<i64>().ok()?),
+            None => (number, 0),
+        };
+
+        let (integer, fraction) = match mantissa.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (mantissa, ""),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        if !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let mut digits = String::with_capacity(integer.len() + fraction.len());
+        digits.push_str(integer);
+        digits.push_str(fraction);
+        let mut decimal = Decimal {
+            negative,
+            digits,
+            exponent: exponent.checked_sub(fraction.len() as i64)?,
+        };
+        decimal.normalize();
+        Some(decimal)
+    }
+
+    /// Remove leading zeros, and trailing zeros into the exponent.
+    pub(crate) fn normalize(&mut self) {
+        let trimmed = self.digits.trim_start_matches('0');
+        if trimmed.is_empty() {
+            self.digits = String::from("0");
+            self.exponent = 0;
+            self.negative = false;
+            return;
+        }
+        let significant = trimmed.trim_end_matches('0');
+        self.exponent = self
+            .exponent
+            .saturating_add((trimmed.len() - significant.len()) as i64);
+        self.digits = String::from(significant);
+    }
+
+    /// Whether the number is zero.
+    pub(crate) fn is_zero(&self) -> bool {
+        self.digits == "0"
+    }
+
+    /// The power of ten of the leading digit (e.g. 3 for 1234, -2 for 0.05).
+    pub(crate) fn leading_power(&self) -> i64 {
+        (self.digits.len() as i64 - 1).saturating_add(self.exponent)
+    }
+
+    /// Whether the number can be written in positional notation within [`MAX_EXPANDED_POWER`]
+    /// digits of the decimal point.
+    pub(crate) fn is_expandable(&self) -> bool {
+        self.leading_power() <= MAX_EXPANDED_POWER && self.exponent >= -MAX_EXPANDED_POWER
+    }
+
+    /// Multiply the number by a power of ten.
+    pub(crate) fn shift(&self, places: i64) -> Decimal {
+        let mut result = self.clone();
+        if !result.is_zero() {
+            result.exponent += places;
+        }
+        result
+    }
+
+    /// Round the number to a number of significant digits, rounding half away from zero.
+    pub(crate) fn round_significant(&self, significant: usize) -> Decimal {
+        let significant = significant.max(1);
+        if self.digits.len() <= significant {
+            return self.clone();
+        }
+
+        let round_up = self.digits.as_bytes()[significant] >= b'5';
+        let mut result = Decimal {
+            negative: self.negative,
+            digits: String::from(&self.digits[..significant]),
+            exponent: self.exponent + (self.digits.len() - significant) as i64,
+        };
+        if round_up {
+            result.increment();
+        }
+        result.normalize();
+        result
+    }
+
+    /// Add one to the last digit, carrying into the digits before it.
+    fn increment(&mut self) {
+        self.digits = increment_digits(&self.digits);
+    }
+
+    /// Write the number in plain positional notation (e.g. "-1500", "0.05").
+    pub(crate) fn to_plain_string(&self) -> String {
+        let mut result = String::new();
+        if self.negative {
+            result.push('-');
+        }
+
+        if self.exponent >= 0 {
+            result.push_str(&self.digits);
+            if !self.is_zero() {
+                for _ in 0..self.exponent {
+                    result.push('0');
+                }
+            }
+            return result;
+        }
+
+        let fraction_len = (-self.exponent) as usize;
+        if fraction_len >= self.digits.len() {
+            result.push_str("0.");
+            for _ in self.digits.len()..fraction_len {
+                result.push('0');
+            }
+            result.push_str(&self.digits);
+        } else {
+            let (integer, fraction) = self.digits.split_at(self.digits.len() - fraction_len);
+            result.push_str(integer);
+            result.push('.');
+            result.push_str(fraction);
+        }
+        result
+    }
+}
+
+/// Add one to a string of decimal digits (e.g. "199" -> "200").
+pub(crate) fn increment_digits(digits: &str) -> String {
+    let mut digits = String::from(digits).into_bytes();
+    for digit in digits.iter_mut().rev() {
+        if *digit == b'9' {
+            *digit = b'0';
+        } else {
+            *digit += 1;
+            return String::from_utf8(digits).unwrap_or_default();
+        }
+    }
+    digits.insert(0, b'1');
+    String::from_utf8(digits).unwrap_or_default()
+}
+
+/// Multiply a string of decimal digits by a small factor and add a small number to the product
+/// (e.g. "25", 4, 3 -> "103").
+pub(crate) fn multiply_add_digits(digits: &str, factor: u32, addend: u32) -> String {
+    let mut result = Vec::with_capacity(digits.len() + 10);
+    let mut carry = u64::from(addend);
+    for digit in digits.bytes().rev() {
+        let product = u64::from(digit - b'0') * u64::from(factor) + carry;
+        result.push(b'0' + (product % 10) as u8);
+        carry = product / 10;
+    }
+    while carry > 0 {
+        result.push(b'0' + (carry % 10) as u8);
+        carry /= 10;
+    }
+    while result.len() > 1 && result.last() == Some(&b'0') {
+        result.pop();
+    }
+    result.reverse();
+    String::from_utf8(result).unwrap_or_default()
+}
+
+/// Divide a string of decimal digits by a small divisor, returning the quotient without leading
+/// zeros and the remainder (e.g. "150", 4 -> ("37", 2)).
+pub(crate) fn divide_digits(digits: &str, divisor: u32) -> (String, u32) {
+    let mut quotient = String::with_capacity(digits.len());
+    let mut remainder = 0;
+    for digit in digits.bytes() {
+        let value = u64::from(remainder) * 10 + u64::from(digit - b'0');
+        let next = (value / u64::from(divisor)) as u8;
+        if next > 0 || !quotient.is_empty() {
+            quotient.push((b'0' + next) as char);
+        }
+        remainder = (value % u64::from(divisor)) as u32;
+    }
+    if quotient.is_empty() {
+        quotient.push('0');
+    }
+    (quotient, remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{divide_digits, increment_digits, multiply_add_digits, Decimal};
+    use alloc::string::String;
+
+    #[test]
+    fn test_decimal() {
+        let plain = |s| Decimal::parse(s).unwrap().to_plain_string();
+        assert_eq!(plain("1500"), "1500");
+        assert_eq!(plain("-0.050"), "-0.05");
+        assert_eq!(plain("1.5e3"), "1500");
+        assert_eq!(plain("6.02E23"), "602000000000000000000000");
+        assert_eq!(plain("4.7e-8"), "0.000000047");
+        assert_eq!(plain("-0"), "0");
+        assert_eq!(plain(".5"), "0.5");
+        assert_eq!(Decimal::parse("1.2.3"), None);
+        assert_eq!(Decimal::parse("abc"), None);
+        assert_eq!(Decimal::parse(""), None);
+
+        let number = Decimal::parse("3200").unwrap();
+        assert_eq!(number.leading_power(), 3);
+        assert_eq!(number.shift(-3).to_plain_string(), "3.2");
+        assert_eq!(Decimal::parse("0.002").unwrap().leading_power(), -3);
+        assert!(Decimal::parse("1.5e10000").unwrap().is_expandable());
+        assert!(!Decimal::parse("1e10001").unwrap().is_expandable());
+        assert!(!Decimal::parse("1e-10001").unwrap().is_expandable());
+
+        let round = |s, n| {
+            Decimal::parse(s)
+                .unwrap()
+                .round_significant(n)
+                .to_plain_string()
+        };
+        assert_eq!(round("1234567", 2), "1200000");
+        assert_eq!(round("1250000", 2), "1300000");
+        assert_eq!(round("-9960", 2), "-10000");
+        assert_eq!(round("0.0012345", 3), "0.00123");
+        assert_eq!(round("15", 5), "15");
+
+        assert_eq!(increment_digits("199"), "200");
+        assert_eq!(increment_digits("999"), "1000");
+        assert_eq!(multiply_add_digits("25", 4, 0), "100");
+        assert_eq!(multiply_add_digits("25", 4, 3), "103");
+        assert_eq!(multiply_add_digits("0", 16, 15), "15");
+        assert_eq!(multiply_add_digits("0", 7, 0), "0");
+        assert_eq!(divide_digits("150", 4), (String::from("37"), 2));
+        assert_eq!(divide_digits("3", 5), (String::from("0"), 3));
+    }
+}
@@ -0,0 +1,219 @@
+#[cfg(feature = "bigint")]
+use crate::convert_integer_to_english;
+use crate::{digit_to_english, Options};
+use alloc::string::String;
+#[cfg(feature = "bigint")]
+use alloc::string::ToString;
+#[cfg(feature = "bigint")]
+use num_bigint::{BigInt, Sign};
+
+/// How the digits of a number in another base are read.
+///
+/// # Examples
+/// ```
+/// use num2english::{radix_digits_to_english, Options, RadixStyle};
+/// let phonetic = RadixStyle {
+///     letter_names: &["alpha", "bravo", "charlie", "delta", "echo", "foxtrot"],
+///     ..RadixStyle::HEXADECIMAL
+/// };
+/// assert_eq!(
+///     radix_digits_to_english(0xC0FFEE, &phonetic, &Options::default()),
+///     "charlie zero foxtrot foxtrot echo echo, hexadecimal"
+/// );
+/// ```
+#[cfg(feature = "bigint")]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RadixStyle {
+    /// The base the number is written in, from 2 to 36.
+    pub radix: u32,
+    /// The names of the digits from ten upwards (e.g. "alpha" for A), or empty to read them as capital letters.
+    pub letter_names: &'static [&'static str],
+    /// Follow the digits with the name of the base (e.g. "one zero, binary").
+    pub name_base: bool,
+}
+
+#[cfg(feature = "bigint")]
+impl RadixStyle {
+    /// Base 2.
+    pub const BINARY: RadixStyle = RadixStyle {
+        radix: 2,
+        letter_names: &[],
+        name_base: true,
+    };
+    /// Base 8.
+    pub const OCTAL: RadixStyle = RadixStyle {
+        radix: 8,
+        letter_names: &[],
+        name_base: true,
+    };
+    /// Base 16, with digits above nine read as capital letters.
+    pub const HEXADECIMAL: RadixStyle = RadixStyle {
+        radix: 16,
+        letter_names: &[],
+        name_base: true,
+    };
+}
+
+#[cfg(feature = "bigint")]
+impl Default for RadixStyle {
+    fn default() -> Self {
+        RadixStyle::HEXADECIMAL
+    }
+}
+
+/// Read each digit of a number string on its own, keeping any leading zeros.
+///
+/// A leading "-" is read with the negative word and a "." as "point". Any other characters, such
+/// as spaces or separators, are skipped.
+///
+/// # Examples
+/// ```
+/// use num2english::{digits_to_english, Options};
+/// assert_eq!(digits_to_english("404", &Options::default()), "four zero four");
+/// assert_eq!(digits_to_english("007", &Options::default()), "zero zero seven");
+/// assert_eq!(digits_to_english("3.14", &Options::default()), "three point one four");
+/// ```
+pub fn digits_to_english(digits: &str, options: &Options) -> String {
+    options
+        .case
+        .apply(&convert_digit_string_to_english(digits, options))
+}
+
+/// Read each digit of a number string on its own (e.g. "404" -> "four zero four")
+pub(crate) fn convert_digit_string_to_english(digits: &str, options: &Options) -> String {
+    let mut result = String::new();
+
+    for (i, c) in digits.chars().enumerate() {
+        let word = match c {
+            '0'..='9' => digit_to_english(c as u8 - b'0', options),
+            '-' if i == 0 => options.negative_word,
+            '.' => "point",
+            _ => continue,
+        };
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        result.push_str(word);
+    }
+
+    result
+}
+
+/// Read each digit of an integer written in another base.
+///
+/// Digits above nine are read with the style's letter names. A radix outside 2 to 36 is clamped to that range.
+///
+/// # Examples
+/// ```
+/// use num2english::{radix_digits_to_english, Options, RadixStyle};
+/// let options = Options::default();
+/// assert_eq!(
+///     radix_digits_to_english(13, &RadixStyle::BINARY, &options),
+///     "one one zero one, binary"
+/// );
+/// assert_eq!(
+///     radix_digits_to_english(0x2F, &RadixStyle::HEXADECIMAL, &options),
+///     "two F, hexadecimal"
+/// );
+/// ```
+#[cfg(feature = "bigint")]
+pub fn radix_digits_to_english(
+    number: impl Into<BigInt>,
+    style: &RadixStyle,
+    options: &Options,
+) -> String {
+    let number = number.into();
+    let radix = style.radix.clamp(2, 36);
+
+    let mut result = String::new();
+    if let Sign::Minus = number.sign() {
+        result.push_str(options.negative_word);
+    }
+    for c in number.magnitude().to_str_radix(radix).chars() {
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        match c.to_digit(radix) {
+            Some(digit) if digit < 10 => result.push_str(digit_to_english(digit as u8, options)),
+            Some(digit) => match style.letter_names.get(digit as usize - 10) {
+                Some(name) => result.push_str(name),
+                None => result.push(c.to_ascii_uppercase()),
+            },
+            None => {}
+        }
+    }
+
+    if style.name_base {
+        result.push_str(", ");
+        match radix {
+            2 => result.push_str("binary"),
+            8 => result.push_str("octal"),
+            10 => result.push_str("decimal"),
+            16 => result.push_str("hexadecimal"),
+            _ => {
+                result.push_str("base ");
+                result.push_str(&convert_integer_to_english(&radix.to_string(), options));
+            }
+        }
+    }
+
+    options.case.apply(&result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{digits_to_english, NumberToEnglish, Options};
+    #[cfg(feature = "bigint")]
+    use crate::{radix_digits_to_english, RadixStyle};
+
+    #[test]
+    fn test_digits() {
+        let options = Options::default();
+        assert_eq!(404.to_english_digits(&options), "four zero four");
+        assert_eq!(3.25.to_english_digits(&options), "three point two five");
+        assert_eq!((-12).to_english_digits(&options), "negative one two");
+        assert_eq!(digits_to_english("0042", &options), "zero zero four two");
+        assert_eq!(digits_to_english("12 34", &options), "one two three four");
+        let oh = Options {
+            zero_word: "oh",
+            ..Default::default()
+        };
+        assert_eq!(digits_to_english("101", &oh), "one oh one");
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_radix_digits() {
+        let options = Options::default();
+        assert_eq!(
+            radix_digits_to_english(0, &RadixStyle::BINARY, &options),
+            "zero, binary"
+        );
+        assert_eq!(
+            radix_digits_to_english(-493, &RadixStyle::OCTAL, &options),
+            "negative seven five five, octal"
+        );
+        let base36 = RadixStyle {
+            radix: 36,
+            name_base: false,
+            ..Default::default()
+        };
+        assert_eq!(radix_digits_to_english(71, &base36, &options), "one Z");
+        let ternary = RadixStyle {
+            radix: 3,
+            ..Default::default()
+        };
+        assert_eq!(
+            radix_digits_to_english(5, &ternary, &options),
+            "one two, base three"
+        );
+        let oh = Options {
+            zero_word: "oh",
+            ..Default::default()
+        };
+        assert_eq!(
+            radix_digits_to_english(0xA0, &RadixStyle::HEXADECIMAL, &oh),
+            "A oh, hexadecimal"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{NumberToEnglish, Options};
+use core::fmt::{self, Alignment, Display, Write};
+use num_traits::Num;
+
+/// Display a number as its name in English.
+///
+/// The words are written straight to the formatter rather than built into a `String` first.
+/// Width, fill and alignment flags are honored as they are for strings, and a precision
+/// truncates the words.
+///
+/// # Examples
+/// ```
+/// use num2english::InEnglish;
+/// assert_eq!(format!("{} items", InEnglish(42)), "forty-two items");
+/// assert_eq!(format!("[{:>12}]", InEnglish(7)), "[       seven]");
+/// assert_eq!(format!("[{:*^9}]", InEnglish(3)), "[**three**]");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct InEnglish<T>(pub T);
+
+impl<T: Num + Display> Display for InEnglish<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = Options::default();
+        if f.precision().is_some() {
+            return f.pad(&self.0.to_english());
+        }
+        let Some(width) = f.width() else {
+            return self.0.write_english(f, &options);
+        };
+
+        let mut counter = CharCounter(0);
+        self.0.write_english(&mut counter, &options)?;
+        let padding = width.saturating_sub(counter.0);
+        let (before, after) = match f.align() {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (0, padding),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        self.0.write_english(f, &options)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// A sink that counts the characters written to it.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::InEnglish;
+    use alloc::format;
+
+    #[test]
+    fn test_in_english() {
+        assert_eq!(format!("{}", InEnglish(0)), "zero");
+        assert_eq!(
+            format!("{}", InEnglish(-1.5)),
+            "negative one and five tenths"
+        );
+        assert_eq!(format!("{:<6}|", InEnglish(1)), "one   |");
+        assert_eq!(format!("{:6}|", InEnglish(2)), "two   |");
+        assert_eq!(format!("{:-^8}", InEnglish(10)), "--ten---");
+        assert_eq!(format!("{:2}", InEnglish(11)), "eleven");
+        assert_eq!(format!("{:.5}", InEnglish(17)), "seven");
+        assert_eq!(format!("{:>7.3}", InEnglish(20)), "    twe");
+    }
+}
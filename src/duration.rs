@@ -0,0 +1,151 @@
+use crate::{convert_number_to_english, DecimalStyle, Options};
+use alloc::{format, string::String, vec::Vec};
+use core::time::Duration;
+
+/// How a duration is read.
+///
+/// # Examples
+/// ```
+/// use core::time::Duration;
+/// use num2english::{duration_to_english, DurationStyle};
+/// let style = DurationStyle {
+///     skip_zero: false,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     duration_to_english(Duration::from_secs(7_205), &style),
+///     "two hours, zero minutes and five seconds"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DurationStyle {
+    /// Leave out units with a count of zero between other units (e.g. "two hours and five seconds"
+    /// instead of "two hours, zero minutes and five seconds").
+    pub skip_zero: bool,
+    /// The number of decimal places read for the seconds, dropping any trailing zeros.
+    pub subsecond_digits: u32,
+}
+
+impl Default for DurationStyle {
+    fn default() -> Self {
+        DurationStyle {
+            skip_zero: true,
+            subsecond_digits: 0,
+        }
+    }
+}
+
+/// The length of each unit of a duration in seconds, from largest to smallest.
+const UNITS: [(u64, &str, &str); 4] = [
+    (86_400, "day", "days"),
+    (3_600, "hour", "hours"),
+    (60, "minute", "minutes"),
+    (1, "second", "seconds"),
+];
+
+/// Read a duration in days, hours, minutes and seconds.
+///
+/// # Examples
+/// ```
+/// use core::time::Duration;
+/// use num2english::{duration_to_english, DurationStyle};
+/// assert_eq!(
+///     duration_to_english(Duration::from_secs(7_385), &DurationStyle::default()),
+///     "two hours, three minutes and five seconds"
+/// );
+/// let precise = DurationStyle {
+///     subsecond_digits: 2,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     duration_to_english(Duration::from_millis(1_250), &precise),
+///     "one point two five seconds"
+/// );
+/// ```
+pub fn duration_to_english(duration: Duration, style: &DurationStyle) -> String {
+    let options = Options {
+        decimal_style: DecimalStyle::Point,
+        ..Default::default()
+    };
+
+    let mut seconds = duration.as_secs();
+    let mut parts = Vec::new();
+    for &(length, singular, plural) in UNITS.iter() {
+        let count = seconds / length;
+        seconds %= length;
+
+        // the seconds carry any fraction of a second (e.g. "one point two five seconds")
+        let mut number = format!("{}", count);
+        if length == 1 && style.subsecond_digits > 0 {
+            let nanos = format!("{:09}", duration.subsec_nanos());
+            let digits = nanos[..(style.subsecond_digits as usize).min(9)].trim_end_matches('0');
+            if !digits.is_empty() {
+                number.push('.');
+                number.push_str(digits);
+            }
+        }
+
+        let is_zero = number == "0";
+        let last = length == 1 && parts.is_empty();
+        // leading zero units are always left out (e.g. no "zero days" before the hours)
+        if is_zero && (style.skip_zero || parts.is_empty()) && !last {
+            continue;
+        }
+
+        let mut part = convert_number_to_english(number, &options);
+        part.push(' ');
+        part.push_str(if count == 1 && !part.contains(" point ") {
+            singular
+        } else {
+            plural
+        });
+        parts.push(part);
+    }
+
+    let mut result = String::new();
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            result.push_str(if i == parts.len() - 1 { " and " } else { ", " });
+        }
+        result.push_str(part);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{duration_to_english, DurationStyle};
+    use core::time::Duration;
+
+    #[test]
+    fn test_duration() {
+        let style = DurationStyle::default();
+        let read = |seconds| duration_to_english(Duration::from_secs(seconds), &style);
+        assert_eq!(read(0), "zero seconds");
+        assert_eq!(read(1), "one second");
+        assert_eq!(read(60), "one minute");
+        assert_eq!(read(7_205), "two hours and five seconds");
+        assert_eq!(read(90_061), "one day, one hour, one minute and one second");
+        assert_eq!(
+            duration_to_english(Duration::from_millis(1_500), &style),
+            "one second"
+        );
+
+        let precise = DurationStyle {
+            subsecond_digits: 3,
+            ..Default::default()
+        };
+        assert_eq!(
+            duration_to_english(Duration::from_millis(61_050), &precise),
+            "one minute and one point zero five seconds"
+        );
+        assert_eq!(
+            duration_to_english(Duration::from_millis(120_000), &precise),
+            "two minutes"
+        );
+        assert_eq!(
+            duration_to_english(Duration::from_millis(250), &precise),
+            "zero point two five seconds"
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use crate::Language;
+use alloc::{borrow::Cow, format};
+use core::fmt::{self, Write};
+
+/// The names of the numbers from one to nineteen, which Dutch writes as single words.
+const UNITS: [&str; 19] = [
+    "een",
+    "twee",
+    "drie",
+    "vier",
+    "vijf",
+    "zes",
+    "zeven",
+    "acht",
+    "negen",
+    "tien",
+    "elf",
+    "twaalf",
+    "dertien",
+    "veertien",
+    "vijftien",
+    "zestien",
+    "zeventien",
+    "achttien",
+    "negentien",
+];
+
+/// The names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "tien", "twintig", "dertig", "veertig", "vijftig", "zestig", "zeventig", "tachtig", "negentig",
+];
+
+/// The names of each power of one thousand above "duizend", in the long scale with "-iljard" names.
+const MAGNITUDES: [&str; 11] = [
+    "miljoen",
+    "miljard",
+    "biljoen",
+    "biljard",
+    "triljoen",
+    "triljard",
+    "quadriljoen",
+    "quadriljard",
+    "quintiljoen",
+    "quintiljard",
+    "sextiljoen",
+];
+
+/// Numbers in Dutch (e.g. 123 -> "honderddrieëntwintig").
+///
+/// Each group is written as one word, with the units before the tens joined by "en". The "en"
+/// takes a diaeresis after a unit ending in "e" (e.g. "tweeëntwintig", but "vierentwintig").
+/// "Honderd" and "duizend" are not counted with "een".
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Dutch};
+/// assert_eq!(number_to_words(&123, &Dutch), "honderddrieëntwintig");
+/// assert_eq!(number_to_words(&44, &Dutch), "vierenveertig");
+/// assert_eq!(number_to_words(&2_500, &Dutch), "tweeduizend vijfhonderd");
+/// assert_eq!(number_to_words(&3_000_000, &Dutch), "drie miljoen");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Dutch;
+
+impl Language for Dutch {
+    fn zero(&self) -> &str {
+        "nul"
+    }
+
+    fn negative(&self) -> &str {
+        "min"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "komma"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "honderd"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, _group: u128) -> Cow<'_, str> {
+        if magnitude == 1 {
+            return Cow::Borrowed("duizend");
+        }
+        let largest = MAGNITUDES.len() + 1;
+        match MAGNITUDES.get(magnitude - 2) {
+            // the names are not pluralised after a count (e.g. "twee miljoen")
+            Some(name) => Cow::Borrowed(name),
+            // past the named powers, the largest name is counted (e.g. "duizend sextiljoen")
+            None => {
+                let inner = self.magnitude_name(magnitude - largest, 2);
+                Cow::Owned(format!("{} sextiljoen", inner))
+            }
+        }
+    }
+
+    fn tens_separator(&self) -> &str {
+        "en"
+    }
+
+    fn write_below_hundred<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        let tens = number / 10;
+        let unit = number % 10;
+        match number {
+            1..=19 => out.write_str(UNITS[number as usize - 1]),
+            _ if unit == 0 => out.write_str(TENS[tens as usize - 1]),
+            // the units come before the tens (e.g. "drieëntwintig", "vijfentwintig")
+            _ => {
+                let name = UNITS[unit as usize - 1];
+                let and = if name.ends_with('e') { "ën" } else { "en" };
+                write!(out, "{}{}{}", name, and, TENS[tens as usize - 1])
+            }
+        }
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        let hundreds = number / 100;
+        let rest = number % 100;
+        if hundreds > 1 {
+            out.write_str(UNITS[hundreds as usize - 1])?;
+        }
+        if hundreds > 0 {
+            out.write_str("honderd")?;
+        }
+        if rest > 0 {
+            self.write_below_hundred(out, rest)?;
+        }
+        Ok(())
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        match (magnitude, group) {
+            (0, _) => self.write_hundreds(out, group as u64),
+            // "duizend" is not counted with "een", and the thousands are joined to their count
+            (1, 1) => out.write_str("duizend"),
+            (1, _) => {
+                self.write_hundreds(out, group as u64)?;
+                out.write_str("duizend")
+            }
+            _ => {
+                self.write_hundreds(out, group as u64)?;
+                write!(out, " {}", self.magnitude_name(magnitude, group))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Dutch};
+
+    #[test]
+    fn test_dutch() {
+        for (number, words) in [
+            (0_i64, "nul"),
+            (1, "een"),
+            (13, "dertien"),
+            (21, "eenentwintig"),
+            (22, "tweeëntwintig"),
+            (23, "drieëntwintig"),
+            (25, "vijfentwintig"),
+            (80, "tachtig"),
+            (99, "negenennegentig"),
+            (100, "honderd"),
+            (101, "honderdeen"),
+            (123, "honderddrieëntwintig"),
+            (200, "tweehonderd"),
+            (1_000, "duizend"),
+            (1_001, "duizend een"),
+            (2_000, "tweeduizend"),
+            (32_000, "tweeëndertigduizend"),
+            (1_000_000, "een miljoen"),
+            (2_000_005, "twee miljoen vijf"),
+            (1_000_000_000, "een miljard"),
+            (-4, "min vier"),
+        ] {
+            assert_eq!(number_to_words(&number, &Dutch), words);
+        }
+        assert_eq!(number_to_words(&0.5, &Dutch), "nul komma vijf");
+        assert_eq!(
+            number_to_words(&(10_u128.pow(36) * 3), &Dutch),
+            "drie sextiljoen"
+        );
+    }
+}
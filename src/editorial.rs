@@ -0,0 +1,118 @@
+use crate::{Case, NumberToEnglish, Options};
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+use num_traits::Num;
+
+/// The editorial style guide that decides which numbers are spelled out.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum StyleGuide {
+    /// The Associated Press style: zero through nine are spelled out (e.g. "nine", "10").
+    #[default]
+    Ap,
+    /// The Chicago Manual of Style: one through ninety-nine are spelled out (e.g. "ninety-nine", "100").
+    Chicago,
+}
+
+impl StyleGuide {
+    /// Whether a whole number is spelled out by the style guide.
+    fn spells_out(self, number: u64) -> bool {
+        match self {
+            StyleGuide::Ap => number <= 9,
+            StyleGuide::Chicago => (1..=99).contains(&number),
+        }
+    }
+}
+
+/// How a number is written in running text.
+///
+/// # Examples
+/// ```
+/// use num2english::{editorial_number, EditorialStyle, StyleGuide};
+/// let style = EditorialStyle {
+///     guide: StyleGuide::Chicago,
+///     ..Default::default()
+/// };
+/// assert_eq!(editorial_number(&42, &style), "forty-two");
+/// assert_eq!(editorial_number(&420, &style), "420");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct EditorialStyle {
+    /// The style guide that decides which numbers are spelled out.
+    pub guide: StyleGuide,
+    /// The number starts a sentence, so it is always spelled out with a capital letter.
+    pub sentence_start: bool,
+    /// The options used to read a number that is spelled out.
+    pub options: Options,
+}
+
+/// Write a number in running text as an editorial style guide would: spelled out if it is a
+/// small whole number, and as a numeral otherwise.
+///
+/// A number that starts a sentence is spelled out whatever its value, with its first letter
+/// capitalized unless the [`Options`] ask for another case.
+///
+/// # Examples
+/// ```
+/// use num2english::{editorial_number, EditorialStyle};
+/// let style = EditorialStyle::default();
+/// assert_eq!(editorial_number(&7, &style), "seven");
+/// assert_eq!(editorial_number(&12, &style), "12");
+/// assert_eq!(editorial_number(&2.5, &style), "2.5");
+///
+/// let start = EditorialStyle {
+///     sentence_start: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(editorial_number(&12, &start), "Twelve");
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub fn editorial_number<T: Num + Display>(number: &T, style: &EditorialStyle) -> String {
+    if style.sentence_start {
+        let mut options = style.options.clone();
+        if options.case == Case::Lower {
+            options.case = Case::Sentence;
+        }
+        return number.to_english_with(&options);
+    }
+
+    let numeral = number.to_string();
+    match numeral.parse::<u64>() {
+        Ok(whole) if style.guide.spells_out(whole) => number.to_english_with(&style.options),
+        _ => numeral,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{editorial_number, Case, EditorialStyle, Options, StyleGuide};
+
+    #[test]
+    fn test_editorial_number() {
+        let ap = EditorialStyle::default();
+        assert_eq!(editorial_number(&0, &ap), "zero");
+        assert_eq!(editorial_number(&9_u8, &ap), "nine");
+        assert_eq!(editorial_number(&10, &ap), "10");
+        assert_eq!(editorial_number(&-3, &ap), "-3");
+        assert_eq!(editorial_number(&1_000_000, &ap), "1000000");
+
+        let chicago = EditorialStyle {
+            guide: StyleGuide::Chicago,
+            ..Default::default()
+        };
+        assert_eq!(editorial_number(&0, &chicago), "0");
+        assert_eq!(editorial_number(&1, &chicago), "one");
+        assert_eq!(editorial_number(&99, &chicago), "ninety-nine");
+        assert_eq!(editorial_number(&100, &chicago), "100");
+
+        let start = EditorialStyle {
+            guide: StyleGuide::Chicago,
+            sentence_start: true,
+            options: Options {
+                case: Case::Title,
+                ..Default::default()
+            },
+        };
+        assert_eq!(editorial_number(&121, &start), "One Hundred Twenty-One");
+    }
+}
@@ -0,0 +1,40 @@
+use core::fmt;
+
+/// An error from converting a number to or from its textual representation.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input is not a well-formed Roman numeral.
+    InvalidRomanNumeral,
+    /// The hour or minute of a time of day is out of range.
+    InvalidTime,
+    /// The month or day of a date is out of range.
+    InvalidDate,
+    /// The input is not a well-formed decimal number.
+    InvalidNumber,
+    /// The number is infinite or not a number.
+    NonFinite,
+    /// The number is too large or too precise for the names of the chosen scale.
+    MagnitudeOverflow,
+    /// The input is not the name of a number in English.
+    InvalidWords,
+    /// The output does not fit in the buffer it is written to.
+    BufferTooSmall,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidRomanNumeral => write!(f, "invalid Roman numeral"),
+            Error::InvalidTime => write!(f, "invalid time of day"),
+            Error::InvalidDate => write!(f, "invalid date"),
+            Error::InvalidNumber => write!(f, "invalid number"),
+            Error::NonFinite => write!(f, "number is not finite"),
+            Error::MagnitudeOverflow => write!(f, "number is too large to name"),
+            Error::InvalidWords => write!(f, "invalid number words"),
+            Error::BufferTooSmall => write!(f, "buffer is too small"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
@@ -0,0 +1,116 @@
+//! A C interface for converting numbers written as text to English.
+//!
+//! Build a shared or static library for C with
+//! `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`), and declare:
+//!
+//! ```c
+//! #include <stddef.h>
+//!
+//! #define NUM2ENGLISH_OK 0
+//! #define NUM2ENGLISH_NULL_POINTER -1
+//! #define NUM2ENGLISH_INVALID_UTF8 -2
+//! #define NUM2ENGLISH_INVALID_NUMBER -3
+//! #define NUM2ENGLISH_BUFFER_TOO_SMALL -4
+//!
+//! int num2english_convert(const char *number, char *buf, size_t len);
+//! ```
+
+use crate::{convert_str, Options};
+use core::ffi::{c_char, c_int, CStr};
+
+/// The number was converted.
+pub const NUM2ENGLISH_OK: c_int = 0;
+/// The number or the buffer is a null pointer.
+pub const NUM2ENGLISH_NULL_POINTER: c_int = -1;
+/// The number is not valid UTF-8.
+pub const NUM2ENGLISH_INVALID_UTF8: c_int = -2;
+/// The text is not a number.
+pub const NUM2ENGLISH_INVALID_NUMBER: c_int = -3;
+/// The name and its terminating NUL do not fit in the buffer.
+pub const NUM2ENGLISH_BUFFER_TOO_SMALL: c_int = -4;
+
+/// Write the name in English of a number written as text into a buffer as a NUL-terminated string.
+///
+/// The number is read as by [`convert_str`] with the default [`Options`]. Returns
+/// [`NUM2ENGLISH_OK`] on success, or one of the negative error codes, in which case the buffer
+/// holds an empty string if it has room for one.
+///
+/// # Safety
+/// `number` must point to a NUL-terminated string, and `buf` must point to `len` writable bytes.
+///
+/// # Examples
+/// ```
+/// use num2english::ffi::{num2english_convert, NUM2ENGLISH_OK};
+/// let mut buf = [0; 32];
+/// let code = unsafe { num2english_convert(c"42".as_ptr(), buf.as_mut_ptr(), buf.len()) };
+/// assert_eq!(code, NUM2ENGLISH_OK);
+/// let words = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) };
+/// assert_eq!(words.to_str(), Ok("forty-two"));
+/// ```
+///
+/// [`convert_str`]: ../fn.convert_str.html
+/// [`Options`]: ../struct.Options.html
+#[no_mangle]
+pub unsafe extern "C" fn num2english_convert(
+    number: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+) -> c_int {
+    if number.is_null() || buf.is_null() {
+        return NUM2ENGLISH_NULL_POINTER;
+    }
+    // SAFETY: the caller guarantees `buf` points to `len` writable bytes
+    let buf = unsafe { core::slice::from_raw_parts_mut(buf.cast::<u8>(), len) };
+    if let Some(first) = buf.first_mut() {
+        *first = 0;
+    }
+
+    // SAFETY: the caller guarantees `number` points to a NUL-terminated string
+    let number = unsafe { CStr::from_ptr(number) };
+    let Ok(number) = number.to_str() else {
+        return NUM2ENGLISH_INVALID_UTF8;
+    };
+    let Ok(words) = convert_str(number, &Options::default()) else {
+        return NUM2ENGLISH_INVALID_NUMBER;
+    };
+    if words.len() >= len {
+        return NUM2ENGLISH_BUFFER_TOO_SMALL;
+    }
+
+    buf[..words.len()].copy_from_slice(words.as_bytes());
+    buf[words.len()] = 0;
+    NUM2ENGLISH_OK
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr;
+
+    fn convert(number: &CStr, buf: &mut [u8]) -> c_int {
+        unsafe { num2english_convert(number.as_ptr(), buf.as_mut_ptr().cast(), buf.len()) }
+    }
+
+    #[test]
+    fn test_ffi() {
+        let mut buf = [0xFF; 64];
+        assert_eq!(convert(c"-1,500.5", &mut buf), NUM2ENGLISH_OK);
+        let words = CStr::from_bytes_until_nul(&buf).unwrap();
+        assert_eq!(
+            words.to_str(),
+            Ok("negative one thousand five hundred and five tenths")
+        );
+
+        let mut exact = [0xFF; 4];
+        assert_eq!(convert(c"1", &mut exact), NUM2ENGLISH_OK);
+        assert_eq!(&exact, b"one\0");
+        assert_eq!(convert(c"3", &mut exact), NUM2ENGLISH_BUFFER_TOO_SMALL);
+        assert_eq!(exact[0], 0);
+        assert_eq!(convert(c"1", &mut []), NUM2ENGLISH_BUFFER_TOO_SMALL);
+
+        assert_eq!(convert(c"twelve", &mut buf), NUM2ENGLISH_INVALID_NUMBER);
+        assert_eq!(convert(c"\xFF", &mut buf), NUM2ENGLISH_INVALID_UTF8);
+        let code = unsafe { num2english_convert(ptr::null(), buf.as_mut_ptr().cast(), 1) };
+        assert_eq!(code, NUM2ENGLISH_NULL_POINTER);
+    }
+}
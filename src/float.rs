@@ -0,0 +1,105 @@
+use crate::{
+    convert_non_finite_to_english, scaled::convert_scaled_to_english, Options, ScaledDecimal,
+};
+use alloc::string::String;
+
+/// Convert the exact value stored by a float to English using the given [`Options`].
+///
+/// Unlike [`to_english`], which reads the shortest decimal that rounds to the float, every digit
+/// of the float's binary value is read (e.g. 0.1 is stored as
+/// 0.1000000000000000055511151231257827021181583404541015625). An `f32` widens to an `f64` without
+/// changing its value, so its exact value is read too.
+///
+/// # Examples
+/// ```
+/// use num2english::{exact_float_to_english, DecimalStyle, Options};
+/// assert_eq!(exact_float_to_english(0.5, &Options::default()), "five tenths");
+/// let point = Options {
+///     decimal_style: DecimalStyle::Point,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     exact_float_to_english(0.1_f32, &point),
+///     "zero point one zero zero zero zero zero zero zero one four nine zero one one six one one \
+///      nine three eight four seven six five six two five"
+/// );
+/// ```
+///
+/// [`Options`]: struct.Options.html
+/// [`to_english`]: trait.NumberToEnglish.html#tymethod.to_english
+pub fn exact_float_to_english(value: impl Into<f64>, options: &Options) -> String {
+    let value = value.into();
+    if !value.is_finite() {
+        let name = if value.is_nan() {
+            "NaN"
+        } else if value < 0.0 {
+            "-inf"
+        } else {
+            "inf"
+        };
+        let result = convert_non_finite_to_english(name, options).unwrap_or_default();
+        return options.case.apply(&result);
+    }
+
+    let bits = value.to_bits();
+    let exponent_field = ((bits >> 52) & 0x7FF) as i64;
+    let fraction = bits & ((1 << 52) - 1);
+    // subnormal numbers have no implicit leading bit and share the smallest normal exponent
+    let (mantissa, exponent) = if exponent_field == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), exponent_field - 1075)
+    };
+
+    let mut mantissa = mantissa as i128;
+    if value.is_sign_negative() {
+        mantissa = -mantissa;
+    }
+    // the exponent of an f64 is always in range
+    let decimal = ScaledDecimal::from_binary(mantissa, exponent).unwrap_or_default();
+    options
+        .case
+        .apply(&convert_scaled_to_english(&decimal, options))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{convert_str, exact_float_to_english, DecimalStyle, Options};
+
+    #[test]
+    fn test_exact_float() {
+        let options = Options::default();
+        assert_eq!(exact_float_to_english(0.0, &options), "zero");
+        assert_eq!(exact_float_to_english(-0.0, &options), "zero");
+        assert_eq!(
+            exact_float_to_english(-2.5, &options),
+            "negative two and five tenths"
+        );
+        assert_eq!(
+            exact_float_to_english(9_007_199_254_740_993_u64 as f64, &options),
+            "nine quadrillion seven trillion one hundred ninety-nine billion two hundred \
+             fifty-four million seven hundred forty thousand nine hundred ninety-two"
+        );
+        assert_eq!(
+            Ok(exact_float_to_english(0.1, &options)),
+            convert_str(
+                "0.1000000000000000055511151231257827021181583404541015625",
+                &options
+            )
+        );
+        let point = Options {
+            decimal_style: DecimalStyle::Point,
+            ..Default::default()
+        };
+        let smallest = exact_float_to_english(f64::from_bits(1), &point);
+        assert!(smallest.starts_with("zero point zero zero"));
+        assert!(smallest.contains(" zero four nine four zero six five six"));
+        assert!(smallest.ends_with(" six two five"));
+        assert_eq!(smallest.split(' ').count(), 2 + 1074);
+        assert_eq!(
+            exact_float_to_english(f64::NEG_INFINITY, &options),
+            "negative infinity"
+        );
+        assert_eq!(exact_float_to_english(f32::NAN, &options), "not a number");
+    }
+}
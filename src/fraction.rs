@@ -0,0 +1,633 @@
+use crate::{
+    convert_integer_to_english,
+    decimal::{divide_digits, multiply_add_digits},
+    ordinal_words, Options,
+};
+#[cfg(feature = "bigint")]
+use crate::{
+    convert_number_to_english, digits::convert_digit_string_to_english,
+    scaled::convert_scaled_to_english, DecimalStyle, Error, ScaledDecimal,
+};
+use alloc::string::String;
+#[cfg(feature = "bigint")]
+use alloc::{collections::BTreeMap, string::ToString};
+#[cfg(feature = "bigint")]
+use num_bigint::{BigInt, Sign};
+
+/// A fraction with a numerator and a denominator, such as the parts of a `num_rational::Ratio`.
+///
+/// Fractions are read with ordinal denominators (e.g. "three fifths"), using "half" and "quarter"
+/// for halves and quarters. The fraction is read as given, without being reduced.
+///
+/// # Examples
+/// ```
+/// use num2english::Fraction;
+/// assert_eq!(Fraction::new(3, 5).to_english(), "three fifths");
+/// assert_eq!(Fraction::new(1, 2).to_english(), "one half");
+/// assert_eq!(Fraction::new(1, 4).to_english(), "one quarter");
+/// assert_eq!(Fraction::new(-21, 100).to_english(), "negative twenty-one hundredths");
+/// ```
+///
+/// A `Ratio` can be converted from its parts:
+/// ```ignore
+/// let ratio = num_rational::Ratio::new(2, 3);
+/// let fraction = Fraction::new(*ratio.numer(), *ratio.denom());
+/// assert_eq!(fraction.to_english(), "two thirds");
+/// ```
+#[cfg(feature = "bigint")]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Fraction {
+    /// The numerator of the fraction.
+    pub numerator: BigInt,
+    /// The denominator of the fraction.
+    pub denominator: BigInt,
+}
+
+#[cfg(feature = "bigint")]
+impl Fraction {
+    /// Create a fraction from a numerator and a denominator.
+    ///
+    /// # Panics
+    /// Panics if the denominator is zero. Use [`try_new`] for a denominator that may be zero.
+    ///
+    /// [`try_new`]: #method.try_new
+    pub fn new(numerator: impl Into<BigInt>, denominator: impl Into<BigInt>) -> Self {
+        match Self::try_new(numerator, denominator) {
+            Ok(fraction) => fraction,
+            Err(_) => panic!("fraction has a zero denominator"),
+        }
+    }
+
+    /// Create a fraction from a numerator and a denominator, reporting a zero denominator.
+    ///
+    /// A fraction with a zero denominator built from its fields is read as the division would
+    /// be for floating point numbers (e.g. "infinity", "not a number").
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidNumber`] if the denominator is zero.
+    ///
+    /// [`Error::InvalidNumber`]: enum.Error.html#variant.InvalidNumber
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{Error, Fraction};
+    /// assert_eq!(Fraction::try_new(3, 5), Ok(Fraction::new(3, 5)));
+    /// assert_eq!(Fraction::try_new(3, 0), Err(Error::InvalidNumber));
+    /// ```
+    pub fn try_new(
+        numerator: impl Into<BigInt>,
+        denominator: impl Into<BigInt>,
+    ) -> Result<Self, Error> {
+        let denominator = denominator.into();
+        if denominator == BigInt::from(0) {
+            return Err(Error::InvalidNumber);
+        }
+        Ok(Fraction {
+            numerator: numerator.into(),
+            denominator,
+        })
+    }
+
+    /// Convert the fraction to its English representation.
+    pub fn to_english(&self) -> String {
+        self.to_english_with(&Options::default())
+    }
+
+    /// Convert the fraction to a percentage in English, reading any part of a percent as a mixed number.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{Fraction, Options};
+    /// assert_eq!(
+    ///     Fraction::new(9, 20).to_english_percent(&Options::default()),
+    ///     "forty-five percent"
+    /// );
+    /// assert_eq!(
+    ///     Fraction::new(1, 3).to_english_percent(&Options::default()),
+    ///     "thirty-three and a third percent"
+    /// );
+    /// ```
+    pub fn to_english_percent(&self, options: &Options) -> String {
+        let (numerator, denominator) = reduce(
+            self.numerator.clone() * BigInt::from(100),
+            self.denominator.clone(),
+        );
+        let percent = Fraction {
+            numerator,
+            denominator,
+        };
+        let mixed = Options {
+            decimal_style: DecimalStyle::Mixed,
+            ..options.clone()
+        };
+        let mut result = convert_fraction_to_english(&percent, &mixed);
+        result.push_str(" percent");
+        options.case.apply(&result)
+    }
+
+    /// Convert the fraction to its English representation using the given [`Options`].
+    ///
+    /// [`Options`]: struct.Options.html
+    pub fn to_english_with(&self, options: &Options) -> String {
+        options
+            .case
+            .apply(&convert_fraction_to_english(self, options))
+    }
+
+    /// Convert the fraction to a decimal by long division and read it using the given [`Options`].
+    ///
+    /// Division stops when it terminates or after `max_places` decimal places. A decimal cut
+    /// short is followed by "…".
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{DecimalStyle, Fraction, Options};
+    /// let point = Options {
+    ///     decimal_style: DecimalStyle::Point,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     Fraction::new(1, 3).to_english_decimal(3, &point),
+    ///     "zero point three three three…"
+    /// );
+    /// assert_eq!(
+    ///     Fraction::new(3, 8).to_english_decimal(10, &Options::default()),
+    ///     "three hundred seventy-five thousandths"
+    /// );
+    /// ```
+    ///
+    /// [`Options`]: struct.Options.html
+    pub fn to_english_decimal(&self, max_places: usize, options: &Options) -> String {
+        if self.denominator == BigInt::from(0) {
+            return options
+                .case
+                .apply(&convert_fraction_to_english(self, options));
+        }
+
+        let (decimal, exact) = self.long_divide(max_places);
+        let mut result = convert_scaled_to_english(&decimal, options);
+        if !exact {
+            result.push('…');
+        }
+        options.case.apply(&result)
+    }
+
+    /// Read the fraction as a decimal in point style, marking the digits that repeat forever.
+    ///
+    /// The repeating digits are found by long division, so the number of digits read can be as
+    /// large as the denominator. Decimals that terminate are read without a mark.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{Fraction, Options, RepeatingStyle};
+    /// let options = Options::default();
+    /// assert_eq!(
+    ///     Fraction::new(1, 6).to_english_repeating(RepeatingStyle::Repeating, &options),
+    ///     "zero point one six repeating"
+    /// );
+    /// assert_eq!(
+    ///     Fraction::new(1, 6).to_english_repeating(RepeatingStyle::WithRepetend, &options),
+    ///     "zero point one six, with six repeating"
+    /// );
+    /// ```
+    pub fn to_english_repeating(&self, style: RepeatingStyle, options: &Options) -> String {
+        if self.denominator == BigInt::from(0) {
+            return options
+                .case
+                .apply(&convert_fraction_to_english(self, options));
+        }
+
+        let negative =
+            (self.numerator.sign() == Sign::Minus) != (self.denominator.sign() == Sign::Minus);
+        let numerator = BigInt::from(self.numerator.magnitude().clone());
+        let denominator = BigInt::from(self.denominator.magnitude().clone());
+
+        // a remainder seen before starts the same digits over again
+        let mut remainder = numerator.clone() % denominator.clone();
+        let mut digits = String::new();
+        let mut seen = BTreeMap::new();
+        let mut repetend_start = None;
+        while remainder != BigInt::from(0) {
+            if let Some(&start) = seen.get(&remainder) {
+                repetend_start = Some(start);
+                break;
+            }
+            seen.insert(remainder.clone(), digits.len());
+            remainder *= 10;
+            let digit = remainder.clone() / denominator.clone();
+            digits.push_str(&digit.to_string());
+            remainder %= denominator.clone();
+        }
+
+        let mut number = String::new();
+        if negative {
+            number.push('-');
+        }
+        number.push_str(&(numerator / denominator).to_string());
+        if !digits.is_empty() {
+            number.push('.');
+            number.push_str(&digits);
+        }
+        let point = Options {
+            decimal_style: DecimalStyle::Point,
+            max_decimal_places: None,
+            ..options.clone()
+        };
+        let mut result = convert_number_to_english(number, &point);
+
+        if let Some(start) = repetend_start {
+            match style {
+                RepeatingStyle::Repeating => result.push_str(" repeating"),
+                RepeatingStyle::WithRepetend => {
+                    result.push_str(", with ");
+                    result.push_str(&convert_digit_string_to_english(&digits[start..], options));
+                    result.push_str(" repeating");
+                }
+            }
+        }
+        options.case.apply(&result)
+    }
+
+    /// Divide the numerator by the denominator to at most `max_places` decimal places,
+    /// returning the truncated decimal and whether the division terminated.
+    fn long_divide(&self, max_places: usize) -> (ScaledDecimal, bool) {
+        let negative =
+            (self.numerator.sign() == Sign::Minus) != (self.denominator.sign() == Sign::Minus);
+        let numerator = BigInt::from(self.numerator.magnitude().clone());
+        let denominator = BigInt::from(self.denominator.magnitude().clone());
+
+        let mut mantissa = numerator.clone() / denominator.clone();
+        let mut remainder = numerator % denominator.clone();
+        let mut places = 0;
+        while remainder != BigInt::from(0) && places < max_places {
+            remainder *= 10;
+            mantissa = mantissa * 10 + remainder.clone() / denominator.clone();
+            remainder %= denominator.clone();
+            places += 1;
+        }
+
+        if negative {
+            mantissa = -mantissa;
+        }
+        let decimal = ScaledDecimal {
+            mantissa,
+            scale: places as i64,
+        };
+        (decimal, remainder == BigInt::from(0))
+    }
+}
+
+/// How the repeating digits of a decimal are marked.
+#[cfg(feature = "bigint")]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum RepeatingStyle {
+    /// Read the repeating digits once, followed by "repeating" (e.g. "zero point one six repeating").
+    #[default]
+    Repeating,
+    /// Read the decimal, then name the repeating digits (e.g. "zero point one six, with six repeating").
+    WithRepetend,
+}
+
+/// Convert a fraction to its name in English (e.g. 3/5 -> "three fifths")
+#[cfg(feature = "bigint")]
+pub(crate) fn convert_fraction_to_english(fraction: &Fraction, options: &Options) -> String {
+    let mut numerator = fraction.numerator.clone();
+    let mut denominator = fraction.denominator.clone();
+    let negative = (numerator.sign() == Sign::Minus) != (denominator.sign() == Sign::Minus);
+    if let Sign::Minus = numerator.sign() {
+        numerator = -numerator;
+    }
+    if let Sign::Minus = denominator.sign() {
+        denominator = -denominator;
+    }
+
+    // dividing by zero is named as it is for floating point numbers (e.g. 1/0 -> "infinity")
+    if denominator == BigInt::from(0) {
+        let number = match numerator.sign() {
+            Sign::NoSign => "NaN",
+            _ if negative => "-inf",
+            _ => "inf",
+        };
+        return convert_number_to_english(String::from(number), options);
+    }
+
+    let mut result = String::new();
+    if negative && numerator > BigInt::from(0) {
+        result.push_str(options.negative_word);
+        result.push(' ');
+    }
+
+    if numerator == BigInt::from(0) {
+        result.push_str(options.zero_word);
+        return result;
+    }
+
+    // mixed numbers read the whole part first (e.g. 11/3 -> "three and two thirds")
+    if options.decimal_style == DecimalStyle::Mixed
+        && numerator >= denominator
+        && denominator > BigInt::from(1)
+    {
+        let whole = numerator.clone() / denominator.clone();
+        let rest = numerator % denominator.clone();
+        result.push_str(&convert_integer_to_english(&whole.to_string(), options));
+        if rest > BigInt::from(0) {
+            result.push_str(" and ");
+            result.push_str(&convert_mixed_part_to_english(
+                &rest.to_string(),
+                &denominator.to_string(),
+                options,
+            ));
+        }
+        return result;
+    }
+
+    result.push_str(&convert_parts_to_english(
+        &numerator.to_string(),
+        &denominator.to_string(),
+        options,
+    ));
+    result
+}
+
+/// Convert a positive fraction, given as the digits of its numerator and denominator, to its name
+/// in English without a whole part (e.g. "3", "5" -> "three fifths")
+pub(crate) fn convert_parts_to_english(
+    numerator: &str,
+    denominator: &str,
+    options: &Options,
+) -> String {
+    let plural = numerator != "1";
+    let mut result = convert_integer_to_english(numerator, options);
+
+    if denominator != "1" {
+        result.push(' ');
+        result.push_str(&denominator_to_english(denominator, plural, options));
+    }
+
+    result
+}
+
+/// Convert the fractional part of a mixed number, using "a" for a single part (e.g. 1/2 -> "a half")
+pub(crate) fn convert_mixed_part_to_english(
+    numerator: &str,
+    denominator: &str,
+    options: &Options,
+) -> String {
+    if numerator != "1" {
+        let mut result = convert_integer_to_english(numerator, options);
+        result.push(' ');
+        result.push_str(&denominator_to_english(denominator, true, options));
+        return result;
+    }
+
+    let name = denominator_to_english(denominator, false, options);
+    let vowel = name.starts_with(['a', 'e', 'i', 'o', 'u']) && !name.starts_with("one");
+    let mut result = String::from(if vowel { "an " } else { "a " });
+    result.push_str(&name);
+    result
+}
+
+/// Reduce the decimal part of a number, given as its digits and number of decimal places, to a
+/// fraction in lowest terms (e.g. "50", 3 -> ("1", "20"))
+pub(crate) fn reduce_decimal(digits: &str, places: usize) -> (String, String) {
+    // the denominator is a power of ten, so only factors of two and five can be shared
+    let mut numerator = String::from(digits.trim_start_matches('0'));
+    let mut denominator = String::from("1");
+    for factor in [2, 5] {
+        let mut shared = 0;
+        while shared < places {
+            match divide_digits(&numerator, factor) {
+                (quotient, 0) => numerator = quotient,
+                _ => break,
+            }
+            shared += 1;
+        }
+        for _ in shared..places {
+            denominator = multiply_add_digits(&denominator, factor, 0);
+        }
+    }
+    (numerator, denominator)
+}
+
+/// Reduce a fraction to its lowest terms (e.g. 5/10 -> 1/2)
+#[cfg(feature = "bigint")]
+pub(crate) fn reduce(numerator: BigInt, denominator: BigInt) -> (BigInt, BigInt) {
+    let mut a = numerator.clone();
+    let mut b = denominator.clone();
+    while b != BigInt::from(0) {
+        let r = a % b.clone();
+        a = b;
+        b = r;
+    }
+    if a == BigInt::from(0) {
+        return (numerator, denominator);
+    }
+    // the divisor is kept positive so that the signs stay where they were
+    if let Sign::Minus = a.sign() {
+        a = -a;
+    }
+    (numerator / a.clone(), denominator / a)
+}
+
+/// Get the name of a denominator (e.g. 2 -> "half", 4 -> "quarters", 5 -> "fifths")
+pub(crate) fn denominator_to_english(denominator: &str, plural: bool, options: &Options) -> String {
+    if denominator == "2" {
+        return String::from(if plural { "halves" } else { "half" });
+    }
+
+    let mut result = if denominator == "4" {
+        String::from("quarter")
+    } else {
+        let plain = Options {
+            informal_article: false,
+            british_and: false,
+            colloquial_units: false,
+            ..options.clone()
+        };
+        // powers of ten drop their leading "one" (e.g. "hundredth", not "one hundredth")
+        let power_of_ten = denominator.len() > 2 && denominator[1..].bytes().all(|b| b == b'0');
+        let name = convert_integer_to_english(denominator, &plain);
+        match name.strip_prefix("one ") {
+            Some(rest) if power_of_ten => ordinal_words(rest),
+            _ => ordinal_words(&name),
+        }
+    };
+
+    if plural {
+        result.push('s');
+    }
+    result
+}
+
+#[cfg(all(test, feature = "bigint"))]
+mod tests {
+    use crate::{DecimalStyle, Error, Fraction, NumberToEnglish, Options, RepeatingStyle};
+
+    #[test]
+    fn test_percent() {
+        let options = Options::default();
+        assert_eq!(45.to_english_percent(&options), "forty-five percent");
+        assert_eq!(100.to_english_percent(&options), "one hundred percent");
+        assert_eq!(
+            45.5.to_english_percent(&options),
+            "forty-five and five tenths percent"
+        );
+        assert_eq!(
+            Fraction::new(1, 2).to_english_percent(&options),
+            "fifty percent"
+        );
+        assert_eq!(
+            Fraction::new(1, 8).to_english_percent(&options),
+            "twelve and a half percent"
+        );
+        assert_eq!(
+            Fraction::new(1, 300).to_english_percent(&options),
+            "one third percent"
+        );
+        assert_eq!(
+            Fraction::new(-3, 4).to_english_percent(&options),
+            "negative seventy-five percent"
+        );
+    }
+
+    #[test]
+    fn test_long_division() {
+        let options = Options::default();
+        assert_eq!(
+            Fraction::new(1, 4).to_english_decimal(5, &options),
+            "twenty-five hundredths"
+        );
+        assert_eq!(
+            Fraction::new(7, 2).to_english_decimal(0, &options),
+            "three…"
+        );
+        assert_eq!(
+            Fraction::new(-2, 3).to_english_decimal(2, &options),
+            "negative sixty-six hundredths…"
+        );
+        assert_eq!(
+            Fraction::new(22, -7).to_english_decimal(4, &options),
+            "negative three and one thousand four hundred twenty-eight ten-thousandths…"
+        );
+        assert_eq!(Fraction::new(6, 3).to_english_decimal(4, &options), "two");
+        assert_eq!(
+            Fraction::new(1, 1024).to_english_decimal(20, &options),
+            "nine million seven hundred sixty-five thousand six hundred twenty-five ten-billionths"
+        );
+    }
+
+    #[test]
+    fn test_repeating() {
+        let options = Options::default();
+        let repeating =
+            |n, d| Fraction::new(n, d).to_english_repeating(RepeatingStyle::Repeating, &options);
+        let repetend =
+            |n, d| Fraction::new(n, d).to_english_repeating(RepeatingStyle::WithRepetend, &options);
+        assert_eq!(repeating(1, 3), "zero point three repeating");
+        assert_eq!(repetend(1, 3), "zero point three, with three repeating");
+        assert_eq!(
+            repeating(1, 7),
+            "zero point one four two eight five seven repeating"
+        );
+        assert_eq!(
+            repetend(-25, 12),
+            "negative two point zero eight three, with three repeating"
+        );
+        assert_eq!(
+            repetend(1, 11),
+            "zero point zero nine, with zero nine repeating"
+        );
+        assert_eq!(repeating(3, 8), "zero point three seven five");
+        assert_eq!(repetend(4, 2), "two");
+        assert_eq!(repeating(0, 5), "zero");
+    }
+
+    #[test]
+    fn test_mixed() {
+        let mixed = Options {
+            decimal_style: DecimalStyle::Mixed,
+            ..Default::default()
+        };
+        assert_eq!(
+            Fraction::new(11, 3).to_english_with(&mixed),
+            "three and two thirds"
+        );
+        assert_eq!(
+            Fraction::new(3, 2).to_english_with(&mixed),
+            "one and a half"
+        );
+        assert_eq!(
+            Fraction::new(17, 8).to_english_with(&mixed),
+            "two and an eighth"
+        );
+        assert_eq!(Fraction::new(8, 4).to_english_with(&mixed), "two");
+        assert_eq!(Fraction::new(2, 3).to_english_with(&mixed), "two thirds");
+        assert_eq!(1.5.to_english_with(&mixed), "one and a half");
+        assert_eq!(3.75.to_english_with(&mixed), "three and three quarters");
+        assert_eq!(2.125.to_english_with(&mixed), "two and an eighth");
+        assert_eq!(0.5.to_english_with(&mixed), "one half");
+        assert_eq!((-4.2).to_english_with(&mixed), "negative four and a fifth");
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(Fraction::new(3, 5).to_english(), "three fifths");
+        assert_eq!(Fraction::new(1, 2).to_english(), "one half");
+        assert_eq!(Fraction::new(3, 2).to_english(), "three halves");
+        assert_eq!(Fraction::new(1, 4).to_english(), "one quarter");
+        assert_eq!(Fraction::new(3, 4).to_english(), "three quarters");
+        assert_eq!(Fraction::new(1, 3).to_english(), "one third");
+        assert_eq!(Fraction::new(5, 8).to_english(), "five eighths");
+        assert_eq!(Fraction::new(1, 12).to_english(), "one twelfth");
+        assert_eq!(Fraction::new(7, 20).to_english(), "seven twentieths");
+        assert_eq!(Fraction::new(1, 21).to_english(), "one twenty-first");
+        assert_eq!(Fraction::new(2, 1_000).to_english(), "two thousandths");
+        assert_eq!(Fraction::new(1, 101).to_english(), "one one hundred first");
+        assert_eq!(Fraction::new(6, 1).to_english(), "six");
+        assert_eq!(Fraction::new(0, 7).to_english(), "zero");
+        assert_eq!(Fraction::new(1, -3).to_english(), "negative one third");
+        assert_eq!(Fraction::new(-1, -3).to_english(), "one third");
+        let minus = Options {
+            negative_word: "minus",
+            ..Default::default()
+        };
+        assert_eq!(
+            Fraction::new(-2, 9).to_english_with(&minus),
+            "minus two ninths"
+        );
+    }
+
+    #[test]
+    fn test_zero_denominator() {
+        assert_eq!(Fraction::try_new(3, 0), Err(Error::InvalidNumber));
+        let infinite = Fraction {
+            numerator: (-3).into(),
+            denominator: 0.into(),
+        };
+        let undefined = Fraction {
+            numerator: 0.into(),
+            denominator: 0.into(),
+        };
+        let options = Options::default();
+        assert_eq!(infinite.to_english(), "negative infinity");
+        assert_eq!(undefined.to_english(), "not a number");
+        assert_eq!(
+            infinite.to_english_percent(&options),
+            "negative infinity percent"
+        );
+        assert_eq!(
+            infinite.to_english_decimal(5, &options),
+            "negative infinity"
+        );
+        assert_eq!(
+            undefined.to_english_repeating(RepeatingStyle::Repeating, &options),
+            "not a number"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_zero_denominator() {
+        Fraction::new(3, 0);
+    }
+}
@@ -0,0 +1,277 @@
+use crate::Language;
+use alloc::{borrow::Cow, format, string::String};
+use core::fmt::{self, Write};
+
+/// The names of the numbers from one to sixteen, which French writes as single words.
+const UNITS: [&str; 16] = [
+    "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze", "douze",
+    "treize", "quatorze", "quinze", "seize",
+];
+
+/// The names of the tens from ten to sixty; the tens above are counted in twenties.
+const TENS: [&str; 6] = [
+    "dix",
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+];
+
+/// The names of each power of one thousand above "mille", in the long scale with "-illiard" names.
+const MAGNITUDES: [&str; 11] = [
+    "million",
+    "milliard",
+    "billion",
+    "billiard",
+    "trillion",
+    "trilliard",
+    "quadrillion",
+    "quadrilliard",
+    "quintillion",
+    "quintilliard",
+    "sextillion",
+];
+
+/// Numbers in French (e.g. 97 -> "quatre-vingt-dix-sept", 71 -> "soixante et onze").
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, French};
+/// assert_eq!(number_to_words(&97, &French::default()), "quatre-vingt-dix-sept");
+/// assert_eq!(number_to_words(&80_200, &French::default()), "quatre-vingt mille deux cents");
+///
+/// let reformed = French {
+///     hyphenate_all: true,
+/// };
+/// assert_eq!(number_to_words(&321, &reformed), "trois-cent-vingt-et-un");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct French {
+    /// Join every numeral with hyphens, as in the 1990 spelling reform (e.g. "deux-mille-vingt-et-un"
+    /// instead of "deux mille vingt et un"). The nouns "million", "milliard" and above stay apart.
+    pub hyphenate_all: bool,
+}
+
+impl French {
+    /// The separator between the numerals of a number.
+    fn joiner(&self) -> &'static str {
+        if self.hyphenate_all {
+            "-"
+        } else {
+            " "
+        }
+    }
+
+    /// Write a number from one to ninety-nine. A final "quatre-vingt" takes an "s" if `plural`.
+    fn write_below_hundred_as<W: Write>(
+        &self,
+        out: &mut W,
+        number: u64,
+        plural: bool,
+    ) -> fmt::Result {
+        let tens = number / 10;
+        let unit = number % 10;
+        let and = if self.hyphenate_all { "-et-" } else { " et " };
+        match (tens, unit) {
+            _ if number <= 16 => out.write_str(UNITS[number as usize - 1]),
+            (1, _) => write!(out, "dix-{}", UNITS[unit as usize - 1]),
+            // seventy and ninety count on from sixty and eighty (e.g. "soixante-douze")
+            (7, 1) => write!(out, "soixante{}onze", and),
+            (7, _) => {
+                out.write_str("soixante-")?;
+                self.write_below_hundred_as(out, 10 + unit, plural)
+            }
+            (8, 0) if plural => out.write_str("quatre-vingts"),
+            (8, 0) => out.write_str("quatre-vingt"),
+            // eighty-one has no "et" (e.g. "quatre-vingt-un")
+            (8, _) => write!(out, "quatre-vingt-{}", UNITS[unit as usize - 1]),
+            (9, _) => {
+                out.write_str("quatre-vingt-")?;
+                self.write_below_hundred_as(out, 10 + unit, plural)
+            }
+            (_, 0) => out.write_str(TENS[tens as usize - 1]),
+            (_, 1) => write!(out, "{}{}un", TENS[tens as usize - 1], and),
+            _ => write!(
+                out,
+                "{}-{}",
+                TENS[tens as usize - 1],
+                UNITS[unit as usize - 1]
+            ),
+        }
+    }
+
+    /// Write a number from one to nine hundred ninety-nine. A final "cent" or "quatre-vingt"
+    /// takes an "s" if `plural`, which it does unless "mille" follows.
+    fn write_hundreds_as<W: Write>(&self, out: &mut W, number: u64, plural: bool) -> fmt::Result {
+        let hundreds = number / 100;
+        let rest = number % 100;
+        if hundreds > 0 {
+            if hundreds > 1 {
+                out.write_str(UNITS[hundreds as usize - 1])?;
+                out.write_str(self.joiner())?;
+            }
+            out.write_str("cent")?;
+            if hundreds > 1 && rest == 0 && plural {
+                out.write_char('s')?;
+            }
+            if rest > 0 {
+                out.write_str(self.joiner())?;
+            }
+        }
+        if rest > 0 {
+            self.write_below_hundred_as(out, rest, plural)?;
+        }
+        Ok(())
+    }
+}
+
+impl Language for French {
+    fn zero(&self) -> &str {
+        "zéro"
+    }
+
+    fn negative(&self) -> &str {
+        "moins"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "virgule"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "cent"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str> {
+        if magnitude == 1 {
+            return Cow::Borrowed("mille");
+        }
+        let largest = MAGNITUDES.len() + 1;
+        let name = match MAGNITUDES.get(magnitude - 2) {
+            Some(name) => Cow::Borrowed(*name),
+            // past the named powers, the largest name is counted (e.g. "mille sextillions")
+            None => {
+                let inner = self.magnitude_name(magnitude - largest, group);
+                let of = if magnitude - largest == 1 {
+                    " "
+                } else {
+                    " de "
+                };
+                return Cow::Owned(format!("{}{}sextillions", inner, of));
+            }
+        };
+        if group > 1 {
+            let mut name = String::from(name);
+            name.push('s');
+            Cow::Owned(name)
+        } else {
+            name
+        }
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        self.write_hundreds_as(out, number, true)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        // "mille" is an invariable numeral, counted without "un" (e.g. "mille", "deux cent mille")
+        if magnitude == 1 {
+            if group > 1 {
+                self.write_hundreds_as(out, group as u64, false)?;
+                out.write_str(self.joiner())?;
+            }
+            return out.write_str("mille");
+        }
+        self.write_hundreds_as(out, group as u64, true)?;
+        if magnitude > 0 {
+            out.write_char(' ')?;
+            out.write_str(&self.magnitude_name(magnitude, group))?;
+        }
+        Ok(())
+    }
+
+    fn write_group_separator<W: Write>(
+        &self,
+        out: &mut W,
+        above: usize,
+        _magnitude: usize,
+        _group: u128,
+    ) -> fmt::Result {
+        // only the numeral "mille" is joined to what follows it
+        if above == 1 {
+            out.write_str(self.joiner())
+        } else {
+            out.write_char(' ')
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, French};
+
+    #[test]
+    fn test_french() {
+        let french = French::default();
+        for (number, words) in [
+            (0_i64, "zéro"),
+            (17, "dix-sept"),
+            (21, "vingt et un"),
+            (22, "vingt-deux"),
+            (61, "soixante et un"),
+            (70, "soixante-dix"),
+            (71, "soixante et onze"),
+            (77, "soixante-dix-sept"),
+            (80, "quatre-vingts"),
+            (81, "quatre-vingt-un"),
+            (91, "quatre-vingt-onze"),
+            (97, "quatre-vingt-dix-sept"),
+            (100, "cent"),
+            (200, "deux cents"),
+            (201, "deux cent un"),
+            (1_000, "mille"),
+            (1_001, "mille un"),
+            (200_000, "deux cent mille"),
+            (80_000, "quatre-vingt mille"),
+            (80_000_000, "quatre-vingts millions"),
+            (1_000_000, "un million"),
+            (2_000_000_000, "deux milliards"),
+            (1_000_000_000_000, "un billion"),
+            (-5, "moins cinq"),
+        ] {
+            assert_eq!(number_to_words(&number, &french), words);
+        }
+        assert_eq!(number_to_words(&1.25, &french), "un virgule deux cinq");
+        assert_eq!(
+            number_to_words(&(10_u128.pow(38) * 2), &french),
+            "deux cents sextillions"
+        );
+        #[cfg(feature = "bigint")]
+        {
+            let huge = num_bigint::BigInt::from(10).pow(42) * 3;
+            assert_eq!(
+                number_to_words(&huge, &french),
+                "trois millions de sextillions"
+            );
+        }
+
+        let reformed = French {
+            hyphenate_all: true,
+        };
+        assert_eq!(number_to_words(&71, &reformed), "soixante-et-onze");
+        assert_eq!(
+            number_to_words(&2_300_200, &reformed),
+            "deux millions trois-cent-mille-deux-cents"
+        );
+        assert_eq!(number_to_words(&1_000_005, &reformed), "un million cinq");
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{Case, NumberToEnglish, Options};
+use alloc::string::String;
+use core::fmt::Display;
+use num_traits::Num;
+
+/// How a count of times is read.
+///
+/// # Examples
+/// ```
+/// use num2english::{frequency_to_english, FrequencyStyle};
+/// let style = FrequencyStyle {
+///     thrice: false,
+///     ..Default::default()
+/// };
+/// assert_eq!(frequency_to_english(&3, &style), "three times");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FrequencyStyle {
+    /// Read three times as "thrice".
+    pub thrice: bool,
+    /// The options used to read the count.
+    pub options: Options,
+}
+
+impl Default for FrequencyStyle {
+    fn default() -> Self {
+        FrequencyStyle {
+            thrice: true,
+            options: Options::default(),
+        }
+    }
+}
+
+/// Convert a count to the number of times something happens in English
+/// (e.g. 1 -> "once", 2 -> "twice", 4 -> "four times").
+///
+/// # Examples
+/// ```
+/// use num2english::{frequency_to_english, FrequencyStyle};
+/// let style = FrequencyStyle::default();
+/// assert_eq!(frequency_to_english(&1, &style), "once");
+/// assert_eq!(frequency_to_english(&2, &style), "twice");
+/// assert_eq!(frequency_to_english(&3, &style), "thrice");
+/// assert_eq!(frequency_to_english(&4, &style), "four times");
+/// ```
+pub fn frequency_to_english<T: Num + Display>(count: &T, style: &FrequencyStyle) -> String {
+    let words = count.to_english_with(&Options {
+        case: Case::Lower,
+        ..style.options.clone()
+    });
+    let adverb = match words.as_str() {
+        "one" => Some("once"),
+        "two" => Some("twice"),
+        "three" if style.thrice => Some("thrice"),
+        _ => None,
+    };
+    let result = match adverb {
+        Some(adverb) => String::from(adverb),
+        None => {
+            let mut result = words;
+            result.push_str(" times");
+            result
+        }
+    };
+    style.options.case.apply(&result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{frequency_to_english, Case, FrequencyStyle, Options};
+
+    #[test]
+    fn test_frequency() {
+        let style = FrequencyStyle::default();
+        assert_eq!(frequency_to_english(&0, &style), "zero times");
+        assert_eq!(frequency_to_english(&1_u8, &style), "once");
+        assert_eq!(frequency_to_english(&1.0, &style), "once");
+        assert_eq!(frequency_to_english(&21, &style), "twenty-one times");
+        assert_eq!(
+            frequency_to_english(&1.5, &style),
+            "one and five tenths times"
+        );
+
+        let title = FrequencyStyle {
+            thrice: false,
+            options: Options {
+                case: Case::Title,
+                ..Default::default()
+            },
+        };
+        assert_eq!(frequency_to_english(&2, &title), "Twice");
+        assert_eq!(frequency_to_english(&3, &title), "Three Times");
+    }
+}
@@ -0,0 +1,240 @@
+use crate::Language;
+use alloc::{borrow::Cow, format, string::String};
+use core::fmt::{self, Write};
+
+/// The names of the numbers from one to nineteen, which German writes as single words.
+const UNITS: [&str; 19] = [
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+
+/// The names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "zehn", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+/// The names of each power of one thousand above "tausend", in the long scale with "-illiarde" names.
+const MAGNITUDES: [&str; 11] = [
+    "Million",
+    "Milliarde",
+    "Billion",
+    "Billiarde",
+    "Trillion",
+    "Trilliarde",
+    "Quadrillion",
+    "Quadrilliarde",
+    "Quintillion",
+    "Quintilliarde",
+    "Sextillion",
+];
+
+/// Numbers in German (e.g. 123 -> "einhundertdreiundzwanzig").
+///
+/// Numbers below one million are written as one word. A final one is "eins", but "ein" within
+/// a word (e.g. "einundzwanzig", "eintausend") and "eine" before "Million" and above.
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, German};
+/// assert_eq!(number_to_words(&123, &German::default()), "einhundertdreiundzwanzig");
+/// assert_eq!(number_to_words(&1_001, &German::default()), "eintausendeins");
+/// assert_eq!(number_to_words(&2_000_021, &German::default()), "zwei Millionen einundzwanzig");
+///
+/// let short = German {
+///     omit_leading_ein: true,
+/// };
+/// assert_eq!(number_to_words(&1_100, &short), "tausendhundert");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct German {
+    /// Leave out the "ein" before a leading "hundert" or "tausend" (e.g. "hundertzwei" instead of
+    /// "einhundertzwei").
+    pub omit_leading_ein: bool,
+}
+
+impl German {
+    /// Write a number from one to nine hundred ninety-nine, with a final one written as `one`.
+    fn write_hundreds_as<W: Write>(&self, out: &mut W, number: u64, one: &str) -> fmt::Result {
+        let hundreds = number / 100;
+        let rest = number % 100;
+        if hundreds > 0 {
+            match hundreds {
+                1 if self.omit_leading_ein => {}
+                1 => out.write_str("ein")?,
+                _ => out.write_str(UNITS[hundreds as usize - 1])?,
+            }
+            out.write_str("hundert")?;
+        }
+
+        let tens = rest / 10;
+        let unit = rest % 10;
+        match rest {
+            0 => Ok(()),
+            1 => out.write_str(one),
+            2..=19 => out.write_str(UNITS[rest as usize - 1]),
+            // the units come before the tens (e.g. "dreiundzwanzig")
+            _ if unit == 0 => out.write_str(TENS[tens as usize - 1]),
+            _ if unit == 1 => write!(out, "einund{}", TENS[tens as usize - 1]),
+            _ => write!(
+                out,
+                "{}und{}",
+                UNITS[unit as usize - 1],
+                TENS[tens as usize - 1]
+            ),
+        }
+    }
+}
+
+impl Language for German {
+    fn zero(&self) -> &str {
+        "null"
+    }
+
+    fn negative(&self) -> &str {
+        "minus"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "Komma"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "hundert"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str> {
+        if magnitude == 1 {
+            return Cow::Borrowed("tausend");
+        }
+        let largest = MAGNITUDES.len() + 1;
+        let name = match MAGNITUDES.get(magnitude - 2) {
+            Some(name) => *name,
+            // past the named powers, the largest name is counted (e.g. "tausend Sextillionen")
+            None => {
+                let inner = self.magnitude_name(magnitude - largest, group);
+                return Cow::Owned(format!("{} Sextillionen", inner));
+            }
+        };
+        if group == 1 {
+            Cow::Borrowed(name)
+        } else {
+            // "Million" -> "Millionen", "Milliarde" -> "Milliarden"
+            let mut name = String::from(name);
+            name.push_str(if name.ends_with('e') { "n" } else { "en" });
+            Cow::Owned(name)
+        }
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        self.write_hundreds_as(out, number, "eins")
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        match magnitude {
+            0 => self.write_hundreds_as(out, group as u64, "eins"),
+            1 => {
+                match group {
+                    1 if self.omit_leading_ein => {}
+                    _ => self.write_hundreds_as(out, group as u64, "ein")?,
+                }
+                out.write_str("tausend")
+            }
+            _ => {
+                // "Million" and above are feminine nouns (e.g. "eine Million")
+                match group {
+                    1 => out.write_str("eine")?,
+                    _ => self.write_hundreds_as(out, group as u64, "ein")?,
+                }
+                out.write_char(' ')?;
+                out.write_str(&self.magnitude_name(magnitude, group))
+            }
+        }
+    }
+
+    fn write_group_separator<W: Write>(
+        &self,
+        out: &mut W,
+        above: usize,
+        _magnitude: usize,
+        _group: u128,
+    ) -> fmt::Result {
+        // the thousands are joined to the rest of the word (e.g. "zweitausenddrei")
+        if above == 1 {
+            Ok(())
+        } else {
+            out.write_char(' ')
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, German};
+
+    #[test]
+    fn test_german() {
+        let german = German::default();
+        for (number, words) in [
+            (0_i64, "null"),
+            (1, "eins"),
+            (16, "sechzehn"),
+            (17, "siebzehn"),
+            (21, "einundzwanzig"),
+            (30, "dreißig"),
+            (99, "neunundneunzig"),
+            (101, "einhunderteins"),
+            (123, "einhundertdreiundzwanzig"),
+            (1_000, "eintausend"),
+            (21_000, "einundzwanzigtausend"),
+            (
+                999_999,
+                "neunhundertneunundneunzigtausendneunhundertneunundneunzig",
+            ),
+            (1_000_000, "eine Million"),
+            (1_000_001, "eine Million eins"),
+            (2_300_004, "zwei Millionen dreihunderttausendvier"),
+            (1_000_000_000, "eine Milliarde"),
+            (3_000_000_000, "drei Milliarden"),
+            (-6, "minus sechs"),
+        ] {
+            assert_eq!(number_to_words(&number, &german), words);
+        }
+        assert_eq!(number_to_words(&1.5, &german), "eins Komma fünf");
+        assert_eq!(
+            number_to_words(&(10_u128.pow(36) * 21), &german),
+            "einundzwanzig Sextillionen"
+        );
+
+        let short = German {
+            omit_leading_ein: true,
+        };
+        assert_eq!(number_to_words(&100, &short), "hundert");
+        assert_eq!(number_to_words(&1_001, &short), "tausendeins");
+        assert_eq!(number_to_words(&1_000_000, &short), "eine Million");
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{convert_non_finite_to_english, convert_number_to_english, decimal::Decimal, Options};
+use alloc::string::{String, ToString};
+use num_bigint::BigInt;
+
+/// Convert a half precision float, given as the bits of a `half::f16`, to English using the given [`Options`].
+///
+/// Like the `Display` output of `f32` and `f64`, the number is read as the shortest decimal
+/// that rounds back to the same value (e.g. the nearest `f16` to 0.1 is read as "one tenth",
+/// not as its exact value of 0.0999755859375).
+///
+/// # Examples
+/// ```
+/// use num2english::{f16_to_english, Options};
+/// assert_eq!(f16_to_english(0x3C00, &Options::default()), "one");
+/// assert_eq!(f16_to_english(0x2E66, &Options::default()), "one tenth");
+/// assert_eq!(f16_to_english(0xFC00, &Options::default()), "negative infinity");
+/// ```
+///
+/// A `half::f16` can be converted from its bits:
+/// ```ignore
+/// let number = half::f16::from_f32(2.5);
+/// assert_eq!(f16_to_english(number.to_bits(), &Options::default()), "two and five tenths");
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub fn f16_to_english(bits: u16, options: &Options) -> String {
+    options
+        .case
+        .apply(&convert_half_to_english(bits, 10, 5, options))
+}
+
+/// Convert a brain float, given as the bits of a `half::bf16`, to English using the given [`Options`].
+///
+/// The number is read as the shortest decimal that rounds back to the same value, as with [`f16_to_english`].
+///
+/// # Examples
+/// ```
+/// use num2english::{bf16_to_english, Options};
+/// assert_eq!(bf16_to_english(0x3F80, &Options::default()), "one");
+/// assert_eq!(bf16_to_english(0x3DCD, &Options::default()), "one tenth");
+/// ```
+///
+/// [`f16_to_english`]: fn.f16_to_english.html
+pub fn bf16_to_english(bits: u16, options: &Options) -> String {
+    options
+        .case
+        .apply(&convert_half_to_english(bits, 7, 8, options))
+}
+
+/// Convert the bits of a 16-bit float with the given field widths to its name in English.
+fn convert_half_to_english(
+    bits: u16,
+    mantissa_bits: u32,
+    exponent_bits: u32,
+    options: &Options,
+) -> String {
+    let bits = bits as u32;
+    let negative = bits >> 15 == 1;
+    let exponent_field = (bits >> mantissa_bits) & ((1 << exponent_bits) - 1);
+    let fraction = bits & ((1 << mantissa_bits) - 1);
+
+    if exponent_field == (1 << exponent_bits) - 1 {
+        let name = match (fraction, negative) {
+            (0, true) => "-inf",
+            (0, false) => "inf",
+            _ => "NaN",
+        };
+        return convert_non_finite_to_english(name, options).unwrap_or_default();
+    }
+
+    // subnormal numbers have no implicit leading bit and share the smallest normal exponent
+    let bias = (1_i64 << (exponent_bits - 1)) - 1;
+    let (mantissa, exponent) = if exponent_field == 0 {
+        (fraction, 1 - bias - mantissa_bits as i64)
+    } else {
+        (
+            fraction | (1 << mantissa_bits),
+            exponent_field as i64 - bias - mantissa_bits as i64,
+        )
+    };
+    // the gap to the next smaller value halves just above a power of two
+    let lower_closer = exponent_field > 1 && fraction == 0;
+
+    let mut result = String::new();
+    if negative && mantissa != 0 {
+        result.push_str(options.negative_word);
+        result.push(' ');
+    }
+    let decimal = shortest_decimal(mantissa, exponent, lower_closer);
+    result.push_str(&convert_number_to_english(
+        decimal.to_plain_string(),
+        options,
+    ));
+    result
+}
+
+/// Find the shortest decimal that rounds to `mantissa * 2^exponent` (e.g. 1638 * 2^-14 -> 0.1).
+fn shortest_decimal(mantissa: u32, exponent: i64, lower_closer: bool) -> Decimal {
+    // mantissa * 2^exponent is exactly mantissa * 5^-exponent * 10^exponent
+    let mut exact = if exponent >= 0 {
+        Decimal {
+            negative: false,
+            digits: (BigInt::from(mantissa) << exponent as usize).to_string(),
+            exponent: 0,
+        }
+    } else {
+        Decimal {
+            negative: false,
+            digits: (BigInt::from(mantissa) * BigInt::from(5).pow(exponent.unsigned_abs() as u32))
+                .to_string(),
+            exponent,
+        }
+    };
+    exact.normalize();
+
+    for significant in 1..exact.digits.len() {
+        let candidate = exact.round_significant(significant);
+        if rounds_to(&candidate, &exact, exponent, lower_closer) {
+            return candidate;
+        }
+    }
+    exact
+}
+
+/// Whether a decimal is strictly closer to a value than to its neighbours `2^exponent` away.
+fn rounds_to(candidate: &Decimal, exact: &Decimal, exponent: i64, lower_closer: bool) -> bool {
+    // scale every term to an integer by multiplying by 10^tens * 2^twos
+    let tens = (-candidate.exponent.min(exact.exponent)).max(0);
+    let half_gap = if lower_closer && candidate_below(candidate, exact) {
+        exponent - 2
+    } else {
+        exponent - 1
+    };
+    let twos = (-half_gap).max(0);
+
+    let scale = |decimal: &Decimal| {
+        let digits = BigInt::parse_bytes(decimal.digits.as_bytes(), 10).unwrap_or_default();
+        (digits * BigInt::from(10).pow((decimal.exponent + tens) as u32)) << twos as usize
+    };
+    let difference = scale(candidate) - scale(exact);
+    let half_gap = (BigInt::from(10).pow(tens as u32) << (half_gap + twos) as usize)
+        .magnitude()
+        .clone();
+    difference.magnitude() < &half_gap
+}
+
+/// Whether a decimal is below a value of the same sign.
+fn candidate_below(candidate: &Decimal, exact: &Decimal) -> bool {
+    let power = candidate.leading_power();
+    let exact_power = exact.leading_power();
+    if power != exact_power {
+        return power < exact_power;
+    }
+    // with the same leading power, the digit strings compare in order once padded
+    let width = candidate.digits.len().max(exact.digits.len());
+    let pad = |digits: &str| {
+        let mut padded = String::from(digits);
+        for _ in digits.len()..width {
+            padded.push('0');
+        }
+        padded
+    };
+    pad(&candidate.digits) < pad(&exact.digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bf16_to_english, f16_to_english, Options};
+
+    #[test]
+    fn test_half() {
+        let f16 = |bits| f16_to_english(bits, &Options::default());
+        assert_eq!(f16(0x0000), "zero");
+        assert_eq!(f16(0x8000), "zero");
+        assert_eq!(f16(0xC100), "negative two and five tenths");
+        assert_eq!(f16(0x7BFF), "sixty-five thousand five hundred");
+        assert_eq!(
+            f16(0x3555),
+            "three thousand three hundred thirty-three ten-thousandths"
+        );
+        assert_eq!(f16(0x0001), "six hundred-millionths");
+        assert_eq!(
+            f16(0x0400),
+            "six thousand one hundred four hundred-millionths"
+        );
+        assert_eq!(f16(0x7E00), "not a number");
+
+        let bf16 = |bits| bf16_to_english(bits, &Options::default());
+        assert_eq!(bf16(0x4049), "three and fourteen hundredths");
+        assert_eq!(bf16(0x4300), "one hundred twenty-eight");
+        assert_eq!(bf16(0x4780), "sixty-five thousand five hundred");
+        assert_eq!(bf16(0xFF80), "negative infinity");
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{language::write_integer_in, Language};
+use alloc::{borrow::Cow, string::ToString};
+use core::{
+    fmt::{self, Write},
+    iter::repeat_n,
+};
+
+/// The names of the numbers from one to ninety-nine, which Hindi names irregularly.
+const UNITS: [&str; 99] = [
+    "एक",
+    "दो",
+    "तीन",
+    "चार",
+    "पाँच",
+    "छह",
+    "सात",
+    "आठ",
+    "नौ",
+    "दस",
+    "ग्यारह",
+    "बारह",
+    "तेरह",
+    "चौदह",
+    "पंद्रह",
+    "सोलह",
+    "सत्रह",
+    "अठारह",
+    "उन्नीस",
+    "बीस",
+    "इक्कीस",
+    "बाईस",
+    "तेईस",
+    "चौबीस",
+    "पच्चीस",
+    "छब्बीस",
+    "सत्ताईस",
+    "अट्ठाईस",
+    "उनतीस",
+    "तीस",
+    "इकतीस",
+    "बत्तीस",
+    "तैंतीस",
+    "चौंतीस",
+    "पैंतीस",
+    "छत्तीस",
+    "सैंतीस",
+    "अड़तीस",
+    "उनतालीस",
+    "चालीस",
+    "इकतालीस",
+    "बयालीस",
+    "तैंतालीस",
+    "चवालीस",
+    "पैंतालीस",
+    "छियालीस",
+    "सैंतालीस",
+    "अड़तालीस",
+    "उनचास",
+    "पचास",
+    "इक्यावन",
+    "बावन",
+    "तिरपन",
+    "चौवन",
+    "पचपन",
+    "छप्पन",
+    "सत्तावन",
+    "अट्ठावन",
+    "उनसठ",
+    "साठ",
+    "इकसठ",
+    "बासठ",
+    "तिरसठ",
+    "चौंसठ",
+    "पैंसठ",
+    "छियासठ",
+    "सड़सठ",
+    "अड़सठ",
+    "उनहत्तर",
+    "सत्तर",
+    "इकहत्तर",
+    "बहत्तर",
+    "तिहत्तर",
+    "चौहत्तर",
+    "पचहत्तर",
+    "छिहत्तर",
+    "सतहत्तर",
+    "अठहत्तर",
+    "उन्यासी",
+    "अस्सी",
+    "इक्यासी",
+    "बयासी",
+    "तिरासी",
+    "चौरासी",
+    "पचासी",
+    "छियासी",
+    "सत्तासी",
+    "अट्ठासी",
+    "नवासी",
+    "नब्बे",
+    "इक्यानवे",
+    "बानवे",
+    "तिरानवे",
+    "चौरानवे",
+    "पंचानवे",
+    "छियानवे",
+    "सत्तानवे",
+    "अट्ठानवे",
+    "निन्यानवे",
+];
+
+/// The names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "दस",
+    "बीस",
+    "तीस",
+    "चालीस",
+    "पचास",
+    "साठ",
+    "सत्तर",
+    "अस्सी",
+    "नब्बे",
+];
+
+/// The names of the groups above the hundreds: a thousand, then each power of one hundred.
+const MAGNITUDES: [&str; 8] = ["हज़ार", "लाख", "करोड़", "अरब", "खरब", "नील", "पद्म", "शंख"];
+
+/// Numbers in Hindi (e.g. 1234567 -> "बारह लाख चौंतीस हज़ार पाँच सौ सड़सठ").
+///
+/// The numbers from one to ninety-nine each have their own name. Above the hundreds, numbers
+/// are grouped by two digits, in thousands, lakhs (10^5), crores (10^7) and on to "शंख" (10^17),
+/// which counts any larger number (e.g. 10^19 -> "एक सौ शंख").
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Hindi};
+/// assert_eq!(number_to_words(&23, &Hindi), "तेईस");
+/// assert_eq!(number_to_words(&123, &Hindi), "एक सौ तेईस");
+/// assert_eq!(number_to_words(&150_000, &Hindi), "एक लाख पचास हज़ार");
+/// assert_eq!(number_to_words(&20_000_000, &Hindi), "दो करोड़");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Hindi;
+
+impl Language for Hindi {
+    fn zero(&self) -> &str {
+        "शून्य"
+    }
+
+    fn negative(&self) -> &str {
+        "ऋण"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "दशमलव"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "सौ"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, _group: u128) -> Cow<'_, str> {
+        Cow::Borrowed(MAGNITUDES[(magnitude - 1).min(MAGNITUDES.len() - 1)])
+    }
+
+    fn group_digits(&self, magnitude: usize) -> u32 {
+        match magnitude {
+            0 => 3,
+            // the largest name counts the rest of the number as a whole
+            _ if magnitude >= MAGNITUDES.len() => 38,
+            _ => 2,
+        }
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        if magnitude >= MAGNITUDES.len() {
+            // "शंख" counts a whole number, and each wider group above it is 10^38 times larger
+            let shift = 38 * (magnitude - MAGNITUDES.len());
+            let mut digits = group.to_string();
+            digits.extend(repeat_n('0', shift));
+            write_integer_in(out, &digits, self)?;
+        } else {
+            self.write_hundreds(out, group as u64)?;
+        }
+        if magnitude > 0 {
+            out.write_char(' ')?;
+            out.write_str(&self.magnitude_name(magnitude, group))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Hindi};
+
+    #[test]
+    fn test_hindi() {
+        for (number, words) in [
+            (0_i64, "शून्य"),
+            (5, "पाँच"),
+            (19, "उन्नीस"),
+            (23, "तेईस"),
+            (67, "सड़सठ"),
+            (99, "निन्यानवे"),
+            (100, "एक सौ"),
+            (123, "एक सौ तेईस"),
+            (1_000, "एक हज़ार"),
+            (12_345, "बारह हज़ार तीन सौ पैंतालीस"),
+            (100_000, "एक लाख"),
+            (1_234_567, "बारह लाख चौंतीस हज़ार पाँच सौ सड़सठ"),
+            (10_000_000, "एक करोड़"),
+            (1_000_000_000, "एक अरब"),
+            (100_000_000_000_000_000, "एक शंख"),
+            (-8, "ऋण आठ"),
+        ] {
+            assert_eq!(number_to_words(&number, &Hindi), words);
+        }
+        assert_eq!(number_to_words(&0.5, &Hindi), "शून्य दशमलव पाँच");
+        assert_eq!(number_to_words(&10_u128.pow(19), &Hindi), "एक सौ शंख");
+        assert_eq!(
+            number_to_words(&(10_u128.pow(22) * 3), &Hindi),
+            "तीन लाख शंख"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use crate::Language;
+use alloc::{borrow::Cow, format, string::String};
+use core::fmt::{self, Write};
+
+/// The names of the numbers from one to nineteen, which Italian writes as single words.
+const UNITS: [&str; 19] = [
+    "uno",
+    "due",
+    "tre",
+    "quattro",
+    "cinque",
+    "sei",
+    "sette",
+    "otto",
+    "nove",
+    "dieci",
+    "undici",
+    "dodici",
+    "tredici",
+    "quattordici",
+    "quindici",
+    "sedici",
+    "diciassette",
+    "diciotto",
+    "diciannove",
+];
+
+/// The names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "dieci",
+    "venti",
+    "trenta",
+    "quaranta",
+    "cinquanta",
+    "sessanta",
+    "settanta",
+    "ottanta",
+    "novanta",
+];
+
+/// The names of each power of one thousand above "mille", in the long scale with "-iliardo" names.
+const MAGNITUDES: [&str; 11] = [
+    "milione",
+    "miliardo",
+    "bilione",
+    "biliardo",
+    "trilione",
+    "triliardo",
+    "quadrilione",
+    "quadriliardo",
+    "quintilione",
+    "quintiliardo",
+    "sestilione",
+];
+
+/// Numbers in Italian (e.g. 123 -> "centoventitré").
+///
+/// Numbers below one million are written as one word. A ten drops its last vowel before "uno"
+/// and "otto" (e.g. "ottantuno", "ventotto"), and a final "tre" in a compound is accented
+/// (e.g. "trentatré").
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Italian};
+/// assert_eq!(number_to_words(&123, &Italian), "centoventitré");
+/// assert_eq!(number_to_words(&81, &Italian), "ottantuno");
+/// assert_eq!(number_to_words(&21_000, &Italian), "ventunmila");
+/// assert_eq!(number_to_words(&2_000_000, &Italian), "due milioni");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Italian;
+
+impl Italian {
+    /// Write a number from one to nine hundred ninety-nine. A final one is written "un" if `short`,
+    /// as before "mila" and "milioni", and otherwise "uno". A final "tre" is accented if `last`.
+    fn write_hundreds_as<W: Write>(
+        &self,
+        out: &mut W,
+        number: u64,
+        short: bool,
+        last: bool,
+    ) -> fmt::Result {
+        let hundreds = number / 100;
+        let rest = number % 100;
+        let tens = rest / 10;
+        let unit = rest % 10;
+        // "otto" and "ottanta" elide the vowel before them (e.g. "centotto", "centottanta")
+        let elides = rest == 8 || tens == 8;
+        if hundreds > 0 {
+            if hundreds > 1 {
+                out.write_str(UNITS[hundreds as usize - 1])?;
+            }
+            out.write_str(if elides { "cent" } else { "cento" })?;
+        }
+
+        let unit_name = |unit: u64| match unit {
+            1 if short => "un",
+            3 if last && number > 3 => "tré",
+            _ => UNITS[unit as usize - 1],
+        };
+        match rest {
+            0 => Ok(()),
+            1..=9 => out.write_str(unit_name(rest)),
+            10..=19 => out.write_str(UNITS[rest as usize - 1]),
+            _ => {
+                let ten = TENS[tens as usize - 1];
+                match unit {
+                    0 => out.write_str(ten),
+                    1 | 8 => write!(out, "{}{}", &ten[..ten.len() - 1], unit_name(unit)),
+                    _ => write!(out, "{}{}", ten, unit_name(unit)),
+                }
+            }
+        }
+    }
+}
+
+impl Language for Italian {
+    fn zero(&self) -> &str {
+        "zero"
+    }
+
+    fn negative(&self) -> &str {
+        "meno"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "virgola"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "cento"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str> {
+        if magnitude == 1 {
+            return Cow::Borrowed(if group == 1 { "mille" } else { "mila" });
+        }
+        let largest = MAGNITUDES.len() + 1;
+        let name = match MAGNITUDES.get(magnitude - 2) {
+            Some(name) => *name,
+            // past the named powers, the largest name is counted (e.g. "mila sestilioni")
+            None => {
+                let inner = self.magnitude_name(magnitude - largest, group);
+                return Cow::Owned(format!("{} di sestilioni", inner));
+            }
+        };
+        if group == 1 {
+            Cow::Borrowed(name)
+        } else {
+            // "milione" -> "milioni", "miliardo" -> "miliardi"
+            let mut name = String::from(&name[..name.len() - 1]);
+            name.push('i');
+            Cow::Owned(name)
+        }
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        self.write_hundreds_as(out, number, false, true)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        match (magnitude, group) {
+            (0, _) => self.write_hundreds_as(out, group as u64, false, true),
+            // "mille" is not counted with "un", and the thousands are joined to their count
+            (1, 1) => out.write_str("mille"),
+            (1, _) => {
+                self.write_hundreds_as(out, group as u64, true, false)?;
+                out.write_str("mila")
+            }
+            (_, 1) => write!(out, "un {}", self.magnitude_name(magnitude, group)),
+            // the millions are a separate word, so their count keeps its accent ("ventitré milioni")
+            _ => {
+                self.write_hundreds_as(out, group as u64, true, true)?;
+                write!(out, " {}", self.magnitude_name(magnitude, group))
+            }
+        }
+    }
+
+    fn write_group_separator<W: Write>(
+        &self,
+        out: &mut W,
+        above: usize,
+        _magnitude: usize,
+        _group: u128,
+    ) -> fmt::Result {
+        // the thousands are joined to the rest of the word (e.g. "milleduecento")
+        if above == 1 {
+            Ok(())
+        } else {
+            out.write_char(' ')
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Italian};
+
+    #[test]
+    fn test_italian() {
+        for (number, words) in [
+            (0_i64, "zero"),
+            (3, "tre"),
+            (17, "diciassette"),
+            (21, "ventuno"),
+            (28, "ventotto"),
+            (33, "trentatré"),
+            (81, "ottantuno"),
+            (88, "ottantotto"),
+            (100, "cento"),
+            (103, "centotré"),
+            (108, "centotto"),
+            (180, "centottanta"),
+            (123, "centoventitré"),
+            (1_000, "mille"),
+            (1_001, "milleuno"),
+            (1_200, "milleduecento"),
+            (2_000, "duemila"),
+            (21_000, "ventunmila"),
+            (23_000, "ventitremila"),
+            (1_000_000, "un milione"),
+            (21_000_000, "ventun milioni"),
+            (23_000_000, "ventitré milioni"),
+            (23_000_003, "ventitré milioni tre"),
+            (2_300_000, "due milioni trecentomila"),
+            (1_000_000_000, "un miliardo"),
+            (5_000_000_000, "cinque miliardi"),
+            (-9, "meno nove"),
+        ] {
+            assert_eq!(number_to_words(&number, &Italian), words);
+        }
+        assert_eq!(number_to_words(&-0.5, &Italian), "meno zero virgola cinque");
+        assert_eq!(
+            number_to_words(&(10_u128.pow(36) * 2), &Italian),
+            "due sestilioni"
+        );
+    }
+}
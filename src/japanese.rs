@@ -0,0 +1,299 @@
+use crate::Language;
+use alloc::{borrow::Cow, format, string::String};
+use core::fmt::{self, Write};
+
+/// The names of the numbers from one to nine in kanji.
+const UNITS: [&str; 9] = ["一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+/// The names of the tens from ten to ninety in kanji.
+const TENS: [&str; 9] = [
+    "十", "二十", "三十", "四十", "五十", "六十", "七十", "八十", "九十",
+];
+
+/// The names of each power of ten thousand in kanji.
+const MAGNITUDES: [&str; 12] = [
+    "万", "億", "兆", "京", "垓", "秭", "穣", "溝", "澗", "正", "載", "極",
+];
+
+/// The names of the numbers from one to nine in romaji.
+const ROMAJI_UNITS: [&str; 9] = [
+    "ichi", "ni", "san", "yon", "go", "roku", "nana", "hachi", "kyū",
+];
+
+/// The names of the tens from ten to ninety in romaji.
+const ROMAJI_TENS: [&str; 9] = [
+    "jū", "nijū", "sanjū", "yonjū", "gojū", "rokujū", "nanajū", "hachijū", "kyūjū",
+];
+
+/// The names of the hundreds from one hundred to nine hundred in romaji, with their sound changes.
+const ROMAJI_HUNDREDS: [&str; 9] = [
+    "hyaku",
+    "nihyaku",
+    "sanbyaku",
+    "yonhyaku",
+    "gohyaku",
+    "roppyaku",
+    "nanahyaku",
+    "happyaku",
+    "kyūhyaku",
+];
+
+/// The names of the thousands from one thousand to nine thousand in romaji, with their sound
+/// changes.
+const ROMAJI_THOUSANDS: [&str; 9] = [
+    "sen", "nisen", "sanzen", "yonsen", "gosen", "rokusen", "nanasen", "hassen", "kyūsen",
+];
+
+/// The names of each power of ten thousand in romaji.
+const ROMAJI_MAGNITUDES: [&str; 12] = [
+    "man", "oku", "chō", "kei", "gai", "jo", "jō", "kō", "kan", "sei", "sai", "goku",
+];
+
+/// Numbers in Japanese (e.g. 12345 -> "一万二千三百四十五").
+///
+/// Numbers are grouped by four digits, in powers of ten thousand (万, 億, 兆 and above).
+/// "十", "百" and "千" are not counted with "一", except "一千" before a power of ten thousand
+/// (e.g. "千", but "一千万").
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Japanese};
+/// assert_eq!(number_to_words(&12_345, &Japanese::default()), "一万二千三百四十五");
+/// assert_eq!(number_to_words(&100_000_000, &Japanese::default()), "一億");
+///
+/// let romaji = Japanese {
+///     romaji: true,
+/// };
+/// assert_eq!(number_to_words(&123, &romaji), "hyaku nijūsan");
+/// assert_eq!(number_to_words(&10_000_000, &romaji), "issenman");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Japanese {
+    /// Write numbers in Hepburn romaji instead of kanji, with spaces between the hundreds,
+    /// thousands and powers of ten thousand (e.g. "sanbyaku", "itchō").
+    pub romaji: bool,
+}
+
+impl Japanese {
+    /// Write a number from one to nine thousand nine hundred ninety-nine in kanji. A leading
+    /// thousand is counted with "一" if `counted`.
+    fn write_kanji_group<W: Write>(&self, out: &mut W, number: u64, counted: bool) -> fmt::Result {
+        for (digit, name) in [
+            (number / 1000, "千"),
+            (number / 100 % 10, "百"),
+            (number / 10 % 10, "十"),
+        ] {
+            match digit {
+                0 => {}
+                1 if name == "千" && counted => out.write_str("一千")?,
+                1 => out.write_str(name)?,
+                _ => write!(out, "{}{}", UNITS[digit as usize - 1], name)?,
+            }
+        }
+        match number % 10 {
+            0 => Ok(()),
+            unit => out.write_str(UNITS[unit as usize - 1]),
+        }
+    }
+
+    /// Write a number from one to nine thousand nine hundred ninety-nine in romaji. A leading
+    /// thousand is "issen" if `counted`.
+    fn write_romaji_group<W: Write>(&self, out: &mut W, number: u64, counted: bool) -> fmt::Result {
+        let mut separator = "";
+        match number / 1000 {
+            0 => {}
+            1 if counted => {
+                out.write_str("issen")?;
+                separator = " ";
+            }
+            thousands => {
+                out.write_str(ROMAJI_THOUSANDS[thousands as usize - 1])?;
+                separator = " ";
+            }
+        }
+        let hundreds = number / 100 % 10;
+        if hundreds > 0 {
+            out.write_str(separator)?;
+            out.write_str(ROMAJI_HUNDREDS[hundreds as usize - 1])?;
+            separator = " ";
+        }
+        let rest = number % 100;
+        if rest > 0 {
+            out.write_str(separator)?;
+            self.write_below_hundred(out, rest)?;
+        }
+        Ok(())
+    }
+}
+
+impl Language for Japanese {
+    fn zero(&self) -> &str {
+        if self.romaji {
+            "zero"
+        } else {
+            "零"
+        }
+    }
+
+    fn negative(&self) -> &str {
+        if self.romaji {
+            "mainasu"
+        } else {
+            "マイナス"
+        }
+    }
+
+    fn decimal_point(&self) -> &str {
+        if self.romaji {
+            "ten"
+        } else {
+            "点"
+        }
+    }
+
+    fn units(&self) -> &[&str] {
+        if self.romaji {
+            &ROMAJI_UNITS
+        } else {
+            &UNITS
+        }
+    }
+
+    fn tens(&self) -> &[&str] {
+        if self.romaji {
+            &ROMAJI_TENS
+        } else {
+            &TENS
+        }
+    }
+
+    fn hundred(&self) -> &str {
+        if self.romaji {
+            "hyaku"
+        } else {
+            "百"
+        }
+    }
+
+    fn magnitude_name(&self, magnitude: usize, _group: u128) -> Cow<'_, str> {
+        let names = if self.romaji {
+            &ROMAJI_MAGNITUDES
+        } else {
+            &MAGNITUDES
+        };
+        match names.get(magnitude - 1) {
+            Some(name) => Cow::Borrowed(name),
+            // past the named powers, the largest name is counted (e.g. "万極")
+            None => {
+                let inner = self.magnitude_name(magnitude - names.len(), 1);
+                Cow::Owned(format!("{}{}", inner, names[names.len() - 1]))
+            }
+        }
+    }
+
+    fn group_digits(&self, _magnitude: usize) -> u32 {
+        4
+    }
+
+    fn word_separator(&self) -> &str {
+        if self.romaji {
+            " "
+        } else {
+            ""
+        }
+    }
+
+    fn tens_separator(&self) -> &str {
+        ""
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        self.write_group(out, number as u128, 0)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        if !self.romaji {
+            self.write_kanji_group(out, group as u64, magnitude > 0)?;
+            if magnitude > 0 {
+                out.write_str(&self.magnitude_name(magnitude, group))?;
+            }
+            return Ok(());
+        }
+
+        let mut count = String::new();
+        self.write_romaji_group(&mut count, group as u64, magnitude > 0)?;
+        if magnitude > 0 {
+            let name = self.magnitude_name(magnitude, group);
+            // "ichi", "hachi" and "jū" are shortened before "chō" and "kei" (e.g. "itchō", "jukkei")
+            let doubled = match name.as_bytes()[0] {
+                b'c' => Some('t'),
+                b'k' => Some('k'),
+                _ => None,
+            };
+            if let Some(doubled) = doubled {
+                if let Some(stem) = count.strip_suffix("chi") {
+                    count.truncate(stem.len());
+                    count.push(doubled);
+                } else if let Some(stem) = count.strip_suffix("jū") {
+                    count.truncate(stem.len());
+                    count.push_str("ju");
+                    count.push(doubled);
+                }
+            }
+            count.push_str(&name);
+        }
+        out.write_str(&count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Japanese};
+
+    #[test]
+    fn test_japanese() {
+        let kanji = Japanese::default();
+        for (number, words) in [
+            (0_i64, "零"),
+            (10, "十"),
+            (11, "十一"),
+            (23, "二十三"),
+            (100, "百"),
+            (123, "百二十三"),
+            (1_000, "千"),
+            (1_234, "千二百三十四"),
+            (10_000, "一万"),
+            (12_345, "一万二千三百四十五"),
+            (10_000_000, "一千万"),
+            (100_000_000, "一億"),
+            (100_000_001, "一億一"),
+            (200_030_000, "二億三万"),
+            (1_000_000_000_000, "一兆"),
+            (-5, "マイナス五"),
+        ] {
+            assert_eq!(number_to_words(&number, &kanji), words);
+        }
+        assert_eq!(number_to_words(&2.25, &kanji), "二点二五");
+        assert_eq!(number_to_words(&10_u128.pow(36), &kanji), "一澗");
+
+        let romaji = Japanese { romaji: true };
+        for (number, words) in [
+            (0_i64, "zero"),
+            (14, "jūyon"),
+            (300, "sanbyaku"),
+            (600, "roppyaku"),
+            (8_000, "hassen"),
+            (10_000, "ichiman"),
+            (12_345, "ichiman nisen sanbyaku yonjūgo"),
+            (10_000_000, "issenman"),
+            (100_000_000, "ichioku"),
+            (1_000_000_000_000, "itchō"),
+            (10_000_000_000_000, "jutchō"),
+            (80_000_000_000_000_000, "hakkei"),
+            (-2, "mainasu ni"),
+        ] {
+            assert_eq!(number_to_words(&number, &romaji), words);
+        }
+        assert_eq!(number_to_words(&3.5, &romaji), "san ten go");
+    }
+}
@@ -0,0 +1,230 @@
+use crate::Language;
+use alloc::{borrow::Cow, format};
+use core::fmt::{self, Write};
+
+/// The Sino-Korean names of the numbers from one to nine.
+const UNITS: [&str; 9] = ["일", "이", "삼", "사", "오", "육", "칠", "팔", "구"];
+
+/// The Sino-Korean names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "십", "이십", "삼십", "사십", "오십", "육십", "칠십", "팔십", "구십",
+];
+
+/// The native Korean names of the numbers from one to nine.
+const NATIVE_UNITS: [&str; 9] = [
+    "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+
+/// The native Korean names of the tens from ten to ninety.
+const NATIVE_TENS: [&str; 9] = [
+    "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+/// The names of each power of ten thousand.
+const MAGNITUDES: [&str; 12] = [
+    "만", "억", "조", "경", "해", "자", "양", "구", "간", "정", "재", "극",
+];
+
+/// The counting system that numbers are named in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum KoreanSystem {
+    /// Sino-Korean numbers, used for dates, money and measures (e.g. 23 -> "이십삼").
+    #[default]
+    SinoKorean,
+    /// Native Korean numbers, used for counting things and ages (e.g. 23 -> "스물셋"). Native
+    /// names only go up to ninety-nine, so the hundreds and above are Sino-Korean
+    /// (e.g. 123 -> "백스물셋").
+    Native,
+}
+
+/// Numbers in Korean (e.g. 12345 -> "만 이천삼백사십오").
+///
+/// Numbers are grouped by four digits, in powers of ten thousand (만, 억, 조 and above), with a
+/// space between the groups. "십", "백", "천" and "만" are not counted with "일".
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Korean, KoreanSystem};
+/// assert_eq!(number_to_words(&12_345, &Korean::default()), "만 이천삼백사십오");
+/// assert_eq!(number_to_words(&100_000_000, &Korean::default()), "일억");
+///
+/// let native = Korean {
+///     system: KoreanSystem::Native,
+///     ..Default::default()
+/// };
+/// assert_eq!(number_to_words(&23, &native), "스물셋");
+///
+/// let counter = Korean {
+///     system: KoreanSystem::Native,
+///     attributive: true,
+/// };
+/// assert_eq!(number_to_words(&23, &counter), "스물세");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Korean {
+    /// The counting system.
+    pub system: KoreanSystem,
+    /// Write the shortened native forms used before a counter (e.g. "한 개", "스무 살")
+    /// instead of "하나" and "스물". Sino-Korean numbers have no such forms.
+    pub attributive: bool,
+}
+
+impl Korean {
+    /// Write a number from one to ninety-nine in native Korean.
+    fn write_native<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        let tens = number / 10;
+        let unit = number % 10;
+        if tens > 0 {
+            match tens {
+                2 if unit == 0 && self.attributive => out.write_str("스무")?,
+                _ => out.write_str(NATIVE_TENS[tens as usize - 1])?,
+            }
+        }
+        match unit {
+            0 => Ok(()),
+            1..=4 if self.attributive => out.write_str(["한", "두", "세", "네"][unit as usize - 1]),
+            _ => out.write_str(NATIVE_UNITS[unit as usize - 1]),
+        }
+    }
+}
+
+impl Language for Korean {
+    fn zero(&self) -> &str {
+        "영"
+    }
+
+    fn negative(&self) -> &str {
+        "마이너스"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "점"
+    }
+
+    // the digits after the decimal point are always Sino-Korean
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "백"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, _group: u128) -> Cow<'_, str> {
+        match MAGNITUDES.get(magnitude - 1) {
+            Some(name) => Cow::Borrowed(name),
+            // past the named powers, the largest name is counted (e.g. "만극")
+            None => {
+                let inner = self.magnitude_name(magnitude - MAGNITUDES.len(), 1);
+                Cow::Owned(format!("{}{}", inner, MAGNITUDES[MAGNITUDES.len() - 1]))
+            }
+        }
+    }
+
+    fn group_digits(&self, _magnitude: usize) -> u32 {
+        4
+    }
+
+    fn tens_separator(&self) -> &str {
+        ""
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        self.write_group(out, number as u128, 0)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        let number = group as u64;
+        let native = magnitude == 0 && self.system == KoreanSystem::Native;
+        let digits: &[(u64, &str)] = if native {
+            &[(number / 1000, "천"), (number / 100 % 10, "백")]
+        } else {
+            &[
+                (number / 1000, "천"),
+                (number / 100 % 10, "백"),
+                (number / 10 % 10, "십"),
+            ]
+        };
+        for &(digit, name) in digits {
+            match digit {
+                0 => {}
+                1 => out.write_str(name)?,
+                _ => write!(out, "{}{}", UNITS[digit as usize - 1], name)?,
+            }
+        }
+        if native {
+            if !number.is_multiple_of(100) {
+                self.write_native(out, number % 100)?;
+            }
+        } else {
+            match number % 10 {
+                0 => {}
+                // "만" is not counted with "일", but "억" and above are (e.g. "만", "일억")
+                1 if number == 1 && magnitude == 1 => {}
+                unit => out.write_str(UNITS[unit as usize - 1])?,
+            }
+        }
+        if magnitude > 0 {
+            out.write_str(&self.magnitude_name(magnitude, group))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Korean, KoreanSystem};
+
+    #[test]
+    fn test_korean() {
+        let korean = Korean::default();
+        for (number, words) in [
+            (0_i64, "영"),
+            (10, "십"),
+            (11, "십일"),
+            (23, "이십삼"),
+            (100, "백"),
+            (123, "백이십삼"),
+            (1_000, "천"),
+            (10_000, "만"),
+            (12_345, "만 이천삼백사십오"),
+            (20_000, "이만"),
+            (100_000_000, "일억"),
+            (123_456_789, "일억 이천삼백사십오만 육천칠백팔십구"),
+            (1_000_000_000_000, "일조"),
+            (-5, "마이너스 오"),
+        ] {
+            assert_eq!(number_to_words(&number, &korean), words);
+        }
+        assert_eq!(number_to_words(&2.5, &korean), "이 점 오");
+
+        let native = Korean {
+            system: KoreanSystem::Native,
+            attributive: false,
+        };
+        for (number, words) in [
+            (1_i64, "하나"),
+            (20, "스물"),
+            (21, "스물하나"),
+            (99, "아흔아홉"),
+            (123, "백스물셋"),
+            (10_005, "만 다섯"),
+            (20_000, "이만"),
+        ] {
+            assert_eq!(number_to_words(&number, &native), words);
+        }
+        assert_eq!(number_to_words(&1.5, &native), "하나 점 오");
+
+        let counter = Korean {
+            system: KoreanSystem::Native,
+            attributive: true,
+        };
+        for (number, words) in [(1_i64, "한"), (4, "네"), (20, "스무"), (23, "스물세")] {
+            assert_eq!(number_to_words(&number, &counter), words);
+        }
+    }
+}
@@ -0,0 +1,519 @@
+use crate::{
+    expand_scientific,
+    scales::{ONE_TO_NINETEEN, TENS},
+    write_group_separator, write_hundreds_to_english, write_magnitude_name,
+    write_number_to_english, write_u128_to_english, CaseWriter, Options,
+};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display, Write};
+use num_traits::Num;
+
+/// A language that numbers are named in.
+///
+/// A language gives the names of its units, tens and magnitudes, and the rules that join them.
+/// Splitting a number into groups and reading them from the largest down is shared by every
+/// language, so most languages only override the methods that read a single group.
+///
+/// English is the default language, written with its [`Options`].
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Language};
+/// use std::borrow::Cow;
+///
+/// struct Esperanto;
+///
+/// impl Language for Esperanto {
+///     fn zero(&self) -> &str {
+///         "nul"
+///     }
+///     fn negative(&self) -> &str {
+///         "minus"
+///     }
+///     fn decimal_point(&self) -> &str {
+///         "komo"
+///     }
+///     fn units(&self) -> &[&str] {
+///         &["unu", "du", "tri", "kvar", "kvin", "ses", "sep", "ok", "naŭ"]
+///     }
+///     fn tens(&self) -> &[&str] {
+///         &["dek", "dudek", "tridek", "kvardek", "kvindek", "sesdek", "sepdek", "okdek", "naŭdek"]
+///     }
+///     fn hundred(&self) -> &str {
+///         "cent"
+///     }
+///     fn magnitude_name(&self, magnitude: usize, _group: u128) -> Cow<'_, str> {
+///         Cow::Borrowed(["mil", "miliono"][magnitude - 1])
+///     }
+///     fn tens_separator(&self) -> &str {
+///         " "
+///     }
+/// }
+///
+/// assert_eq!(number_to_words(&42, &Esperanto), "kvardek du");
+/// assert_eq!(number_to_words(&-7_003, &Esperanto), "minus sep mil tri");
+/// assert_eq!(number_to_words(&0.25, &Esperanto), "nul komo du kvin");
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub trait Language {
+    /// The name of zero (e.g. "zero").
+    fn zero(&self) -> &str;
+
+    /// The word before a negative number (e.g. "negative").
+    fn negative(&self) -> &str;
+
+    /// The word between the integer part and the digits of the decimal part (e.g. "point").
+    fn decimal_point(&self) -> &str;
+
+    /// The names of the numbers from one up to the first that is not named in one word,
+    /// at least one to nine (e.g. "one" to "nineteen").
+    fn units(&self) -> &[&str];
+
+    /// The names of the tens from ten to ninety.
+    fn tens(&self) -> &[&str];
+
+    /// The name of the hundreds (e.g. "hundred").
+    fn hundred(&self) -> &str;
+
+    /// The name of the group at a magnitude above the units group (e.g. 1 -> "thousand"),
+    /// given the value of the group so that the name can agree with it (e.g. "million", "millions").
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str>;
+
+    /// The number of digits in the group at a magnitude, counting from the units group.
+    ///
+    /// Groups are at most thirty-eight digits wide. Every group is three digits by default.
+    fn group_digits(&self, _magnitude: usize) -> u32 {
+        3
+    }
+
+    /// The separator between the words of a number, which is a space by default. Languages
+    /// that write numbers without spaces (e.g. Japanese "千二百") leave it empty.
+    fn word_separator(&self) -> &str {
+        " "
+    }
+
+    /// The separator between the tens and the units (e.g. "-" for "twenty-one").
+    fn tens_separator(&self) -> &str {
+        "-"
+    }
+
+    /// Write the name of a number from one to ninety-nine.
+    ///
+    /// By default a number is taken from the units if it is named there, and is otherwise read
+    /// as its tens and units joined by the [`tens_separator`].
+    ///
+    /// [`tens_separator`]: #method.tens_separator
+    fn write_below_hundred<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        if let Some(name) = self.units().get(number as usize - 1) {
+            return out.write_str(name);
+        }
+        out.write_str(self.tens()[number as usize / 10 - 1])?;
+        if !number.is_multiple_of(10) {
+            out.write_str(self.tens_separator())?;
+            out.write_str(self.units()[number as usize % 10 - 1])?;
+        }
+        Ok(())
+    }
+
+    /// Write the name of a number from one to nine hundred ninety-nine.
+    ///
+    /// By default the hundreds are read as a count of the [`hundred`], followed by the rest.
+    ///
+    /// [`hundred`]: #tymethod.hundred
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        let rest = number % 100;
+        if number >= 100 {
+            out.write_str(self.units()[number as usize / 100 - 1])?;
+            out.write_str(self.word_separator())?;
+            out.write_str(self.hundred())?;
+            if rest > 0 {
+                out.write_str(self.word_separator())?;
+            }
+        }
+        if rest > 0 {
+            self.write_below_hundred(out, rest)?;
+        }
+        Ok(())
+    }
+
+    /// Write a nonzero group followed by the name of its magnitude (e.g. 5, 1 -> "five thousand").
+    ///
+    /// The default reads groups below one thousand; a language with wider groups overrides it.
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        self.write_hundreds(out, group as u64)?;
+        if magnitude > 0 {
+            out.write_str(self.word_separator())?;
+            out.write_str(&self.magnitude_name(magnitude, group))?;
+        }
+        Ok(())
+    }
+
+    /// Write the separator before a group after the first, given the magnitude of the group
+    /// written before it, and the group and its magnitude.
+    fn write_group_separator<W: Write>(
+        &self,
+        out: &mut W,
+        _above: usize,
+        _magnitude: usize,
+        _group: u128,
+    ) -> fmt::Result {
+        out.write_str(self.word_separator())
+    }
+
+    /// Write the name of a number, given as a plain decimal number string (e.g. "-12.5").
+    ///
+    /// By default the integer part is read by groups and the decimal part digit by digit after
+    /// the [`decimal_point`]. Text that is not a decimal number (e.g. "NaN") is written as it is.
+    ///
+    /// [`decimal_point`]: #tymethod.decimal_point
+    fn write_number<W: Write>(&self, out: &mut W, number: &str) -> fmt::Result {
+        let (negative, unsigned) = match number.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, number),
+        };
+        let (integer, decimal) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let is_digits = |digits: &str| digits.bytes().all(|b| b.is_ascii_digit());
+        if integer.is_empty() || !is_digits(integer) || !is_digits(decimal) {
+            return out.write_str(number);
+        }
+        let decimal = decimal.trim_end_matches('0');
+        let integer = integer.trim_start_matches('0');
+
+        if negative && (!integer.is_empty() || !decimal.is_empty()) {
+            out.write_str(self.negative())?;
+            out.write_str(self.word_separator())?;
+        }
+        if integer.is_empty() {
+            out.write_str(self.zero())?;
+        } else {
+            write_integer_in(out, integer, self)?;
+        }
+        if !decimal.is_empty() {
+            out.write_str(self.word_separator())?;
+            out.write_str(self.decimal_point())?;
+            for digit in decimal.bytes().map(|b| (b - b'0') as usize) {
+                out.write_str(self.word_separator())?;
+                match digit {
+                    0 => out.write_str(self.zero())?,
+                    _ => out.write_str(self.units()[digit - 1])?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The grammatical gender of the noun being counted, which some languages agree numbers with.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Gender {
+    /// Masculine (e.g. Spanish "uno", "doscientos").
+    #[default]
+    Masculine,
+    /// Feminine (e.g. Spanish "una", "doscientas").
+    Feminine,
+    /// Neuter (e.g. Russian "одно").
+    Neuter,
+}
+
+/// The grammatical case of a number and the noun it counts, which some languages decline.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum GrammaticalCase {
+    /// The subject of a sentence (e.g. Russian "две тысячи").
+    #[default]
+    Nominative,
+    /// Possession, and the count of "no" or "many" (e.g. Russian "двух тысяч").
+    Genitive,
+    /// The indirect object (e.g. Russian "двум тысячам").
+    Dative,
+    /// The direct object, here of an inanimate noun (e.g. Russian "одну тысячу").
+    Accusative,
+    /// The means of an action (e.g. Russian "двумя тысячами").
+    Instrumental,
+    /// A place or subject after a preposition, called the prepositional case in Russian
+    /// (e.g. Russian "двух тысячах").
+    Locative,
+    /// The person addressed, which numbers take in their nominative forms (e.g. Ukrainian
+    /// "дві тисячі").
+    Vocative,
+}
+
+/// The plural category of a number, which chooses the form of the noun it counts.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PluralCategory {
+    /// The form used with one (e.g. Russian "одна книга", "двадцать одна книга").
+    One,
+    /// The form used with a few (e.g. Russian "две книги", "двадцать четыре книги").
+    Few,
+    /// The form used with many (e.g. Russian "пять книг", "одиннадцать книг").
+    Many,
+}
+
+/// The form of a noun that agrees with a number: its case, and whether it is plural.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct NounAgreement {
+    /// The case of the noun (e.g. genitive after Russian "пять").
+    pub case: GrammaticalCase,
+    /// Whether the noun is plural.
+    pub plural: bool,
+}
+
+/// English, written with its options.
+impl Language for Options {
+    fn zero(&self) -> &str {
+        self.zero_word
+    }
+
+    fn negative(&self) -> &str {
+        self.negative_word
+    }
+
+    fn decimal_point(&self) -> &str {
+        "point"
+    }
+
+    fn units(&self) -> &[&str] {
+        &ONE_TO_NINETEEN
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "hundred"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, _group: u128) -> Cow<'_, str> {
+        self.scale.magnitude_name(magnitude)
+    }
+
+    fn group_digits(&self, magnitude: usize) -> u32 {
+        self.scale.group_digits(magnitude)
+    }
+
+    fn tens_separator(&self) -> &str {
+        self.compound_separator
+    }
+
+    fn write_below_hundred<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        write_hundreds_to_english(out, number, self)
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        write_hundreds_to_english(out, number, self)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        if group < 1000 {
+            write_hundreds_to_english(out, group as u64, self)?;
+        } else {
+            // groups wider than three digits are read on their own (e.g. "one thousand five hundred million")
+            let group_options = Options {
+                scale: self.scale.group_scale(),
+                ..self.clone()
+            };
+            write_u128_to_english(out, group, &group_options)?;
+        }
+        write_magnitude_name(out, magnitude, self)
+    }
+
+    fn write_group_separator<W: Write>(
+        &self,
+        out: &mut W,
+        _above: usize,
+        magnitude: usize,
+        group: u128,
+    ) -> fmt::Result {
+        write_group_separator(out, magnitude, group < 100, self)
+    }
+
+    fn write_number<W: Write>(&self, out: &mut W, number: &str) -> fmt::Result {
+        let mut out = CaseWriter::new(out, self.case);
+        write_number_to_english(&mut out, number, self)?;
+        out.finish()
+    }
+}
+
+/// Convert a number to its name in a language.
+///
+/// English is named with its [`Options`], as [`to_english_with`] names it.
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, NumberToEnglish, Options};
+/// let options = Options::default();
+/// assert_eq!(number_to_words(&1_234, &options), "one thousand two hundred thirty-four");
+/// assert_eq!(number_to_words(&-0.5, &options), (-0.5).to_english());
+/// ```
+///
+/// [`Options`]: struct.Options.html
+/// [`to_english_with`]: trait.NumberToEnglish.html#tymethod.to_english_with
+pub fn number_to_words<T: Num + Display, L: Language>(number: &T, language: &L) -> String {
+    let mut result = String::new();
+    // writing to a string never fails
+    let _ = language.write_number(&mut result, &expand_scientific(number.to_string()));
+    result
+}
+
+/// Write the name of a positive integer, given as its decimal digits without leading zeros, in a
+/// language, reading its groups from the largest down.
+pub(crate) fn write_integer_in<W: Write, L: Language + ?Sized>(
+    out: &mut W,
+    number: &str,
+    language: &L,
+) -> fmt::Result {
+    if let Ok(number) = number.parse::<u128>() {
+        return write_u128_in(out, number, language);
+    }
+
+    // split the digits into groups from the units group up
+    let mut groups = Vec::new();
+    let mut end = number.len();
+    while end > 0 {
+        let group_digits = language.group_digits(groups.len()).min(38) as usize;
+        let start = end.saturating_sub(group_digits);
+        groups.push(number[start..end].parse::<u128>().unwrap_or_default());
+        end = start;
+    }
+    write_groups(out, &groups, language)
+}
+
+/// Write the name of a positive integer that fits in a `u128`, using plain integer arithmetic.
+pub(crate) fn write_u128_in<W: Write, L: Language + ?Sized>(
+    out: &mut W,
+    number: u128,
+    language: &L,
+) -> fmt::Result {
+    // a u128 has at most 39 digits, so it splits into at most 39 groups
+    let mut groups = [0; 39];
+    let mut count = 0;
+    let mut number = number;
+    while number > 0 {
+        match 10_u128.checked_pow(language.group_digits(count)) {
+            Some(group_size) => {
+                groups[count] = number % group_size;
+                number /= group_size;
+            }
+            None => {
+                groups[count] = number;
+                number = 0;
+            }
+        }
+        count += 1;
+    }
+    write_groups(out, &groups[..count], language)
+}
+
+/// Write the nonzero groups of an integer, given from the units group up.
+fn write_groups<W: Write, L: Language + ?Sized>(
+    out: &mut W,
+    groups: &[u128],
+    language: &L,
+) -> fmt::Result {
+    // the magnitude of the last group written
+    let mut above = None;
+    for (magnitude, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        if let Some(above) = above {
+            language.write_group_separator(out, above, magnitude, group)?;
+        }
+        above = Some(magnitude);
+        language.write_group(out, group, magnitude)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Case, Language, NumberToEnglish, Options, Scale};
+    use alloc::borrow::Cow;
+    #[cfg(feature = "bigint")]
+    use num_bigint::BigInt;
+
+    /// Numbers grouped by ten thousands, with no separators.
+    struct Myriad;
+
+    impl Language for Myriad {
+        fn zero(&self) -> &str {
+            "ling"
+        }
+        fn negative(&self) -> &str {
+            "fu"
+        }
+        fn decimal_point(&self) -> &str {
+            "dian"
+        }
+        fn units(&self) -> &[&str] {
+            &["yi", "er", "san", "si", "wu", "liu", "qi", "ba", "jiu"]
+        }
+        fn tens(&self) -> &[&str] {
+            &["shi"]
+        }
+        fn hundred(&self) -> &str {
+            "bai"
+        }
+        fn magnitude_name(&self, magnitude: usize, _group: u128) -> Cow<'_, str> {
+            Cow::Borrowed(["wan", "yi"][magnitude - 1])
+        }
+        fn group_digits(&self, _magnitude: usize) -> u32 {
+            4
+        }
+        fn write_group<W: core::fmt::Write>(
+            &self,
+            out: &mut W,
+            group: u128,
+            magnitude: usize,
+        ) -> core::fmt::Result {
+            write!(out, "<{}>", group)?;
+            if magnitude > 0 {
+                out.write_str(&self.magnitude_name(magnitude, group))?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_language() {
+        for options in [
+            Options::default(),
+            Options {
+                british_and: true,
+                case: Case::Title,
+                scale: Scale::Long,
+                ..Default::default()
+            },
+            Options {
+                scale: Scale::Indian,
+                colloquial_hundreds: true,
+                ..Default::default()
+            },
+        ] {
+            for n in [0.0, -1.5, 1_200.0, 1e21, 123_456_789.25] {
+                assert_eq!(number_to_words(&n, &options), n.to_english_with(&options));
+            }
+            #[cfg(feature = "bigint")]
+            {
+                let huge: BigInt = BigInt::from(10).pow(50) + 21;
+                assert_eq!(
+                    number_to_words(&huge, &options),
+                    huge.to_english_with(&options)
+                );
+            }
+        }
+
+        assert_eq!(
+            number_to_words(&123_456_789, &Myriad),
+            "<1>yi <2345>wan <6789>"
+        );
+        assert_eq!(number_to_words(&-0.05, &Myriad), "fu ling dian ling wu");
+        assert_eq!(number_to_words(&-0.0, &Myriad), "ling");
+        assert_eq!(number_to_words(&f64::NAN, &Myriad), "NaN");
+    }
+}
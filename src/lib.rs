@@ -9,7 +9,9 @@
 //! Converting a number to its English representation is done via the [`NumberToEnglish`] trait.
 //! The [`NumberToEnglish`] trait is implemented for all types that implement the [`Num`] trait.
 //!
-//! **_Scientific notation is not supported at this time._**
+//! Numbers whose string representation uses scientific notation (e.g. `1e21`) are expanded before conversion.
+//!
+//! Big integers, fractions and exact decimals need the `bigint` feature, which is on by default.
 //!
 //! # Example
 //!
@@ -29,17 +31,180 @@
 //! [`BigInt`]: https://docs.rs/num-bigint/latest/num_bigint/struct.BigInt.html
 //!
 
+#[cfg(feature = "lang-ar")]
+mod arabic;
+mod batch;
+#[cfg(feature = "bigfloat")]
+mod bigfloat;
+mod bytes;
+#[cfg(feature = "lang-zh")]
+mod chinese;
+mod complex;
+mod currency;
+mod date;
+mod decimal;
+mod digits;
+mod display;
+mod duration;
+#[cfg(feature = "lang-nl")]
+mod dutch;
+mod editorial;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "bigint")]
+mod float;
+mod fraction;
+#[cfg(feature = "lang-fr")]
+mod french;
+mod frequency;
+#[cfg(feature = "lang-de")]
+mod german;
+#[cfg(feature = "bigint")]
+mod half;
+#[cfg(feature = "lang-hi")]
+mod hindi;
+#[cfg(feature = "lang-it")]
+mod italian;
+#[cfg(feature = "lang-ja")]
+mod japanese;
+#[cfg(feature = "lang-ko")]
+mod korean;
+mod language;
+mod notation;
+mod noun;
+mod options;
+#[cfg(feature = "bigint")]
+mod parse;
+mod phone;
+#[cfg(feature = "lang-pl")]
+mod polish;
+#[cfg(feature = "lang-pt")]
+mod portuguese;
+#[cfg(feature = "python")]
+pub mod python;
+mod quantity;
+mod roman;
+#[cfg(feature = "lang-ru")]
+mod russian;
+#[cfg(feature = "bigint")]
+mod scaled;
 mod scales;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(any(feature = "lang-ru", feature = "lang-uk"))]
+mod slavic;
+#[cfg(feature = "lang-es")]
+mod spanish;
+mod ssml;
+#[cfg(feature = "lang-sv")]
+mod swedish;
+mod text;
+mod time;
+#[cfg(feature = "lang-tr")]
+mod turkish;
+#[cfg(feature = "lang-uk")]
+mod ukrainian;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "bigint")]
+mod wide;
+mod words;
+mod year;
 
-use core::fmt::Display;
-use num_bigint::{BigInt, Sign};
+#[cfg(feature = "lang-ar")]
+pub use arabic::Arabic;
+pub use batch::{to_english_batch, write_english_batch};
+#[cfg(feature = "bigfloat")]
+pub use bigfloat::bigfloat_to_english;
+pub use bytes::{bytes_to_english, ByteStyle};
+#[cfg(feature = "lang-zh")]
+pub use chinese::Chinese;
+pub use complex::{complex_to_english, ComplexStyle};
+use core::fmt::{self, Display, Write};
+pub use currency::Currency;
+pub use date::{date_to_english, DateOrder};
+pub use digits::digits_to_english;
+#[cfg(feature = "bigint")]
+pub use digits::{radix_digits_to_english, RadixStyle};
+pub use display::InEnglish;
+pub use duration::{duration_to_english, DurationStyle};
+#[cfg(feature = "lang-nl")]
+pub use dutch::Dutch;
+pub use editorial::{editorial_number, EditorialStyle, StyleGuide};
+pub use error::Error;
+#[cfg(feature = "bigint")]
+pub use float::exact_float_to_english;
+#[cfg(feature = "bigint")]
+pub use fraction::{Fraction, RepeatingStyle};
+#[cfg(feature = "lang-fr")]
+pub use french::French;
+pub use frequency::{frequency_to_english, FrequencyStyle};
+#[cfg(feature = "lang-de")]
+pub use german::German;
+#[cfg(feature = "bigint")]
+pub use half::{bf16_to_english, f16_to_english};
+#[cfg(feature = "lang-hi")]
+pub use hindi::Hindi;
+#[cfg(feature = "lang-it")]
+pub use italian::Italian;
+#[cfg(feature = "lang-ja")]
+pub use japanese::Japanese;
+#[cfg(feature = "lang-ko")]
+pub use korean::{Korean, KoreanSystem};
+pub use language::{
+    number_to_words, Gender, GrammaticalCase, Language, NounAgreement, PluralCategory,
+};
+use language::{write_integer_in, write_u128_in};
+pub use noun::{count_noun, regular_plural, NounStyle, IRREGULAR_PLURALS};
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
 use num_traits::Num;
-use scales::{DECIMALS, MAGNITUDES, ONE_TO_NINETEEN, TENS};
+use options::CaseWriter;
+pub use options::{Case, DecimalStyle, Grouping, Options, Rounding, Scale};
+#[cfg(feature = "bigint")]
+pub use parse::{
+    parse_english, parse_english_currency, parse_english_ordinal, parse_english_split,
+    ParsedAmount, ParsedInteger,
+};
+pub use phone::{phone_number_to_english, PhoneStyle};
+#[cfg(feature = "lang-pl")]
+pub use polish::Polish;
+#[cfg(feature = "lang-pt")]
+pub use portuguese::{Portuguese, PortugueseVariant};
+pub use quantity::{si_quantity_to_english, Unit};
+pub use roman::{parse_roman, roman_to_english, NumberToRoman, RomanOptions};
+#[cfg(feature = "lang-ru")]
+pub use russian::Russian;
+#[cfg(feature = "bigint")]
+pub use scaled::ScaledDecimal;
+use scales::{DECIMALS, ONE_TO_NINETEEN, ONE_TO_NINE_HUNDRED_NINETY_NINE, TENS};
+#[cfg(feature = "lang-es")]
+pub use spanish::Spanish;
+pub use ssml::{number_to_ssml, SsmlStyle};
+#[cfg(feature = "lang-sv")]
+pub use swedish::Swedish;
+pub use text::{convert_str, convert_str_currency, convert_str_ordinal, StrToEnglish};
+pub use time::{time_to_english, Clock, TimeStyle};
+#[cfg(feature = "lang-tr")]
+pub use turkish::Turkish;
+#[cfg(feature = "lang-uk")]
+pub use ukrainian::Ukrainian;
+#[cfg(feature = "bigint")]
+pub use wide::{i256_to_english, u256_to_english};
+use words::WordWriter;
+pub use words::Words;
 
 extern crate alloc;
+// a C library needs the allocator and panic handler of std, and the Python bindings are built
+// on it
+#[cfg(any(feature = "ffi", feature = "python"))]
+extern crate std;
+#[cfg(feature = "bigint")]
+use alloc::vec::Vec;
 use alloc::{
+    borrow::Cow,
     string::{String, ToString},
-    vec::Vec,
 };
 
 /// Represents a number split into its integer and decimal parts.
@@ -56,6 +221,7 @@ use alloc::{
 /// assert_eq!(number.decimal, Some(212.into()));
 /// assert_eq!(number.decimal_places, 3);
 /// ```
+#[cfg(feature = "bigint")]
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct SplitNumber {
     /// The integer part of the number.
@@ -80,9 +246,312 @@ where
     T: Num + Display,
 {
     /// Convert a number to its English representation.
-    ///
-    /// **_Scientific notation is not supported... yet._**
     fn to_english(&self) -> String;
+
+    /// Convert a number to its English representation using the given [`Options`].
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{NumberToEnglish, Options};
+    /// let british = Options {
+    ///     british_and: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(1_005.to_english_with(&british), "one thousand and five");
+    /// assert_eq!(
+    ///     123_456.to_english_with(&british),
+    ///     "one hundred and twenty-three thousand four hundred and fifty-six"
+    /// );
+    /// ```
+    fn to_english_with(&self, options: &Options) -> String;
+
+    /// Append the English representation of a number to a string using the given [`Options`].
+    ///
+    /// This lets a single buffer be reused when converting many numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{NumberToEnglish, Options};
+    /// let options = Options::default();
+    /// let mut buffer = String::new();
+    /// for n in [1, 2, 3] {
+    ///     if !buffer.is_empty() {
+    ///         buffer.push_str(", ");
+    ///     }
+    ///     n.to_english_into(&mut buffer, &options);
+    /// }
+    /// assert_eq!(buffer, "one, two, three");
+    /// ```
+    fn to_english_into(&self, buffer: &mut String, options: &Options);
+
+    /// Write the English representation of a number to any [`fmt::Write`] sink using the given [`Options`].
+    ///
+    /// The words are written to the sink as they are read, rather than joined into a `String` first,
+    /// so a number can be written straight into a formatter or a fixed-size buffer.
+    ///
+    /// # Errors
+    /// Returns an error if the sink fails to accept the words.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use num2english::{Case, NumberToEnglish, Options};
+    /// let title = Options {
+    ///     case: Case::Title,
+    ///     ..Default::default()
+    /// };
+    /// let mut out = String::new();
+    /// write!(out, "Total: ").unwrap();
+    /// 1_234.write_english(&mut out, &title).unwrap();
+    /// assert_eq!(out, "Total: One Thousand Two Hundred Thirty-Four");
+    /// ```
+    ///
+    /// [`fmt::Write`]: https://doc.rust-lang.org/core/fmt/trait.Write.html
+    fn write_english<W: Write>(&self, out: &mut W, options: &Options) -> fmt::Result;
+
+    /// Write the English representation of a number into a byte buffer using the given [`Options`],
+    /// returning the number of bytes written.
+    ///
+    /// The buffer holds UTF-8 text up to the returned length. This suits callers that keep their
+    /// output in fixed-size buffers; a `heapless::String` can instead be passed to [`write_english`],
+    /// as it implements [`fmt::Write`].
+    ///
+    /// # Errors
+    /// Returns [`Error::BufferTooSmall`] if the words do not fit, in which case the buffer holds only
+    /// the start of the text.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{Error, NumberToEnglish, Options};
+    /// let mut buffer = [0; 16];
+    /// let len = 42.write_english_to_slice(&mut buffer, &Options::default()).unwrap();
+    /// assert_eq!(&buffer[..len], b"forty-two");
+    /// assert_eq!(
+    ///     1_234.write_english_to_slice(&mut buffer, &Options::default()),
+    ///     Err(Error::BufferTooSmall)
+    /// );
+    /// ```
+    ///
+    /// [`write_english`]: trait.NumberToEnglish.html#tymethod.write_english
+    /// [`fmt::Write`]: https://doc.rust-lang.org/core/fmt/trait.Write.html
+    /// [`Error::BufferTooSmall`]: enum.Error.html#variant.BufferTooSmall
+    fn write_english_to_slice(&self, buffer: &mut [u8], options: &Options) -> Result<usize, Error>;
+
+    /// Split the English representation of a number into its words using the given [`Options`].
+    ///
+    /// This lets the words be joined, highlighted or marked up without splitting the output again.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{NumberToEnglish, Options};
+    /// let words = 342.to_english_words(&Options::default());
+    /// assert_eq!(words.collect::<Vec<_>>().join("_"), "three_hundred_forty-two");
+    /// ```
+    fn to_english_words(&self, options: &Options) -> Words;
+
+    /// Get the length in bytes of the English representation of a number using the given [`Options`].
+    ///
+    /// The words are counted as they are read, without building the output, so a buffer can be
+    /// sized or an overly long name rejected before it is written.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{NumberToEnglish, Options};
+    /// let options = Options::default();
+    /// assert_eq!(42.english_len(&options), "forty-two".len());
+    /// assert_eq!(1_234.english_len(&options), 1_234.to_english().len());
+    /// ```
+    fn english_len(&self, options: &Options) -> usize;
+
+    /// Convert a number to its English representation using the given [`Options`], borrowing the
+    /// name if it is held whole in the built-in tables.
+    ///
+    /// Zero and the numbers up to 999 are named without allocating, as long as the options keep
+    /// them lowercase and unchanged. Any other number is converted as by [`to_english_with`].
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{NumberToEnglish, Options};
+    /// use std::borrow::Cow;
+    /// let options = Options::default();
+    /// assert_eq!(12.to_english_cow(&options), Cow::Borrowed("twelve"));
+    /// assert_eq!(300.to_english_cow(&options), Cow::Borrowed("three hundred"));
+    /// assert!(matches!(1_042.to_english_cow(&options), Cow::Owned(_)));
+    /// ```
+    ///
+    /// [`to_english_with`]: trait.NumberToEnglish.html#tymethod.to_english_with
+    fn to_english_cow(&self, options: &Options) -> Cow<'static, str>;
+
+    /// Convert a percentage to its English representation using the given [`Options`].
+    ///
+    /// The number is read as the percentage itself (e.g. 45 -> "forty-five percent").
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{DecimalStyle, NumberToEnglish, Options};
+    /// let point = Options {
+    ///     decimal_style: DecimalStyle::Point,
+    ///     ..Default::default()
+    /// };
+    /// let mixed = Options {
+    ///     decimal_style: DecimalStyle::Mixed,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(45.to_english_percent(&Options::default()), "forty-five percent");
+    /// assert_eq!(0.5.to_english_percent(&point), "zero point five percent");
+    /// assert_eq!(45.5.to_english_percent(&mixed), "forty-five and a half percent");
+    /// ```
+    fn to_english_percent(&self, options: &Options) -> String;
+
+    /// Read each digit of a number on its own using the given [`Options`].
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{NumberToEnglish, Options};
+    /// assert_eq!(404.to_english_digits(&Options::default()), "four zero four");
+    /// assert_eq!(3.25.to_english_digits(&Options::default()), "three point two five");
+    /// ```
+    fn to_english_digits(&self, options: &Options) -> String;
+
+    /// Convert a number to an amount of money in English, rounded to the currency's minor unit.
+    ///
+    /// Infinities and NaN have no minor unit and are named as they are (e.g. "not a number").
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{Currency, NumberToEnglish};
+    /// assert_eq!(
+    ///     1234.56.to_english_currency(&Currency::USD),
+    ///     "one thousand two hundred thirty-four dollars and fifty-six cents"
+    /// );
+    /// assert_eq!(1.to_english_currency(&Currency::USD), "one dollar");
+    /// assert_eq!(f64::NAN.to_english_currency(&Currency::USD), "not a number");
+    /// ```
+    fn to_english_currency(&self, currency: &Currency) -> String;
+
+    /// Convert a number to the amount line of a check, rounded to the currency's minor unit.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{Currency, NumberToEnglish};
+    /// assert_eq!(
+    ///     1234.56.to_english_check(&Currency::USD),
+    ///     "One thousand two hundred thirty-four and 56/100 Dollars"
+    /// );
+    /// assert_eq!(12.to_english_check(&Currency::USD), "Twelve and 00/100 Dollars");
+    /// ```
+    fn to_english_check(&self, currency: &Currency) -> String;
+
+    /// Convert a number to a short approximate English representation, rounded to a number of
+    /// significant figures and read with its largest magnitude name.
+    ///
+    /// Rounded numbers are prefixed with "about". At least one significant figure is always
+    /// kept, so zero significant figures round the same as one.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::NumberToEnglish;
+    /// assert_eq!(1_234_567.to_english_approx(2), "about one point two million");
+    /// assert_eq!(3_000_000_000_u64.to_english_approx(2), "three billion");
+    /// assert_eq!(987.to_english_approx(1), "about one thousand");
+    /// assert_eq!(987.to_english_approx(0), "about one thousand");
+    /// ```
+    fn to_english_approx(&self, significant_figures: usize) -> String;
+
+    /// Convert a number to its English representation in scientific notation.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::NumberToEnglish;
+    /// assert_eq!(
+    ///     6.02e23.to_english_scientific(),
+    ///     "six point zero two times ten to the twenty-third"
+    /// );
+    /// assert_eq!(
+    ///     0.000_03.to_english_scientific(),
+    ///     "three times ten to the negative fifth"
+    /// );
+    /// ```
+    fn to_english_scientific(&self) -> String;
+
+    /// Convert a number to its English representation in engineering notation, where the
+    /// exponent is always a multiple of three.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::NumberToEnglish;
+    /// assert_eq!(
+    ///     4.7e-8.to_english_engineering(),
+    ///     "forty-seven times ten to the negative ninth"
+    /// );
+    /// ```
+    fn to_english_engineering(&self) -> String;
+
+    /// Convert an integer to its English name as an ordinal (e.g. 21 -> "twenty-first").
+    ///
+    /// # Errors
+    /// Returns [`Error::NonFinite`] for infinities and NaN, and [`Error::InvalidNumber`] if the
+    /// number is not an integer.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{Error, NumberToEnglish};
+    /// assert_eq!(4.to_english_ordinal(), Ok("fourth".into()));
+    /// assert_eq!(21.to_english_ordinal(), Ok("twenty-first".into()));
+    /// assert_eq!(100.to_english_ordinal(), Ok("one hundredth".into()));
+    /// assert_eq!(1.5.to_english_ordinal(), Err(Error::InvalidNumber));
+    /// ```
+    ///
+    /// [`Error::NonFinite`]: enum.Error.html#variant.NonFinite
+    /// [`Error::InvalidNumber`]: enum.Error.html#variant.InvalidNumber
+    fn to_english_ordinal(&self) -> Result<String, Error>;
+
+    /// Convert an integer to its English name as an ordinal using the given [`Options`].
+    ///
+    /// # Errors
+    /// Returns the same errors as [`to_english_ordinal`].
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{Case, NumberToEnglish, Options};
+    /// let options = Options {
+    ///     british_and: true,
+    ///     case: Case::Title,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     105.to_english_ordinal_with(&options),
+    ///     Ok("One Hundred and Fifth".into())
+    /// );
+    /// ```
+    ///
+    /// [`Options`]: struct.Options.html
+    /// [`to_english_ordinal`]: #tymethod.to_english_ordinal
+    fn to_english_ordinal_with(&self, options: &Options) -> Result<String, Error>;
+
+    /// Convert a number to its English name as a year.
+    ///
+    /// Numbers outside of 1000 to 9999 are read as regular numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::NumberToEnglish;
+    /// assert_eq!(1984.to_english_year(), "nineteen eighty-four");
+    /// assert_eq!(2005.to_english_year(), "two thousand five");
+    /// assert_eq!(1700.to_english_year(), "seventeen hundred");
+    /// ```
+    fn to_english_year(&self) -> String;
+
+    /// Convert a number to its numeral with an English ordinal suffix (e.g. 61 -> "61st").
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::NumberToEnglish;
+    /// assert_eq!(1.to_ordinal_numeral(), "1st");
+    /// assert_eq!(112.to_ordinal_numeral(), "112th");
+    /// assert_eq!(103.to_ordinal_numeral(), "103rd");
+    /// ```
+    fn to_ordinal_numeral(&self) -> String;
 }
 
 impl<T> NumberToEnglish<T> for T
@@ -90,149 +559,902 @@ where
     T: Num + Display,
 {
     fn to_english(&self) -> String {
+        self.to_english_with(&Options::default())
+    }
+
+    fn to_english_with(&self, options: &Options) -> String {
+        let mut result = String::new();
+        self.to_english_into(&mut result, options);
+        result
+    }
+
+    fn to_english_into(&self, buffer: &mut String, options: &Options) {
+        // writing to a string never fails
+        let _ = self.write_english(buffer, options);
+    }
+
+    fn write_english<W: Write>(&self, out: &mut W, options: &Options) -> fmt::Result {
+        let mut out = CaseWriter::new(out, options.case);
+        // primitive numbers are formatted on the stack, with no intermediate string
+        let mut digits = [0; 48];
+        let mut buffer = SliceWriter {
+            buffer: &mut digits,
+            len: 0,
+        };
+        let written = write!(buffer, "{}", self).map(|()| buffer.len);
+        match written.ok().map(|len| core::str::from_utf8(&digits[..len])) {
+            Some(Ok(number)) if !number.contains(['e', 'E']) => {
+                write_number_to_english(&mut out, number, options)?;
+            }
+            _ => {
+                let string = expand_scientific(self.to_string());
+                write_number_to_english(&mut out, &string, options)?;
+            }
+        }
+        out.finish()
+    }
+
+    fn to_english_words(&self, options: &Options) -> Words {
+        let mut out = WordWriter::new(options);
+        // splitting words never fails
+        let _ = self.write_english(&mut out, options);
+        out.finish()
+    }
+
+    fn write_english_to_slice(&self, buffer: &mut [u8], options: &Options) -> Result<usize, Error> {
+        let mut out = SliceWriter { buffer, len: 0 };
+        match self.write_english(&mut out, options) {
+            Ok(()) => Ok(out.len),
+            Err(_) => Err(Error::BufferTooSmall),
+        }
+    }
+
+    fn english_len(&self, options: &Options) -> usize {
+        let mut out = LenWriter(0);
+        // counting never fails
+        let _ = self.write_english(&mut out, options);
+        out.0
+    }
+
+    fn to_english_cow(&self, options: &Options) -> Cow<'static, str> {
+        // the number's digits fit on the stack whenever its name could be borrowed
+        let mut digits = [0; 20];
+        let mut out = SliceWriter {
+            buffer: &mut digits,
+            len: 0,
+        };
+        let written = write!(out, "{}", self).map(|()| out.len);
+        let name = match written {
+            Ok(len) => core::str::from_utf8(&digits[..len])
+                .ok()
+                .and_then(|digits| digits.parse::<u64>().ok())
+                .and_then(|number| static_name(number, options)),
+            Err(_) => None,
+        };
+        match name {
+            Some(name) => Cow::Borrowed(name),
+            None => Cow::Owned(self.to_english_with(options)),
+        }
+    }
+
+    fn to_english_percent(&self, options: &Options) -> String {
+        let string = expand_scientific(self.to_string());
+        let mut result = convert_number_to_english(string, options);
+        result.push_str(" percent");
+        options.case.apply(&result)
+    }
+
+    fn to_english_digits(&self, options: &Options) -> String {
+        digits_to_english(&self.to_string(), options)
+    }
+
+    fn to_english_currency(&self, currency: &Currency) -> String {
+        let string = expand_scientific(self.to_string());
+        currency::convert_currency_to_english(&string, currency, &Options::default())
+    }
+
+    fn to_english_check(&self, currency: &Currency) -> String {
+        let string = expand_scientific(self.to_string());
+        currency::convert_check_to_english(&string, currency, &Options::default())
+    }
+
+    fn to_english_approx(&self, significant_figures: usize) -> String {
+        let string = self.to_string();
+        let Some(number) = decimal::Decimal::parse(&string) else {
+            return convert_number_to_english(string, &Options::default());
+        };
+        convert_approx_to_english(&number, significant_figures)
+    }
+
+    fn to_english_scientific(&self) -> String {
+        let string = self.to_string();
+        let Some(number) = decimal::Decimal::parse(&string) else {
+            return convert_number_to_english(string, &Options::default());
+        };
+        notation::convert_scientific_to_english(&number)
+    }
+
+    fn to_english_engineering(&self) -> String {
+        let string = self.to_string();
+        let Some(number) = decimal::Decimal::parse(&string) else {
+            return convert_number_to_english(string, &Options::default());
+        };
+        notation::convert_engineering_to_english(&number)
+    }
+
+    fn to_english_ordinal(&self) -> Result<String, Error> {
+        self.to_english_ordinal_with(&Options::default())
+    }
+
+    fn to_english_ordinal_with(&self, options: &Options) -> Result<String, Error> {
         let string = self.to_string();
-        if string.contains('e') {
-            return "Error: Scientific notation is not supported at this time.".to_string();
+        if convert_non_finite_to_english(&string, options).is_some() {
+            return Err(Error::NonFinite);
+        }
+        // only whole numbers have an ordinal (e.g. not "one and five tenthsth")
+        let number = decimal::Decimal::parse(&string).ok_or(Error::InvalidNumber)?;
+        if number.exponent < 0 {
+            return Err(Error::InvalidNumber);
         }
-        convert_number_to_english(string)
+        check_expandable(&number)?;
+        // the suffix is added to the lowercase words before the case is applied
+        let lower = Options {
+            case: Case::Lower,
+            ..options.clone()
+        };
+        Ok(options
+            .case
+            .apply(&ordinal_words(&self.to_english_with(&lower))))
+    }
+
+    fn to_english_year(&self) -> String {
+        let string = expand_scientific(self.to_string());
+        year::convert_year_to_english(string, &Options::default())
+    }
+
+    fn to_ordinal_numeral(&self) -> String {
+        let mut string = self.to_string();
+        string.push_str(ordinal_suffix(&string));
+        string
     }
 }
 
-/// Convert a number to its name in English (e.g. 60.212 -> "sixty and two hundred twelve thousandths")
-fn convert_number_to_english(number: String) -> String {
-    let SplitNumber {
-        integer: before_decimal,
-        decimal: after_decimal,
-        decimal_places,
-    } = split_number(&number);
+/// Convert any number type to its name in English, reporting numbers that can't be named.
+///
+/// # Examples
+/// ```
+/// use num2english::{Error, TryNumberToEnglish};
+/// assert_eq!(60.try_to_english(), Ok("sixty".into()));
+/// assert_eq!(f64::NAN.try_to_english(), Err(Error::NonFinite));
+/// ```
+pub trait TryNumberToEnglish<T>
+where
+    T: Num + Display,
+{
+    /// Convert a number to its English representation.
+    ///
+    /// # Errors
+    /// Returns [`Error::NonFinite`] for infinities and NaN, and [`Error::InvalidNumber`] if the
+    /// number's `Display` output is not a decimal number.
+    ///
+    /// [`Error::NonFinite`]: enum.Error.html#variant.NonFinite
+    /// [`Error::InvalidNumber`]: enum.Error.html#variant.InvalidNumber
+    fn try_to_english(&self) -> Result<String, Error>;
 
-    let mut result = String::new();
+    /// Convert a number to its English representation using the given [`Options`].
+    ///
+    /// # Errors
+    /// As for [`try_to_english`], and [`Error::MagnitudeOverflow`] if a [`Grouping`] has no name
+    /// for one of the number's groups or decimal places.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{Error, Grouping, Options, Scale, TryNumberToEnglish};
+    /// static MYRIAD: Grouping = Grouping {
+    ///     digits: 4,
+    ///     names: &["wan"],
+    ///     decimals: &[],
+    /// };
+    /// let myriad = Options {
+    ///     scale: Scale::Grouped(&MYRIAD),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(12_345.try_to_english_with(&myriad), Ok("one wan two thousand three hundred forty-five".into()));
+    /// assert_eq!(123_456_789.try_to_english_with(&myriad), Err(Error::MagnitudeOverflow));
+    /// ```
+    ///
+    /// [`try_to_english`]: trait.TryNumberToEnglish.html#tymethod.try_to_english
+    /// [`Error::MagnitudeOverflow`]: enum.Error.html#variant.MagnitudeOverflow
+    fn try_to_english_with(&self, options: &Options) -> Result<String, Error>;
+}
 
-    let has_integer = before_decimal.is_some();
+impl<T> TryNumberToEnglish<T> for T
+where
+    T: Num + Display,
+{
+    fn try_to_english(&self) -> Result<String, Error> {
+        self.try_to_english_with(&Options::default())
+    }
 
-    if let Some(mut before_decimal) = before_decimal {
-        // check the sign
-        if let Sign::Minus = before_decimal.sign() {
-            result.push_str("negative ");
-            before_decimal = -before_decimal;
+    fn try_to_english_with(&self, options: &Options) -> Result<String, Error> {
+        let string = self.to_string();
+        if convert_non_finite_to_english(&string, options).is_some() {
+            return Err(Error::NonFinite);
         }
-        result.push_str(&convert_integer_to_english(before_decimal));
+        let decimal = decimal::Decimal::parse(&string).ok_or(Error::InvalidNumber)?;
+        check_expandable(&decimal)?;
+        check_magnitude(&decimal, &string, options)?;
+        Ok(options.case.apply(&convert_number_to_english(
+            expand_scientific(string),
+            options,
+        )))
     }
+}
 
-    if let Some(after_decimal) = after_decimal {
-        if has_integer {
-            result.push_str(" and ");
-        }
-        result.push_str(&convert_decimal_to_english(after_decimal, decimal_places));
+/// Check that the scale has names for every group and decimal place of a number.
+fn check_magnitude(
+    number: &decimal::Decimal,
+    string: &str,
+    options: &Options,
+) -> Result<(), Error> {
+    let Scale::Grouped(grouping) = options.scale else {
+        return Ok(());
+    };
+    if grouping.digits == 0 {
+        return Err(Error::MagnitudeOverflow);
     }
 
-    if result.is_empty() {
-        result.push_str("zero");
+    let integer_digits = (number.leading_power() + 1).max(0) as usize;
+    let groups = integer_digits.div_ceil(grouping.digits as usize);
+    if groups > grouping.names.len() + 1 {
+        return Err(Error::MagnitudeOverflow);
     }
 
-    result
+    // decimal places are counted as written, since trailing zeros are read
+    let decimal_places = match expand_scientific(String::from(string)).split_once('.') {
+        Some((_, decimal)) => decimal.len(),
+        None => 0,
+    };
+    if options.decimal_style == DecimalStyle::Fraction
+        && !grouping.decimals.is_empty()
+        && decimal_places > grouping.decimals.len()
+    {
+        return Err(Error::MagnitudeOverflow);
+    }
+    Ok(())
+}
+
+/// Get the ordinal suffix for a number string (e.g. "1" -> "st", "12" -> "th", "23" -> "rd")
+fn ordinal_suffix(number: &str) -> &'static str {
+    // only whole numbers get "st", "nd" or "rd" (e.g. "3.5th")
+    if !number.bytes().all(|b| b.is_ascii_digit() || b == b'-') {
+        return "th";
+    }
+
+    let digits = number.as_bytes();
+    let last = digits.last().map_or(0, |b| b.wrapping_sub(b'0'));
+    let tens = if digits.len() > 1 {
+        digits[digits.len() - 2].wrapping_sub(b'0')
+    } else {
+        0
+    };
+
+    match (tens, last) {
+        (1, _) => "th",
+        (_, 1) => "st",
+        (_, 2) => "nd",
+        (_, 3) => "rd",
+        _ => "th",
+    }
 }
 
-/// Convert an integer to its name in English (e.g. 60 -> "sixty")
-fn convert_integer_to_english(number: BigInt) -> String {
+/// Convert a floating point number that isn't finite to its name (e.g. "NaN" -> "not a number", "-inf" -> "negative infinity")
+fn convert_non_finite_to_english(number: &str, options: &Options) -> Option<String> {
+    let (negative, unsigned) = match number.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, number),
+    };
+
+    // multiple precision libraries such as MPFR may mark special values with "@" (e.g. "@Inf@")
+    let unsigned = unsigned
+        .strip_prefix('@')
+        .and_then(|rest| rest.strip_suffix('@'))
+        .unwrap_or(unsigned);
+
+    if unsigned.eq_ignore_ascii_case("nan") {
+        return Some(String::from("not a number"));
+    }
+    if !unsigned.eq_ignore_ascii_case("inf") && !unsigned.eq_ignore_ascii_case("infinity") {
+        return None;
+    }
+
+    let mut result = String::new();
+    if negative {
+        result.push_str(options.negative_word);
+        result.push(' ');
+    }
+    result.push_str("infinity");
+    Some(result)
+}
+
+/// Expand a number string written in scientific notation to plain digits (e.g. "1.5e3" -> "1500")
+///
+/// Numbers too large or too small to write out are left as they are, and read in scientific
+/// notation instead.
+fn expand_scientific(number: String) -> String {
+    if !number.contains(['e', 'E']) {
+        return number;
+    }
+    match decimal::Decimal::parse(&number) {
+        Some(decimal) if decimal.is_expandable() => decimal.to_plain_string(),
+        _ => number,
+    }
+}
+
+/// Check that a number string can be expanded to plain digits.
+fn check_expandable(number: &decimal::Decimal) -> Result<(), Error> {
+    if number.is_expandable() {
+        Ok(())
+    } else {
+        Err(Error::MagnitudeOverflow)
+    }
+}
+
+/// Convert a number to its approximate name in English (e.g. 1234567 -> "about one point two million")
+fn convert_approx_to_english(number: &decimal::Decimal, significant_figures: usize) -> String {
+    let rounded = number.round_significant(significant_figures);
+    let options = Options {
+        decimal_style: DecimalStyle::Point,
+        ..Default::default()
+    };
+
     let mut result = String::new();
-    let mut number = number;
-    let mut magnitude = 0;
-
-    while number > BigInt::from(0) {
-        let remainder = number.clone() % BigInt::from(1000);
-        number = (number - remainder.clone()) / BigInt::from(1000);
-
-        if remainder > BigInt::from(0) {
-            let mut remainder_string = convert_hundreds_to_english(remainder);
-            if magnitude > 0 {
-                remainder_string.push(' ');
-                remainder_string.push_str(MAGNITUDES[magnitude - 1]);
+    if rounded != *number {
+        result.push_str("about ");
+    }
+
+    // large numbers are read as a short number of their largest magnitude (e.g. "one point two million")
+    let magnitude = rounded.leading_power().div_euclid(3);
+    if magnitude > 0 && !rounded.is_zero() {
+        let mantissa = rounded.shift(-magnitude * 3);
+        result.push_str(&convert_number_to_english(
+            mantissa.to_plain_string(),
+            &options,
+        ));
+        result.push(' ');
+        result.push_str(&options.scale.magnitude_name(magnitude as usize));
+    } else {
+        result.push_str(&convert_number_to_english(
+            rounded.to_plain_string(),
+            &options,
+        ));
+    }
+
+    result
+}
+
+/// Convert the last word of a cardinal number to its ordinal (e.g. "twenty-one" -> "twenty-first")
+fn ordinal_words(words: &str) -> String {
+    let start = words
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !c.is_alphabetic())
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    let (head, last) = words.split_at(start);
+
+    let mut result = String::from(head);
+    match last {
+        "one" => result.push_str("first"),
+        "two" => result.push_str("second"),
+        "three" => result.push_str("third"),
+        "five" => result.push_str("fifth"),
+        "eight" => result.push_str("eighth"),
+        "nine" => result.push_str("ninth"),
+        "twelve" => result.push_str("twelfth"),
+        _ => match last.strip_suffix('y') {
+            Some(stem) => {
+                result.push_str(stem);
+                result.push_str("ieth");
             }
-            if !result.is_empty() {
-                remainder_string.push(' ');
+            None => {
+                result.push_str(last);
+                result.push_str("th");
             }
-            remainder_string.push_str(&result);
-            result = remainder_string;
+        },
+    }
+    result
+}
+
+/// Get the name of a number that is held whole in the tables (e.g. 20 -> "twenty", 500 -> "five hundred")
+fn static_name(number: u64, options: &Options) -> Option<&'static str> {
+    if options.case != Case::Lower || options.colloquial_units {
+        return None;
+    }
+    // groups of one or two digits name the tens and hundreds by their groups
+    if matches!(options.scale, Scale::Grouped(grouping) if grouping.digits < 3) {
+        return None;
+    }
+    // names without a compound or a british "and" read the same whatever the joining words
+    let plain =
+        number.is_multiple_of(100) || number < 20 || (number < 100 && number.is_multiple_of(10));
+    let default_joins = !options.british_and && options.compound_separator == "-";
+    match number {
+        0 => Some(options.zero_word),
+        // an informal "one hundred" is "a hundred"
+        100 if options.informal_article => None,
+        1..=999 if plain || default_joins => {
+            Some(ONE_TO_NINE_HUNDRED_NINETY_NINE[number as usize - 1])
         }
+        _ => None,
+    }
+}
 
-        magnitude += 1;
+/// A sink that writes into a byte buffer, failing once the buffer is full.
+struct SliceWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        let Some(target) = self.buffer.get_mut(self.len..end) else {
+            return Err(fmt::Error);
+        };
+        target.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
     }
+}
 
-    result
+/// A sink that counts the bytes written to it.
+struct LenWriter(usize);
+
+impl Write for LenWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
 }
 
-/// Converts the decimal part of a number to its name in English (e.g. 60.212 -> "two hundred twelve thousandths")
-fn convert_decimal_to_english(number: BigInt, decimal_places: usize) -> String {
+/// Convert a number to its name in English (e.g. 60.212 -> "sixty and two hundred twelve thousandths")
+fn convert_number_to_english(number: String, options: &Options) -> String {
     let mut result = String::new();
-    let mut number = number;
+    // writing to a string never fails
+    let _ = write_number_to_english(&mut result, &number, options);
+    result
+}
 
-    // get the suffix from the number of digits (e.g. 1 -> "thousandth", 2 -> "hundredth", 3 -> "tenths", etc...)
-    let mut suffix = DECIMALS[decimal_places - 1].to_string();
-    if number > BigInt::from(1) {
-        suffix += "s";
+/// Write the name of a number in English to a sink, one word at a time.
+fn write_number_to_english<W: Write>(out: &mut W, number: &str, options: &Options) -> fmt::Result {
+    if let Some(special) = convert_non_finite_to_english(number, options) {
+        return out.write_str(&special);
+    }
+    // a number left in scientific notation is too large or too small to write out
+    if number.contains(['e', 'E']) {
+        if let Some(decimal) = decimal::Decimal::parse(number) {
+            return out.write_str(&notation::convert_scientific_to_english(&decimal));
+        }
     }
 
-    let mut magnitude = 0;
-    while number > BigInt::from(0) && magnitude < 3 {
-        let remainder = number.clone() % BigInt::from(1000);
-        number = (number - remainder.clone()) / BigInt::from(1000);
+    // the sign is read on its own, since a number like -0.5 has no integer part to carry it
+    let (negative, unsigned) = match number.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, number),
+    };
 
-        if remainder > BigInt::from(0) {
-            let mut remainder_string = convert_hundreds_to_english(remainder);
-            if magnitude > 0 {
-                remainder_string.push(' ');
-                remainder_string.push_str(MAGNITUDES[magnitude]);
+    // an integer that fits in a u128 is read without any big integer arithmetic
+    let is_integer = !unsigned.is_empty() && unsigned.bytes().all(|b| b.is_ascii_digit());
+    if let Some(number) = unsigned.parse::<u128>().ok().filter(|_| is_integer) {
+        if number == 0 {
+            return out.write_str(options.zero_word);
+        }
+        if negative {
+            out.write_str(options.negative_word)?;
+            out.write_char(' ')?;
+        }
+        if options.colloquial_units && !negative {
+            if let Some((count, unit)) = colloquial_unit(number) {
+                return write_colloquial_units_to_english(out, count, unit, options);
             }
-            if !result.is_empty() {
-                remainder_string.push(' ');
+        }
+        if options.informal_article && !negative {
+            return write_informal_integer_to_english(out, &number.to_string(), options);
+        }
+        return write_u128_to_english(out, number, options);
+    }
+
+    let rounded = options
+        .max_decimal_places
+        .map(|places| round_decimal_places(unsigned, places, options.rounding));
+    let (before_decimal, after_decimal, decimal_places) =
+        split_digits(rounded.as_deref().unwrap_or(unsigned));
+
+    let has_integer = before_decimal.is_some();
+
+    if before_decimal.is_none() && after_decimal.is_none() {
+        return out.write_str(options.zero_word);
+    }
+
+    if negative {
+        out.write_str(options.negative_word)?;
+        out.write_char(' ')?;
+    }
+
+    if let Some(before_decimal) = before_decimal {
+        if options.informal_article && !negative {
+            write_informal_integer_to_english(out, before_decimal, options)?;
+        } else {
+            write_integer_to_english(out, before_decimal, options)?;
+        }
+    }
+
+    if let Some(after_decimal) = after_decimal {
+        match options.decimal_style {
+            DecimalStyle::Fraction => {
+                if has_integer {
+                    out.write_str(" and ")?;
+                }
+                write_decimal_to_english(out, after_decimal, decimal_places, options)?;
+            }
+            DecimalStyle::Mixed => {
+                let (numerator, denominator) =
+                    fraction::reduce_decimal(after_decimal, decimal_places);
+                if has_integer {
+                    out.write_str(" and ")?;
+                    out.write_str(&fraction::convert_mixed_part_to_english(
+                        &numerator,
+                        &denominator,
+                        options,
+                    ))?;
+                } else {
+                    out.write_str(&fraction::convert_parts_to_english(
+                        &numerator,
+                        &denominator,
+                        options,
+                    ))?;
+                }
             }
-            remainder_string.push_str(&result);
-            result = remainder_string;
+            DecimalStyle::Point => {
+                if !has_integer {
+                    out.write_str(options.zero_word)?;
+                }
+                out.write_str(" point ")?;
+                write_digits_to_english(out, after_decimal, decimal_places, options)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Write an integer with an informal leading "one hundred" or "one thousand" read as "a hundred" or "a thousand".
+fn write_informal_integer_to_english<W: Write>(
+    out: &mut W,
+    number: &str,
+    options: &Options,
+) -> fmt::Result {
+    let integer = convert_integer_to_english(number, options);
+    match integer.strip_prefix("one ") {
+        Some(rest) => {
+            out.write_str("a ")?;
+            out.write_str(rest)
         }
+        None => out.write_str(&integer),
+    }
+}
 
-        magnitude += 1;
+/// Round an unsigned number string to at most `places` decimal places, dropping any trailing
+/// zeros left by rounding (e.g. "2.345", 2 -> "2.35", "2.996", 2 -> "3")
+fn round_decimal_places(number: &str, places: usize, rounding: Rounding) -> String {
+    let Some((integer, decimal)) = number.split_once('.') else {
+        return String::from(number);
+    };
+    if decimal.len() <= places {
+        return String::from(number);
     }
 
-    result.push(' ');
-    result.push_str(&suffix);
+    let (kept, dropped) = decimal.split_at(places);
+    let first_dropped = dropped.as_bytes()[0];
+    let rest_nonzero = dropped.bytes().skip(1).any(|b| b != b'0');
+    let last_kept = integer.bytes().chain(kept.bytes()).next_back();
+    let round_up = match rounding {
+        Rounding::Truncate => false,
+        Rounding::HalfUp => first_dropped >= b'5',
+        Rounding::HalfEven => {
+            first_dropped > b'5'
+                || (first_dropped == b'5'
+                    && (rest_nonzero || last_kept.is_some_and(|d| (d - b'0') % 2 == 1)))
+        }
+    };
+
+    let mut digits = String::from(integer);
+    digits.push_str(kept);
+    let digits = match digits.trim_start_matches('0') {
+        "" => "0",
+        trimmed => trimmed,
+    };
+    let mut digits = if round_up {
+        decimal::increment_digits(digits)
+    } else {
+        String::from(digits)
+    };
+    if places == 0 {
+        return digits;
+    }
+    while digits.len() <= places {
+        digits.insert(0, '0');
+    }
+    digits.insert(digits.len() - places, '.');
+    let trimmed = digits.trim_end_matches('0').trim_end_matches('.');
+    String::from(trimmed)
+}
 
+/// Convert an integer, given as its decimal digits without a sign, to its name in English
+/// (e.g. "60" -> "sixty")
+fn convert_integer_to_english(number: &str, options: &Options) -> String {
+    let mut result = String::new();
+    let _ = write_integer_to_english(&mut result, number, options);
     result
 }
 
+/// Write the name of an integer, given as its decimal digits without a sign, in English to a sink.
+fn write_integer_to_english<W: Write>(out: &mut W, number: &str, options: &Options) -> fmt::Result {
+    if let Ok(number) = number.parse::<u128>() {
+        return write_u128_to_english(out, number, options);
+    }
+
+    write_integer_in(out, number, options)
+}
+
+/// Write the name of an integer that fits in a `u128`, using plain integer arithmetic.
+fn write_u128_to_english<W: Write>(out: &mut W, number: u128, options: &Options) -> fmt::Result {
+    if options.colloquial_hundreds
+        && (1_100..10_000).contains(&number)
+        && !number.is_multiple_of(1000)
+    {
+        return write_colloquial_hundreds_to_english(out, number as u64, options);
+    }
+
+    write_u128_in(out, number, options)
+}
+
+/// Write the separator before a group of an integer after the first.
+fn write_group_separator<W: Write>(
+    out: &mut W,
+    magnitude: usize,
+    below_hundred: bool,
+    options: &Options,
+) -> fmt::Result {
+    // british style joins a trailing group below one hundred with "and" (e.g. "one thousand and five")
+    if options.british_and && magnitude == 0 && below_hundred {
+        out.write_str(" and ")
+    } else {
+        out.write_str(options.group_separator)
+    }
+}
+
+/// Write the name of the group at the given magnitude after its value, if it has one.
+fn write_magnitude_name<W: Write>(out: &mut W, magnitude: usize, options: &Options) -> fmt::Result {
+    if magnitude > 0 {
+        out.write_char(' ')?;
+        out.write_str(&options.scale.magnitude_name(magnitude))?;
+    }
+    Ok(())
+}
+
+/// Write a number between 1100 and 9999 in hundreds (e.g. 1234 -> "twelve hundred thirty-four")
+fn write_colloquial_hundreds_to_english<W: Write>(
+    out: &mut W,
+    number: u64,
+    options: &Options,
+) -> fmt::Result {
+    let rest = number % 100;
+
+    write_hundreds_to_english(out, number / 100, options)?;
+    out.write_str(" hundred")?;
+    if rest > 0 {
+        out.write_char(' ')?;
+        if options.british_and {
+            out.write_str("and ")?;
+        }
+        write_hundreds_to_english(out, rest, options)?;
+    }
+
+    Ok(())
+}
+
+/// The colloquial units a number may be counted in, with the most of each that is read, in the
+/// order they are preferred.
+const COLLOQUIAL_UNITS: [(u128, &str, u128); 3] =
+    [(144, "gross", 11), (12, "dozen", 11), (20, "score", 4)];
+
+/// Find the colloquial unit that a number is an exact multiple of (e.g. 24 -> (2, "dozen")).
+fn colloquial_unit(number: u128) -> Option<(u64, &'static str)> {
+    COLLOQUIAL_UNITS
+        .iter()
+        .find(|(size, _, most)| number.is_multiple_of(*size) && number / size <= *most)
+        .map(|(size, unit, _)| ((number / size) as u64, *unit))
+}
+
+/// Write a count of a colloquial unit (e.g. 1, "dozen" -> "a dozen", 3, "gross" -> "three gross")
+fn write_colloquial_units_to_english<W: Write>(
+    out: &mut W,
+    count: u64,
+    unit: &str,
+    options: &Options,
+) -> fmt::Result {
+    if count == 1 {
+        out.write_str("a")?;
+    } else {
+        write_hundreds_to_english(out, count, options)?;
+    }
+    out.write_char(' ')?;
+    out.write_str(unit)
+}
+
+/// Write the decimal part of a number in English (e.g. 60.212 -> "two hundred twelve thousandths")
+fn write_decimal_to_english<W: Write>(
+    out: &mut W,
+    number: &str,
+    decimal_places: usize,
+    options: &Options,
+) -> fmt::Result {
+    // get the suffix from the number of digits (e.g. 1 -> "tenth", 2 -> "hundredth", 3 -> "thousandth", etc...)
+    let suffix = match options.scale {
+        Scale::Long | Scale::LongMilliard => Cow::Owned(decimal_name(decimal_places, options)),
+        Scale::Grouped(grouping) if decimal_places <= grouping.decimals.len() => {
+            Cow::Borrowed(grouping.decimals[decimal_places - 1])
+        }
+        _ if decimal_places <= DECIMALS.len() => Cow::Borrowed(DECIMALS[decimal_places - 1]),
+        // places past the end of the table are named from their power of ten (e.g. "ten-sexdecillionth")
+        _ => {
+            let short = Options {
+                scale: Scale::Short,
+                ..options.clone()
+            };
+            Cow::Owned(decimal_name(decimal_places, &short))
+        }
+    };
+    let plural = number != "1";
+
+    write_integer_to_english(out, number, options)?;
+    out.write_char(' ')?;
+    out.write_str(&suffix)?;
+    if plural {
+        out.write_char('s')?;
+    }
+
+    Ok(())
+}
+
+/// Build the name of a decimal place from the name of its power of ten (e.g. 9 -> "thousand-millionth")
+fn decimal_name(decimal_places: usize, options: &Options) -> String {
+    let power = power_of_ten(decimal_places);
+    let plain = Options {
+        british_and: false,
+        group_separator: " ",
+        ..options.clone()
+    };
+    let name = convert_integer_to_english(&power, &plain);
+    let mut result = name.trim_start_matches("one ").replace(' ', "-");
+    result.push_str("th");
+    result
+}
+
+/// Read each digit of the decimal part of a number, keeping leading zeros (e.g. 0.052 -> "zero five two")
+fn write_digits_to_english<W: Write>(
+    out: &mut W,
+    digits: &str,
+    decimal_places: usize,
+    options: &Options,
+) -> fmt::Result {
+    let zeros = decimal_places.saturating_sub(digits.len());
+    let words = core::iter::repeat_n(options.zero_word, zeros).chain(
+        digits
+            .bytes()
+            .map(|digit| digit_to_english(digit - b'0', options)),
+    );
+
+    for (i, word) in words.enumerate() {
+        if i > 0 {
+            out.write_char(' ')?;
+        }
+        out.write_str(word)?;
+    }
+
+    Ok(())
+}
+
+/// Get the name of a single digit (e.g. 0 -> "zero", 7 -> "seven")
+fn digit_to_english(digit: u8, options: &Options) -> &'static str {
+    if digit == 0 {
+        options.zero_word
+    } else {
+        ONE_TO_NINETEEN[(digit - 1) as usize]
+    }
+}
+
 /// Convert a number between 0 and 999 to its name.
-fn convert_hundreds_to_english(number: BigInt) -> String {
+fn convert_hundreds_to_english(number: u64, options: &Options) -> String {
     let mut result = String::new();
-    let mut number = number.to_string().parse::<u64>().unwrap();
+    let _ = write_hundreds_to_english(&mut result, number, options);
+    result
+}
+
+/// Write a number between 0 and 999 to a sink.
+fn write_hundreds_to_english<W: Write>(out: &mut W, number: u64, options: &Options) -> fmt::Result {
+    // with the default joining words, the whole name is in the table
+    if !options.british_and && options.compound_separator == "-" {
+        return match number {
+            1..=999 => out.write_str(ONE_TO_NINE_HUNDRED_NINETY_NINE[number as usize - 1]),
+            _ => Ok(()),
+        };
+    }
 
     let hundreds = number / 100;
-    number %= 100;
+    let mut number = number % 100;
 
     if hundreds > 0 {
-        result.push_str(ONE_TO_NINETEEN[(hundreds - 1) as usize]);
-        result.push_str(" hundred");
+        out.write_str(ONE_TO_NINETEEN[(hundreds - 1) as usize])?;
+        out.write_str(" hundred")?;
         if number > 0 {
-            result.push(' ');
+            out.write_char(' ')?;
+            if options.british_and {
+                out.write_str("and ")?;
+            }
         }
     }
 
     if number > 0 {
         if number < 20 {
-            result.push_str(ONE_TO_NINETEEN[(number - 1) as usize]);
+            out.write_str(ONE_TO_NINETEEN[(number - 1) as usize])?;
         } else {
             let tens = number / 10;
             number %= 10;
-            result.push_str(TENS[(tens - 1) as usize]);
+            out.write_str(TENS[(tens - 1) as usize])?;
             if number > 0 {
-                result.push('-');
-                result.push_str(ONE_TO_NINETEEN[(number - 1) as usize]);
+                out.write_str(options.compound_separator)?;
+                out.write_str(ONE_TO_NINETEEN[(number - 1) as usize])?;
             }
         }
     }
 
+    Ok(())
+}
+
+/// Get the name of a power of ten as its decimal digits (e.g. 3 -> "1000")
+fn power_of_ten(power: usize) -> String {
+    let mut result = String::with_capacity(power + 1);
+    result.push('1');
+    result.extend(core::iter::repeat_n('0', power));
     result
 }
 
+/// Split an unsigned number string into the digits of its integer and decimal parts without
+/// leading zeros, leaving out parts that are zero, and its number of decimal places
+/// (e.g. "012.050" -> (Some("12"), Some("50"), 3))
+fn split_digits(string: &str) -> (Option<&str>, Option<&str>, usize) {
+    let (integer, decimal) = string.split_once('.').unwrap_or((string, ""));
+    (
+        nonzero_digits(integer),
+        nonzero_digits(decimal),
+        decimal.len(),
+    )
+}
+
+/// Get the digits of a part of a number without leading zeros, or `None` if it is zero or isn't
+/// written in digits
+fn nonzero_digits(digits: &str) -> Option<&str> {
+    let trimmed = digits.trim_start_matches('0');
+    if trimmed.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        None
+    } else {
+        Some(trimmed)
+    }
+}
+
+#[cfg(feature = "bigint")]
 fn parse_big_int(n: &str) -> Option<BigInt> {
     let string = n.to_string();
     let bigint = BigInt::parse_bytes(string.as_bytes(), 10).unwrap_or_else(|| BigInt::from(0));
@@ -244,6 +1466,7 @@ fn parse_big_int(n: &str) -> Option<BigInt> {
 }
 
 /// Split a number into its integer and decimal parts.
+#[cfg(feature = "bigint")]
 fn split_number(string: &str) -> SplitNumber {
     let split = string.split('.').collect::<Vec<&str>>();
     let integer = parse_big_int(split[0]);
@@ -366,6 +1589,765 @@ mod tests {
         assert_eq!(fifty_six_thousandths, "fifty-six thousandths");
     }
 
+    #[test]
+    fn test_british_and() {
+        let british = Options {
+            british_and: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            123.to_english_with(&british),
+            "one hundred and twenty-three"
+        );
+        assert_eq!(100.to_english_with(&british), "one hundred");
+        assert_eq!(1_005.to_english_with(&british), "one thousand and five");
+        assert_eq!(1_100.to_english_with(&british), "one thousand one hundred");
+        assert_eq!(
+            1_000_101.to_english_with(&british),
+            "one million one hundred and one"
+        );
+        assert_eq!(
+            5.123.to_english_with(&british),
+            "five and one hundred and twenty-three thousandths"
+        );
+        assert_eq!(123.to_english(), "one hundred twenty-three");
+    }
+
+    #[test]
+    fn test_negative_word() {
+        let minus = Options {
+            negative_word: "minus",
+            ..Default::default()
+        };
+        assert_eq!((-5).to_english_with(&minus), "minus five");
+        assert_eq!(
+            (-1.25).to_english_with(&minus),
+            "minus one and twenty-five hundredths"
+        );
+        assert_eq!(5.to_english_with(&minus), "five");
+        assert_eq!((-5).to_english(), "negative five");
+    }
+
+    #[test]
+    fn test_point_style() {
+        let point = Options {
+            decimal_style: DecimalStyle::Point,
+            ..Default::default()
+        };
+        assert_eq!(60.212.to_english_with(&point), "sixty point two one two");
+        assert_eq!(
+            6.000_052.to_english_with(&point),
+            "six point zero zero zero zero five two"
+        );
+        assert_eq!(0.5.to_english_with(&point), "zero point five");
+        assert_eq!(
+            (-3.25).to_english_with(&point),
+            "negative three point two five"
+        );
+        assert_eq!(42.to_english_with(&point), "forty-two");
+    }
+
+    #[test]
+    fn test_case() {
+        let with_case = |case| Options {
+            case,
+            british_and: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            123.to_english_with(&with_case(Case::Title)),
+            "One Hundred and Twenty-Three"
+        );
+        assert_eq!(
+            123.to_english_with(&with_case(Case::Sentence)),
+            "One hundred and twenty-three"
+        );
+        assert_eq!(
+            123.to_english_with(&with_case(Case::Upper)),
+            "ONE HUNDRED AND TWENTY-THREE"
+        );
+        assert_eq!(
+            (-1.5).to_english_with(&with_case(Case::Title)),
+            "Negative One and Five Tenths"
+        );
+        assert_eq!(
+            100_000_000.to_english_with(&with_case(Case::Title)),
+            "One Hundred Million"
+        );
+    }
+
+    #[test]
+    fn test_compound_separator() {
+        let with_separator = |compound_separator| Options {
+            compound_separator,
+            ..Default::default()
+        };
+        assert_eq!(21.to_english_with(&with_separator(" ")), "twenty one");
+        assert_eq!(
+            21.to_english_with(&with_separator("\u{2011}")),
+            "twenty\u{2011}one"
+        );
+        assert_eq!(
+            99_021.to_english_with(&with_separator("")),
+            "ninetynine thousand twentyone"
+        );
+        let title = Options {
+            case: Case::Title,
+            ..with_separator("\u{2011}")
+        };
+        assert_eq!(21.to_english_with(&title), "Twenty\u{2011}One");
+    }
+
+    #[test]
+    fn test_group_separator() {
+        let commas = Options {
+            group_separator: ", ",
+            ..Default::default()
+        };
+        assert_eq!(
+            1_234_567.to_english_with(&commas),
+            "one million, two hundred thirty-four thousand, five hundred sixty-seven"
+        );
+        assert_eq!(1_000_000.to_english_with(&commas), "one million");
+        assert_eq!(1_000_005.to_english_with(&commas), "one million, five");
+        let british = Options {
+            british_and: true,
+            ..commas
+        };
+        assert_eq!(
+            2_001_005.to_english_with(&british),
+            "two million, one thousand and five"
+        );
+    }
+
+    #[test]
+    fn test_long_scale() {
+        let long = Options {
+            scale: Scale::Long,
+            ..Default::default()
+        };
+        assert_eq!(1_000_000.to_english_with(&long), "one million");
+        assert_eq!(1_000_000_000.to_english_with(&long), "one thousand million");
+        assert_eq!(
+            1_500_000_000.to_english_with(&long),
+            "one thousand five hundred million"
+        );
+        assert_eq!(1_000_000_000_000_u64.to_english_with(&long), "one billion");
+        assert_eq!(
+            2_003_000_000_004_u64.to_english_with(&long),
+            "two billion three thousand million four"
+        );
+        assert_eq!(
+            0.000_000_001.to_english_with(&long),
+            "one thousand-millionth"
+        );
+
+        let milliard = Options {
+            scale: Scale::LongMilliard,
+            ..Default::default()
+        };
+        assert_eq!(1_000_000_000.to_english_with(&milliard), "one milliard");
+        assert_eq!(
+            1_500_000_000.to_english_with(&milliard),
+            "one milliard five hundred million"
+        );
+        assert_eq!(
+            3_000_000_000_000_000_u64.to_english_with(&milliard),
+            "three billiard"
+        );
+        assert_eq!(0.000_000_002.to_english_with(&milliard), "two milliardths");
+    }
+
+    #[test]
+    fn test_indian_scale() {
+        let indian = Options {
+            scale: Scale::Indian,
+            ..Default::default()
+        };
+        assert_eq!(
+            1_234_567.to_english_with(&indian),
+            "twelve lakh thirty-four thousand five hundred sixty-seven"
+        );
+        assert_eq!(10_000_000.to_english_with(&indian), "one crore");
+        assert_eq!(100_000.to_english_with(&indian), "one lakh");
+        assert_eq!(
+            123_456_789.to_english_with(&indian),
+            "twelve crore thirty-four lakh fifty-six thousand seven hundred eighty-nine"
+        );
+        assert_eq!(
+            12_345_000_000_000_u64.to_english_with(&indian),
+            "twelve lakh thirty-four thousand five hundred crore"
+        );
+        assert_eq!(
+            200_000_000_000_000_u64.to_english_with(&indian),
+            "two crore crore"
+        );
+        assert_eq!(
+            100_000.5.to_english_with(&indian),
+            "one lakh and five tenths"
+        );
+    }
+
+    #[test]
+    fn test_grouped_scale() {
+        static MYRIAD: Grouping = Grouping {
+            digits: 4,
+            names: &["myriad", "myllion"],
+            decimals: &[],
+        };
+        let myriad = Options {
+            scale: Scale::Grouped(&MYRIAD),
+            ..Default::default()
+        };
+        assert_eq!(10_000.to_english_with(&myriad), "one myriad");
+        assert_eq!(
+            12_345_678.to_english_with(&myriad),
+            "one thousand two hundred thirty-four myriad five thousand six hundred seventy-eight"
+        );
+        assert_eq!(100_000_000.to_english_with(&myriad), "one myllion");
+        assert_eq!(1_0000_0001.to_english_with(&myriad), "one myllion one");
+        assert_eq!(0.5.to_english_with(&myriad), "five tenths");
+    }
+
+    #[test]
+    fn test_conway_wechsler() {
+        assert_eq!(scales::illion_name(1), "million");
+        assert_eq!(scales::illion_name(1000), "millinillion");
+        assert_eq!(scales::illion_name(1001), "millimillion");
+        assert_eq!(
+            scales::illion_name(1234),
+            "milliquattuortrigintaducentillion"
+        );
+        assert_eq!(scales::illion_name(1_000_000), "millinillinillion");
+
+        let mut digits = String::from("1");
+        digits.push_str(&"0".repeat(3006));
+        assert_eq!(digits.as_str().to_english(), Ok("one millimillion".into()));
+    }
+
+    #[test]
+    fn test_custom_tables() {
+        static CUSTOM: Grouping = Grouping {
+            digits: 3,
+            names: &["grand", "mil"],
+            decimals: &["dime", "cent"],
+        };
+        let custom = Options {
+            scale: Scale::Grouped(&CUSTOM),
+            ..Default::default()
+        };
+        assert_eq!(
+            3_045_000.to_english_with(&custom),
+            "three mil forty-five grand"
+        );
+        assert_eq!(0.01.to_english_with(&custom), "one cent");
+        assert_eq!(2.25.to_english_with(&custom), "two and twenty-five cents");
+    }
+
+    #[test]
+    fn test_long_decimals() {
+        assert_eq!(
+            0.123_456.to_english(),
+            "one hundred twenty-three thousand four hundred fifty-six millionths"
+        );
+        assert_eq!(
+            0.123_456_7.to_english(),
+            "one million two hundred thirty-four thousand five hundred sixty-seven ten-millionths"
+        );
+    }
+
+    #[test]
+    fn test_table_overflow() {
+        assert_eq!(0.000_000_001.to_english(), "one billionth");
+        assert_eq!(0.000_000_002.to_english(), "two billionths");
+
+        static MYRIAD: Grouping = Grouping {
+            digits: 4,
+            names: &["myriad", "myllion"],
+            decimals: &["tenth"],
+        };
+        let myriad = Options {
+            scale: Scale::Grouped(&MYRIAD),
+            ..Default::default()
+        };
+        assert_eq!(
+            1_0000_0000_0000_u64.to_english_with(&myriad),
+            "one myriad myllion"
+        );
+        assert_eq!(
+            2_0000_0000_0000_0000_u64.to_english_with(&myriad),
+            "two myllion myllion"
+        );
+        assert_eq!(0.05.to_english_with(&myriad), "five hundredths");
+
+        static UNNAMED: Grouping = Grouping {
+            digits: 0,
+            names: &[],
+            decimals: &[],
+        };
+        let unnamed = Options {
+            scale: Scale::Grouped(&UNNAMED),
+            ..Default::default()
+        };
+        assert_eq!(42.to_english_with(&unnamed), "four ten two");
+        assert_eq!(300.to_english_with(&unnamed), "three hundred");
+    }
+
+    #[test]
+    fn test_zero_word() {
+        let with_zero = |zero_word| Options {
+            zero_word,
+            decimal_style: DecimalStyle::Point,
+            ..Default::default()
+        };
+        assert_eq!(0.to_english_with(&with_zero("nil")), "nil");
+        assert_eq!(
+            0.5.to_english_with(&with_zero("naught")),
+            "naught point five"
+        );
+        assert_eq!(1.05.to_english_with(&with_zero("oh")), "one point oh five");
+        assert_eq!(0.to_english(), "zero");
+    }
+
+    #[test]
+    fn test_informal_article() {
+        let informal = Options {
+            informal_article: true,
+            ..Default::default()
+        };
+        assert_eq!(100.to_english_with(&informal), "a hundred");
+        assert_eq!(1_200.to_english_with(&informal), "a thousand two hundred");
+        assert_eq!(
+            1_100_000.to_english_with(&informal),
+            "a million one hundred thousand"
+        );
+        assert_eq!(1.to_english_with(&informal), "one");
+        assert_eq!(200.to_english_with(&informal), "two hundred");
+        assert_eq!((-100).to_english_with(&informal), "negative one hundred");
+        assert_eq!(100.to_english(), "one hundred");
+    }
+
+    #[test]
+    fn test_colloquial_units() {
+        let colloquial = Options {
+            colloquial_units: true,
+            ..Default::default()
+        };
+        assert_eq!(12.to_english_with(&colloquial), "a dozen");
+        assert_eq!(24.to_english_with(&colloquial), "two dozen");
+        assert_eq!(144.to_english_with(&colloquial), "a gross");
+        assert_eq!(288_u16.to_english_with(&colloquial), "two gross");
+        assert_eq!(20.to_english_with(&colloquial), "a score");
+        assert_eq!(60.to_english_with(&colloquial), "five dozen");
+        assert_eq!(80.to_english_with(&colloquial), "four score");
+        assert_eq!(100.to_english_with(&colloquial), "one hundred");
+        assert_eq!(13.to_english_with(&colloquial), "thirteen");
+        assert_eq!((-12).to_english_with(&colloquial), "negative twelve");
+        assert_eq!(12.5.to_english_with(&colloquial), "twelve and five tenths");
+        assert_eq!(12.to_english_cow(&colloquial), "a dozen");
+        assert_eq!(
+            12.to_english_with(&Options {
+                case: Case::Title,
+                ..colloquial
+            }),
+            "A Dozen"
+        );
+        assert_eq!(12.to_english(), "twelve");
+    }
+
+    #[test]
+    fn test_colloquial_hundreds() {
+        let colloquial = Options {
+            colloquial_hundreds: true,
+            ..Default::default()
+        };
+        assert_eq!(1_100.to_english_with(&colloquial), "eleven hundred");
+        assert_eq!(1_200.to_english_with(&colloquial), "twelve hundred");
+        assert_eq!(2_500.to_english_with(&colloquial), "twenty-five hundred");
+        assert_eq!(
+            1_234.to_english_with(&colloquial),
+            "twelve hundred thirty-four"
+        );
+        assert_eq!(
+            (-9_999).to_english_with(&colloquial),
+            "negative ninety-nine hundred ninety-nine"
+        );
+        assert_eq!(2_000.to_english_with(&colloquial), "two thousand");
+        assert_eq!(1_050.to_english_with(&colloquial), "one thousand fifty");
+        assert_eq!(12_000.to_english_with(&colloquial), "twelve thousand");
+        assert_eq!(1_200.to_english(), "one thousand two hundred");
+    }
+
+    #[test]
+    fn test_ordinal_words() {
+        assert_eq!(1.to_english_ordinal(), Ok("first".into()));
+        assert_eq!(2.to_english_ordinal(), Ok("second".into()));
+        assert_eq!(3.to_english_ordinal(), Ok("third".into()));
+        assert_eq!(0.to_english_ordinal(), Ok("zeroth".into()));
+        assert_eq!(12.to_english_ordinal(), Ok("twelfth".into()));
+        assert_eq!(40.to_english_ordinal(), Ok("fortieth".into()));
+        assert_eq!(99.to_english_ordinal(), Ok("ninety-ninth".into()));
+        assert_eq!(1_000_000.to_english_ordinal(), Ok("one millionth".into()));
+        assert_eq!(1.5.to_english_ordinal(), Err(Error::InvalidNumber));
+        assert_eq!((-0.25).to_english_ordinal(), Err(Error::InvalidNumber));
+        assert_eq!(f64::NAN.to_english_ordinal(), Err(Error::NonFinite));
+        assert_eq!(f64::INFINITY.to_english_ordinal(), Err(Error::NonFinite));
+        assert_eq!(2.0.to_english_ordinal(), Ok("second".into()));
+    }
+
+    #[test]
+    fn test_approx() {
+        assert_eq!(
+            1_234_567.to_english_approx(2),
+            "about one point two million"
+        );
+        assert_eq!(
+            1_234_567.to_english_approx(3),
+            "about one point two three million"
+        );
+        assert_eq!(
+            (-2_500_000_000_i64).to_english_approx(2),
+            "negative two point five billion"
+        );
+        assert_eq!(999_999.to_english_approx(2), "about one million");
+        assert_eq!(12_345.to_english_approx(2), "about twelve thousand");
+        assert_eq!(123.to_english_approx(5), "one hundred twenty-three");
+        assert_eq!(3.16227.to_english_approx(3), "about three point one six");
+        assert_eq!(0.to_english_approx(2), "zero");
+        assert_eq!(
+            1_234_567.to_english_approx(0),
+            1_234_567.to_english_approx(1)
+        );
+        assert_eq!(
+            f64::MAX.to_english_approx(2),
+            "about one hundred eighty uncentillion"
+        );
+    }
+
+    #[test]
+    fn test_negative_decimals() {
+        assert_eq!((-0.5).to_english(), "negative five tenths");
+        assert_eq!((-0.0).to_english(), "zero");
+        assert_eq!(
+            (-0.25).to_english_with(&Options {
+                decimal_style: DecimalStyle::Point,
+                ..Default::default()
+            }),
+            "negative zero point two five"
+        );
+        assert_eq!(
+            (-0.75).to_english_with(&Options {
+                decimal_style: DecimalStyle::Mixed,
+                ..Default::default()
+            }),
+            "negative three quarters"
+        );
+        assert_eq!((-1e-3).to_english(), "negative one thousandth");
+    }
+
+    #[test]
+    fn test_rounding() {
+        let places = |max, rounding| Options {
+            max_decimal_places: Some(max),
+            rounding,
+            ..Default::default()
+        };
+        let half_up = places(2, Rounding::HalfUp);
+        assert_eq!(
+            3.16227.to_english_with(&half_up),
+            "three and sixteen hundredths"
+        );
+        assert_eq!(
+            2.345.to_english_with(&half_up),
+            "two and thirty-five hundredths"
+        );
+        assert_eq!(2.996.to_english_with(&half_up), "three");
+        assert_eq!(2.104.to_english_with(&half_up), "two and one tenth");
+        assert_eq!(1.5.to_english_with(&half_up), "one and five tenths");
+        assert_eq!(
+            (-0.125).to_english_with(&half_up),
+            "negative thirteen hundredths"
+        );
+        assert_eq!((-0.001).to_english_with(&half_up), "zero");
+        assert_eq!(0.5.to_english_with(&places(0, Rounding::HalfUp)), "one");
+
+        let half_even = places(0, Rounding::HalfEven);
+        assert_eq!(0.5.to_english_with(&half_even), "zero");
+        assert_eq!(1.5.to_english_with(&half_even), "two");
+        assert_eq!(2.5.to_english_with(&half_even), "two");
+        assert_eq!(2.501.to_english_with(&half_even), "three");
+        assert_eq!(
+            0.125.to_english_with(&places(2, Rounding::HalfEven)),
+            "twelve hundredths"
+        );
+
+        let truncate = places(1, Rounding::Truncate);
+        assert_eq!(2.99.to_english_with(&truncate), "two and nine tenths");
+        assert_eq!(
+            (-2.99).to_english_with(&truncate),
+            "negative two and nine tenths"
+        );
+        assert_eq!(0.01.to_english_with(&truncate), "zero");
+    }
+
+    #[test]
+    fn test_to_english_into() {
+        let mut buffer = String::from("a");
+        0.to_english_into(&mut buffer, &Options::default());
+        assert_eq!(buffer, "azero");
+
+        let informal = Options {
+            informal_article: true,
+            ..Default::default()
+        };
+        let mut buffer = String::from("about ");
+        100.to_english_into(&mut buffer, &informal);
+        assert_eq!(buffer, "about a hundred");
+
+        let title = Options {
+            case: Case::Title,
+            ..Default::default()
+        };
+        let mut buffer = String::from("total: ");
+        (-21.5).to_english_into(&mut buffer, &title);
+        assert_eq!(buffer, "total: Negative Twenty-One and Five Tenths");
+    }
+
+    #[test]
+    fn test_write_english() {
+        // a sink that holds a fixed number of bytes, and fails once it is full
+        struct Fixed {
+            bytes: [u8; 16],
+            len: usize,
+        }
+        impl Write for Fixed {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                if end > self.bytes.len() {
+                    return Err(fmt::Error);
+                }
+                self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let options = Options::default();
+        let mut fixed = Fixed {
+            bytes: [0; 16],
+            len: 0,
+        };
+        assert_eq!(42.write_english(&mut fixed, &options), Ok(()));
+        assert_eq!(&fixed.bytes[..fixed.len], b"forty-two");
+        assert_eq!(123.write_english(&mut fixed, &options), Err(fmt::Error));
+
+        let british_title = Options {
+            british_and: true,
+            case: Case::Title,
+            ..Default::default()
+        };
+        let mut out = String::new();
+        1_000_105.write_english(&mut out, &british_title).unwrap();
+        assert_eq!(out, "One Million One Hundred and Five");
+
+        let sentence = Options {
+            case: Case::Sentence,
+            ..Default::default()
+        };
+        let mut out = String::new();
+        (-0.052).write_english(&mut out, &sentence).unwrap();
+        assert_eq!(out, "Negative fifty-two thousandths");
+    }
+
+    #[test]
+    fn test_write_english_to_slice() {
+        let options = Options::default();
+        let mut buffer = [0; 32];
+        assert_eq!(0.write_english_to_slice(&mut buffer, &options), Ok(4));
+        assert_eq!(&buffer[..4], b"zero");
+
+        let len = (-1.5).write_english_to_slice(&mut buffer, &options);
+        assert_eq!(len, Ok(28));
+        assert_eq!(&buffer[..28], b"negative one and five tenths");
+
+        let upper = Options {
+            case: Case::Upper,
+            ..Default::default()
+        };
+        let mut exact = [0; 9];
+        assert_eq!(42.write_english_to_slice(&mut exact, &upper), Ok(9));
+        assert_eq!(&exact, b"FORTY-TWO");
+        assert_eq!(
+            43.write_english_to_slice(&mut exact[..8], &upper),
+            Err(Error::BufferTooSmall)
+        );
+        assert_eq!(
+            1.write_english_to_slice(&mut [], &options),
+            Err(Error::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_english_len() {
+        let options = Options::default();
+        for n in [
+            0.0,
+            -0.5,
+            12.25,
+            1e21,
+            7_000_000.0,
+            f64::NAN,
+            f64::NEG_INFINITY,
+        ] {
+            assert_eq!(n.english_len(&options), n.to_english().len());
+        }
+
+        let cheque = Options {
+            case: Case::Title,
+            british_and: true,
+            decimal_style: DecimalStyle::Mixed,
+            ..Default::default()
+        };
+        let amount = 1_234_567.5;
+        assert_eq!(
+            amount.english_len(&cheque),
+            amount.to_english_with(&cheque).len()
+        );
+        assert_eq!(
+            u128::MAX.english_len(&options),
+            u128::MAX.to_english().len()
+        );
+    }
+
+    #[test]
+    fn test_to_english_cow() {
+        let options = Options::default();
+        for n in [0, 7, 19, 40, 90, 100, 342, 900, 999] {
+            let name = n.to_english_cow(&options);
+            assert!(matches!(name, Cow::Borrowed(_)));
+            assert_eq!(name, n.to_english());
+        }
+        assert_eq!(5.0.to_english_cow(&options), Cow::Borrowed("five"));
+        for n in [1_000, -3, u64::MAX as i128 + 1] {
+            let name = n.to_english_cow(&options);
+            assert!(matches!(name, Cow::Owned(_)));
+            assert_eq!(name, n.to_english());
+        }
+        assert!(matches!(0.5.to_english_cow(&options), Cow::Owned(_)));
+
+        let none = Options {
+            zero_word: "none",
+            ..Default::default()
+        };
+        assert_eq!(0.to_english_cow(&none), Cow::Borrowed("none"));
+        let informal = Options {
+            informal_article: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            100.to_english_cow(&informal),
+            Cow::<str>::Owned("a hundred".into())
+        );
+        assert_eq!(200.to_english_cow(&informal), Cow::Borrowed("two hundred"));
+        let british = Options {
+            british_and: true,
+            ..Default::default()
+        };
+        assert_eq!(60.to_english_cow(&british), Cow::Borrowed("sixty"));
+        assert_eq!(
+            105.to_english_cow(&british),
+            Cow::<str>::Owned("one hundred and five".into())
+        );
+        let upper = Options {
+            case: Case::Upper,
+            ..Default::default()
+        };
+        assert_eq!(3.to_english_cow(&upper), Cow::<str>::Owned("THREE".into()));
+    }
+
+    #[test]
+    fn test_try_to_english() {
+        assert_eq!(0.try_to_english(), Ok("zero".into()));
+        assert_eq!(
+            (-12.5).try_to_english(),
+            Ok("negative twelve and five tenths".into())
+        );
+        assert_eq!(1e21.try_to_english(), Ok("one sextillion".into()));
+        assert_eq!(f32::INFINITY.try_to_english(), Err(Error::NonFinite));
+        assert_eq!(num_bigfloat::NAN.try_to_english(), Err(Error::NonFinite));
+
+        static TENS: Grouping = Grouping {
+            digits: 1,
+            names: &["ten"],
+            decimals: &["tenth"],
+        };
+        let tens = Options {
+            scale: Scale::Grouped(&TENS),
+            ..Default::default()
+        };
+        assert_eq!(42.try_to_english_with(&tens), Ok("four ten two".into()));
+        assert_eq!(
+            420.try_to_english_with(&tens),
+            Err(Error::MagnitudeOverflow)
+        );
+        assert_eq!(0.5.try_to_english_with(&tens), Ok("five tenths".into()));
+        assert_eq!(
+            0.25.try_to_english_with(&tens),
+            Err(Error::MagnitudeOverflow)
+        );
+        let point = Options {
+            decimal_style: DecimalStyle::Point,
+            ..tens.clone()
+        };
+        assert_eq!(
+            0.25.try_to_english_with(&point),
+            Ok("zero point two five".into())
+        );
+    }
+
+    #[test]
+    fn test_non_finite() {
+        assert_eq!(f64::NAN.to_english(), "not a number");
+        assert_eq!(f64::INFINITY.to_english(), "infinity");
+        assert_eq!(f32::NEG_INFINITY.to_english(), "negative infinity");
+        let minus = Options {
+            negative_word: "minus",
+            case: Case::Title,
+            ..Default::default()
+        };
+        assert_eq!(f64::NEG_INFINITY.to_english_with(&minus), "Minus Infinity");
+        assert_eq!(num_bigfloat::INF_POS.to_english(), "infinity");
+        assert_eq!(num_bigfloat::NAN.to_english(), "not a number");
+        assert_eq!(f64::INFINITY.to_english_scientific(), "infinity");
+        assert_eq!(f64::NAN.to_english_approx(2), "not a number");
+        assert_eq!(
+            convert_non_finite_to_english("-@Inf@", &Options::default()).as_deref(),
+            Some("negative infinity")
+        );
+        assert_eq!(
+            convert_non_finite_to_english("@NaN@", &Options::default()).as_deref(),
+            Some("not a number")
+        );
+    }
+
+    #[test]
+    fn test_ordinal_numeral() {
+        assert_eq!(1.to_ordinal_numeral(), "1st");
+        assert_eq!(2.to_ordinal_numeral(), "2nd");
+        assert_eq!(3.to_ordinal_numeral(), "3rd");
+        assert_eq!(4.to_ordinal_numeral(), "4th");
+        assert_eq!(11.to_ordinal_numeral(), "11th");
+        assert_eq!(12.to_ordinal_numeral(), "12th");
+        assert_eq!(13.to_ordinal_numeral(), "13th");
+        assert_eq!(61.to_ordinal_numeral(), "61st");
+        assert_eq!(103.to_ordinal_numeral(), "103rd");
+        assert_eq!(111.to_ordinal_numeral(), "111th");
+        assert_eq!(112.to_ordinal_numeral(), "112th");
+        assert_eq!((-1).to_ordinal_numeral(), "-1st");
+        assert_eq!(0.to_ordinal_numeral(), "0th");
+    }
+
+    #[cfg(feature = "bigint")]
     #[test]
     fn test_bigint() {
         let bigint_num = BigInt::parse_bytes(b"1234", 10).unwrap();
@@ -373,12 +2355,54 @@ mod tests {
         assert_eq!(bigint_num_name, "one thousand two hundred thirty-four");
     }
 
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_primitive_matches_bigint() {
+        // a number past u128::MAX is read with big integers down to its lowest groups
+        let high = BigInt::from(7) * BigInt::from(10).pow(39);
+        for n in [1_u128, 1_005, 12_345_678_901, u64::MAX as u128, u128::MAX] {
+            let big = (high.clone() + n).to_english();
+            let low = n.to_english();
+            assert!(big.ends_with(&low), "{} does not end with {}", big, low);
+        }
+    }
+
+    #[test]
+    fn test_hundreds_table() {
+        let spaced = Options {
+            compound_separator: "~",
+            ..Default::default()
+        };
+        for n in 1..1000 {
+            let built = convert_hundreds_to_english(n as u64, &spaced);
+            assert_eq!(
+                ONE_TO_NINE_HUNDRED_NINETY_NINE[n - 1],
+                built.replace('~', "-")
+            );
+        }
+        assert_eq!(convert_hundreds_to_english(0, &Options::default()), "");
+    }
+
     #[test]
-    #[should_panic]
-    fn test_big_float_panic() {
+    fn test_big_float() {
         use num_bigfloat::BigFloat;
         let bigfloat_num = BigFloat::from(1234.5678);
         let bigfloat_num_name = bigfloat_num.to_english();
-        assert_eq!(bigfloat_num_name, "one thousand two hundred thirty-four and five thousand six hundred seventy-eight hundredths");
+        assert_eq!(bigfloat_num_name, "one thousand two hundred thirty-four and five thousand six hundred seventy-eight ten-thousandths");
+    }
+
+    #[test]
+    fn test_scientific_input() {
+        assert_eq!(1e21_f64.to_english(), "one sextillion");
+        assert_eq!(
+            num_bigfloat::BigFloat::from(0.5).to_english(),
+            "five tenths"
+        );
+        assert_eq!(
+            num_bigfloat::BigFloat::from(-2.0).to_english(),
+            "negative two"
+        );
+        assert_eq!(expand_scientific(String::from("1.5e3")), "1500");
+        assert_eq!(expand_scientific(String::from("-2.5E-3")), "-0.0025");
     }
 }
@@ -0,0 +1,98 @@
+use crate::{
+    convert_integer_to_english, convert_number_to_english, decimal::Decimal, ordinal_words,
+    DecimalStyle, Options,
+};
+use alloc::string::{String, ToString};
+
+/// Convert a number to its name in scientific notation (e.g. 6.02e23 -> "six point zero two times ten to the twenty-third")
+pub(crate) fn convert_scientific_to_english(number: &Decimal) -> String {
+    let exponent = if number.is_zero() {
+        0
+    } else {
+        number.leading_power()
+    };
+    convert_exponent_to_english(number, exponent)
+}
+
+/// Convert a number to its name in engineering notation, with an exponent that is a multiple of three
+/// (e.g. 4.7e-8 -> "forty-seven times ten to the negative ninth")
+pub(crate) fn convert_engineering_to_english(number: &Decimal) -> String {
+    let exponent = if number.is_zero() {
+        0
+    } else {
+        number.leading_power().div_euclid(3) * 3
+    };
+    convert_exponent_to_english(number, exponent)
+}
+
+/// Convert a number to a mantissa times ten to the given exponent (e.g. 1500, 3 -> "one point five times ten to the third")
+fn convert_exponent_to_english(number: &Decimal, exponent: i64) -> String {
+    let options = Options {
+        decimal_style: DecimalStyle::Point,
+        ..Default::default()
+    };
+    let mantissa = number.shift(-exponent);
+    let mut result = convert_number_to_english(mantissa.to_plain_string(), &options);
+
+    if exponent != 0 {
+        result.push_str(" times ten to the ");
+        if exponent < 0 {
+            result.push_str(options.negative_word);
+            result.push(' ');
+        }
+        let power = convert_integer_to_english(&exponent.unsigned_abs().to_string(), &options);
+        result.push_str(&ordinal_words(&power));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NumberToEnglish;
+
+    #[test]
+    fn test_engineering() {
+        assert_eq!(
+            0.000_000_047.to_english_engineering(),
+            "forty-seven times ten to the negative ninth"
+        );
+        assert_eq!(
+            4_700.to_english_engineering(),
+            "four point seven times ten to the third"
+        );
+        assert_eq!(
+            (-0.15).to_english_engineering(),
+            "negative one hundred fifty times ten to the negative third"
+        );
+        assert_eq!(
+            1e24_f64.to_english_engineering(),
+            "one times ten to the twenty-fourth"
+        );
+        assert_eq!(470.to_english_engineering(), "four hundred seventy");
+        assert_eq!(0.to_english_engineering(), "zero");
+    }
+
+    #[test]
+    fn test_scientific() {
+        assert_eq!(
+            602_000_000_000_000_000_000_000_f64.to_english_scientific(),
+            "six point zero two times ten to the twenty-third"
+        );
+        assert_eq!(
+            0.000_012.to_english_scientific(),
+            "one point two times ten to the negative fifth"
+        );
+        assert_eq!(
+            (-1500).to_english_scientific(),
+            "negative one point five times ten to the third"
+        );
+        assert_eq!(10.to_english_scientific(), "one times ten to the first");
+        assert_eq!(7.to_english_scientific(), "seven");
+        assert_eq!(0.to_english_scientific(), "zero");
+        assert_eq!(
+            f64::MIN_POSITIVE.to_english_scientific(),
+            "two point two two five zero seven three eight five eight five zero seven two zero one four times ten to the negative three hundred eighth"
+        );
+    }
+}
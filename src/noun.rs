@@ -0,0 +1,173 @@
+use crate::{NumberToEnglish, Options};
+use alloc::string::String;
+use core::fmt::Display;
+use num_traits::Num;
+
+/// Common English nouns whose plurals do not follow the regular rule, as (singular, plural).
+pub const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    ("child", "children"),
+    ("person", "people"),
+    ("man", "men"),
+    ("woman", "women"),
+    ("mouse", "mice"),
+    ("goose", "geese"),
+    ("foot", "feet"),
+    ("tooth", "teeth"),
+    ("ox", "oxen"),
+    ("die", "dice"),
+    ("leaf", "leaves"),
+    ("half", "halves"),
+    ("knife", "knives"),
+    ("life", "lives"),
+    ("wife", "wives"),
+    ("wolf", "wolves"),
+    ("shelf", "shelves"),
+    ("thief", "thieves"),
+    ("potato", "potatoes"),
+    ("tomato", "tomatoes"),
+    ("hero", "heroes"),
+    ("criterion", "criteria"),
+    ("phenomenon", "phenomena"),
+    ("cactus", "cacti"),
+    ("sheep", "sheep"),
+    ("fish", "fish"),
+    ("deer", "deer"),
+    ("series", "series"),
+    ("species", "species"),
+    ("aircraft", "aircraft"),
+];
+
+/// How a noun is counted.
+///
+/// # Examples
+/// ```
+/// use num2english::{count_noun, NounStyle};
+/// let style = NounStyle {
+///     irregular: &[("octopus", "octopodes")],
+///     ..Default::default()
+/// };
+/// assert_eq!(count_noun(&8, "octopus", &style), "eight octopodes");
+/// assert_eq!(count_noun(&2, "child", &style), "two childs");
+/// ```
+#[derive(Debug, Clone)]
+pub struct NounStyle {
+    /// Nouns whose plurals are looked up rather than formed by the rule, as (singular, plural).
+    pub irregular: &'static [(&'static str, &'static str)],
+    /// The rule that forms the plural of any other noun.
+    pub plural: fn(&str) -> String,
+    /// The options used to read the count.
+    pub options: Options,
+}
+
+impl Default for NounStyle {
+    fn default() -> Self {
+        NounStyle {
+            irregular: IRREGULAR_PLURALS,
+            plural: regular_plural,
+            options: Options::default(),
+        }
+    }
+}
+
+/// Form the plural of a noun by the regular English rule (e.g. "box" -> "boxes", "city" -> "cities").
+///
+/// # Examples
+/// ```
+/// use num2english::regular_plural;
+/// assert_eq!(regular_plural("cat"), "cats");
+/// assert_eq!(regular_plural("church"), "churches");
+/// assert_eq!(regular_plural("day"), "days");
+/// ```
+pub fn regular_plural(noun: &str) -> String {
+    let mut plural = String::from(noun);
+    if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|ending| noun.ends_with(ending))
+    {
+        plural.push_str("es");
+    } else if let Some(stem) = noun.strip_suffix('y').filter(|stem| {
+        stem.chars()
+            .next_back()
+            .is_some_and(|c| !"aeiou".contains(c))
+    }) {
+        plural.truncate(stem.len());
+        plural.push_str("ies");
+    } else {
+        plural.push('s');
+    }
+    plural
+}
+
+/// Convert a count to its name in English followed by a noun that agrees with it
+/// (e.g. 3, "child" -> "three children").
+///
+/// The noun is singular only for a count of one.
+///
+/// # Examples
+/// ```
+/// use num2english::{count_noun, NounStyle};
+/// let style = NounStyle::default();
+/// assert_eq!(count_noun(&3, "child", &style), "three children");
+/// assert_eq!(count_noun(&1, "box", &style), "one box");
+/// assert_eq!(count_noun(&0, "city", &style), "zero cities");
+/// ```
+pub fn count_noun<T: Num + Display>(count: &T, noun: &str, style: &NounStyle) -> String {
+    let mut result = count.to_english_with(&style.options);
+    result.push(' ');
+    if count.is_one() {
+        result.push_str(noun);
+        return result;
+    }
+    match style
+        .irregular
+        .iter()
+        .find(|(singular, _)| *singular == noun)
+    {
+        Some((_, plural)) => result.push_str(plural),
+        None => result.push_str(&(style.plural)(noun)),
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{count_noun, regular_plural, Case, NounStyle, Options};
+    use alloc::string::String;
+
+    #[test]
+    fn test_count_noun() {
+        let style = NounStyle::default();
+        assert_eq!(count_noun(&1, "person", &style), "one person");
+        assert_eq!(count_noun(&2, "person", &style), "two people");
+        assert_eq!(count_noun(&12, "sheep", &style), "twelve sheep");
+        assert_eq!(count_noun(&-4, "wolf", &style), "negative four wolves");
+        assert_eq!(
+            count_noun(&1.5, "hour", &style),
+            "one and five tenths hours"
+        );
+
+        for (noun, plural) in [
+            ("bus", "buses"),
+            ("dish", "dishes"),
+            ("box", "boxes"),
+            ("key", "keys"),
+            ("party", "parties"),
+            ("y", "ys"),
+        ] {
+            assert_eq!(regular_plural(noun), plural);
+        }
+
+        fn shout(noun: &str) -> String {
+            noun.to_uppercase() + "S"
+        }
+        let custom = NounStyle {
+            irregular: &[],
+            plural: shout,
+            options: Options {
+                case: Case::Title,
+                ..Default::default()
+            },
+        };
+        assert_eq!(count_noun(&20, "child", &custom), "Twenty CHILDS");
+    }
+}
@@ -0,0 +1,458 @@
+use crate::{convert_integer_to_english, scales::illion_name};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
+use core::fmt::{self, Write};
+use core::iter::repeat_n;
+
+/// Options for customizing how a number is converted to English.
+///
+/// The default options produce the same output as [`to_english`].
+///
+/// # Examples
+/// ```
+/// use num2english::{NumberToEnglish, Options};
+/// let options = Options {
+///     british_and: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(123.to_english_with(&options), "one hundred and twenty-three");
+/// ```
+///
+/// [`to_english`]: trait.NumberToEnglish.html#tymethod.to_english
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Options {
+    /// Insert "and" after the hundreds in each group, and before a trailing group below one hundred
+    /// (e.g. "one hundred and twenty-three", "one thousand and five").
+    pub british_and: bool,
+    /// The word used before negative numbers (e.g. "negative" or "minus").
+    pub negative_word: &'static str,
+    /// How the decimal part of a number is read.
+    pub decimal_style: DecimalStyle,
+    /// The letter case of the output.
+    pub case: Case,
+    /// The separator between tens and units (e.g. "-" for "twenty-one", " " for "twenty one").
+    pub compound_separator: &'static str,
+    /// The separator between magnitude groups (e.g. ", " for "one million, two hundred thousand").
+    pub group_separator: &'static str,
+    /// The system used to name powers of one thousand.
+    pub scale: Scale,
+    /// The word used for zero, both on its own and for zero digits (e.g. "zero", "oh", "naught" or "nil").
+    pub zero_word: &'static str,
+    /// Read a leading "one" before "hundred", "thousand" and larger names as "a" (e.g. "a hundred").
+    pub informal_article: bool,
+    /// Read numbers from 1100 to 9999 in hundreds (e.g. "twelve hundred" instead of "one thousand two hundred").
+    pub colloquial_hundreds: bool,
+    /// Read a positive integer that is an exact multiple of a gross, a dozen (up to eleven of
+    /// either) or a score (up to four) in that unit (e.g. "a dozen", "two gross", "four score").
+    pub colloquial_units: bool,
+    /// The characters accepted between groups of three digits in text input (e.g. ',' for "1,234,567").
+    pub thousands_separators: &'static [char],
+    /// The largest number of decimal places read, rounding away the rest (e.g. `Some(2)` reads 3.14159 as 3.14).
+    pub max_decimal_places: Option<usize>,
+    /// How digits past the largest number of decimal places are rounded.
+    pub rounding: Rounding,
+}
+
+/// How the decimal part of a number is read.
+///
+/// # Examples
+/// ```
+/// use num2english::{DecimalStyle, NumberToEnglish, Options};
+/// let point = Options {
+///     decimal_style: DecimalStyle::Point,
+///     ..Default::default()
+/// };
+/// assert_eq!(60.212.to_english(), "sixty and two hundred twelve thousandths");
+/// assert_eq!(60.212.to_english_with(&point), "sixty point two one two");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum DecimalStyle {
+    /// Read the decimal part as a fraction (e.g. "sixty and two hundred twelve thousandths").
+    #[default]
+    Fraction,
+    /// Read each digit after a decimal point (e.g. "sixty point two one two").
+    Point,
+    /// Read the decimal part as a mixed number in lowest terms (e.g. "one and a half").
+    Mixed,
+}
+
+#[cfg(any(feature = "wasm", feature = "python"))]
+impl DecimalStyle {
+    /// Find a decimal style by its name in lowercase ("fraction", "point" or "mixed").
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "fraction" => Some(DecimalStyle::Fraction),
+            "point" => Some(DecimalStyle::Point),
+            "mixed" => Some(DecimalStyle::Mixed),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            british_and: false,
+            negative_word: "negative",
+            decimal_style: DecimalStyle::default(),
+            case: Case::default(),
+            compound_separator: "-",
+            group_separator: " ",
+            scale: Scale::default(),
+            zero_word: "zero",
+            informal_article: false,
+            colloquial_hundreds: false,
+            colloquial_units: false,
+            thousands_separators: &[','],
+            max_decimal_places: None,
+            rounding: Rounding::default(),
+        }
+    }
+}
+
+/// How a number is rounded to the largest number of decimal places.
+///
+/// # Examples
+/// ```
+/// use num2english::{NumberToEnglish, Options, Rounding};
+/// let even = Options {
+///     max_decimal_places: Some(1),
+///     rounding: Rounding::HalfEven,
+///     ..Default::default()
+/// };
+/// assert_eq!(0.25.to_english_with(&even), "two tenths");
+/// assert_eq!(0.35.to_english_with(&even), "four tenths");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Rounding {
+    /// Round to the nearest value, and halves away from zero (e.g. 2.25 -> 2.3, -2.25 -> -2.3).
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, and halves to an even last digit (e.g. 2.25 -> 2.2, 2.35 -> 2.4).
+    HalfEven,
+    /// Drop the extra digits (e.g. 2.29 -> 2.2).
+    Truncate,
+}
+
+#[cfg(any(feature = "wasm", feature = "python"))]
+impl Rounding {
+    /// Find a rounding mode by its name in kebab case ("half-up", "half-even" or "truncate").
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "half-up" => Some(Rounding::HalfUp),
+            "half-even" => Some(Rounding::HalfEven),
+            "truncate" => Some(Rounding::Truncate),
+            _ => None,
+        }
+    }
+}
+
+/// The letter case of the output.
+///
+/// # Examples
+/// ```
+/// use num2english::{Case, NumberToEnglish, Options};
+/// let title = Options {
+///     case: Case::Title,
+///     ..Default::default()
+/// };
+/// assert_eq!(123.to_english_with(&title), "One Hundred Twenty-Three");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Case {
+    /// All lowercase (e.g. "one hundred twenty-three").
+    #[default]
+    Lower,
+    /// Every word and each part of a hyphenated compound capitalized, except "and"
+    /// (e.g. "One Hundred and Twenty-Three").
+    Title,
+    /// Only the first letter capitalized (e.g. "One hundred twenty-three").
+    Sentence,
+    /// All uppercase (e.g. "ONE HUNDRED TWENTY-THREE").
+    Upper,
+}
+
+#[cfg(any(feature = "wasm", feature = "python"))]
+impl Case {
+    /// Find a case by its name in lowercase ("lower", "title", "sentence" or "upper").
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "lower" => Some(Case::Lower),
+            "title" => Some(Case::Title),
+            "sentence" => Some(Case::Sentence),
+            "upper" => Some(Case::Upper),
+            _ => None,
+        }
+    }
+}
+
+impl Case {
+    /// Apply the letter case to lowercase English words.
+    pub(crate) fn apply(self, words: &str) -> String {
+        if self == Case::Lower {
+            return words.to_string();
+        }
+        let mut result = String::with_capacity(words.len());
+        let mut writer = CaseWriter::new(&mut result, self);
+        // writing to a string never fails
+        let _ = writer.write_str(words);
+        let _ = writer.finish();
+        result
+    }
+}
+
+/// A sink that applies a letter case to lowercase English words as they are written.
+///
+/// Title case leaves "and" lowercase after the first word, so a word that may still turn out
+/// to be "and" is held back until it ends.
+pub(crate) struct CaseWriter<'a, W: Write> {
+    out: &'a mut W,
+    case: Case,
+    /// Whether any text has been written.
+    started: bool,
+    /// Whether the next letter starts a word or a part of a compound.
+    capitalize: bool,
+    /// Whether the last character written was a space.
+    after_space: bool,
+    /// How many letters of a possible "and" are held back.
+    held: usize,
+}
+
+impl<'a, W: Write> CaseWriter<'a, W> {
+    pub(crate) fn new(out: &'a mut W, case: Case) -> Self {
+        CaseWriter {
+            out,
+            case,
+            started: false,
+            capitalize: true,
+            after_space: false,
+            held: 0,
+        }
+    }
+
+    /// Write out any text still held back.
+    pub(crate) fn finish(mut self) -> fmt::Result {
+        if self.held == 3 {
+            return self.out.write_str("and");
+        }
+        self.release()
+    }
+
+    /// Write the held back letters as the start of an ordinary word.
+    fn release(&mut self) -> fmt::Result {
+        let held = core::mem::take(&mut self.held);
+        "and"[..held].chars().try_for_each(|c| self.write_title(c))
+    }
+
+    /// Write a character in title case, capitalizing each part of a compound (e.g. "Twenty-Three").
+    fn write_title(&mut self, c: char) -> fmt::Result {
+        if self.capitalize && c.is_alphabetic() {
+            c.to_uppercase().try_for_each(|c| self.out.write_char(c))?;
+        } else {
+            self.out.write_char(c)?;
+        }
+        self.capitalize = !c.is_alphabetic();
+        self.after_space = c == ' ';
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for CaseWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.case {
+            Case::Lower => self.out.write_str(s),
+            _ => s.chars().try_for_each(|c| self.write_char(c)),
+        }
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        let first = !self.started;
+        self.started = true;
+        match self.case {
+            Case::Lower => self.out.write_char(c),
+            Case::Upper => c.to_uppercase().try_for_each(|c| self.out.write_char(c)),
+            Case::Sentence if first => c.to_uppercase().try_for_each(|c| self.out.write_char(c)),
+            Case::Sentence => self.out.write_char(c),
+            Case::Title => {
+                if self.held == 3 && c == ' ' {
+                    self.held = 0;
+                    self.out.write_str("and")?;
+                    return self.write_title(c);
+                }
+                if (self.held > 0 || self.after_space) && "and"[self.held..].starts_with(c) {
+                    self.held += 1;
+                    self.after_space = false;
+                    return Ok(());
+                }
+                self.release()?;
+                self.write_title(c)
+            }
+        }
+    }
+}
+
+/// The system used to name large numbers.
+///
+/// # Examples
+/// ```
+/// use num2english::{NumberToEnglish, Options, Scale};
+/// let long = Options {
+///     scale: Scale::Long,
+///     ..Default::default()
+/// };
+/// assert_eq!(1_000_000_000.to_english(), "one billion");
+/// assert_eq!(1_000_000_000.to_english_with(&long), "one thousand million");
+/// assert_eq!(1_000_000_000_000_u64.to_english_with(&long), "one billion");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Scale {
+    /// Each new name is a thousand times the last (e.g. 10^9 is "one billion").
+    #[default]
+    Short,
+    /// Each new name is a million times the last (e.g. 10^9 is "one thousand million", 10^12 is "one billion").
+    Long,
+    /// The long scale with "-illiard" names in between (e.g. 10^9 is "one milliard", 10^12 is "one billion").
+    LongMilliard,
+    /// The Indian numbering system, grouping by hundreds above one thousand
+    /// (e.g. 10^5 is "one lakh", 10^7 is "one crore", 10^14 is "one crore crore").
+    Indian,
+    /// Groups of a custom size with their own names (e.g. myriad grouping by ten thousands).
+    Grouped(&'static Grouping),
+}
+
+#[cfg(any(feature = "wasm", feature = "python"))]
+impl Scale {
+    /// Find a built-in scale by its name in kebab case ("short", "long", "long-milliard" or
+    /// "indian").
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "short" => Some(Scale::Short),
+            "long" => Some(Scale::Long),
+            "long-milliard" => Some(Scale::LongMilliard),
+            "indian" => Some(Scale::Indian),
+            _ => None,
+        }
+    }
+}
+
+/// A custom grouping of digits and the names of each group.
+///
+/// # Examples
+/// ```
+/// use num2english::{Grouping, NumberToEnglish, Options, Scale};
+/// static MYRIAD: Grouping = Grouping {
+///     digits: 4,
+///     names: &["wan", "yi", "zhao"],
+///     decimals: &[],
+/// };
+/// let myriad = Options {
+///     scale: Scale::Grouped(&MYRIAD),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     123_456_789.to_english_with(&myriad),
+///     "one yi two thousand three hundred forty-five wan six thousand seven hundred eighty-nine"
+/// );
+/// ```
+///
+/// Custom names can also replace the names of the decimal places:
+/// ```
+/// use num2english::{Grouping, NumberToEnglish, Options, Scale};
+/// static ARCHAIC: Grouping = Grouping {
+///     digits: 3,
+///     names: &["thousand", "million", "milliard"],
+///     decimals: &["tithe", "centesimal", "millesimal"],
+/// };
+/// let archaic = Options {
+///     scale: Scale::Grouped(&ARCHAIC),
+///     ..Default::default()
+/// };
+/// assert_eq!(2_000_000_000.to_english_with(&archaic), "two milliard");
+/// assert_eq!(0.3.to_english_with(&archaic), "three tithes");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grouping {
+    /// The number of digits in each group, at least one.
+    pub digits: u32,
+    /// The names of each group above the units group, from smallest to largest.
+    ///
+    /// Groups past the end of the list are named by stacking names on the largest
+    /// (e.g. "wan zhao" after "zhao"), or by their power of ten if the list is empty.
+    pub names: &'static [&'static str],
+    /// The names of each decimal place, starting with tenths, or empty to use the built-in names.
+    ///
+    /// Decimal places past the end of the list use the built-in names.
+    pub decimals: &'static [&'static str],
+}
+
+impl Scale {
+    /// The number of digits in the group at the given magnitude, counting from the units group.
+    pub(crate) fn group_digits(self, magnitude: usize) -> u32 {
+        match self {
+            Scale::Short | Scale::LongMilliard => 3,
+            Scale::Long => 6,
+            Scale::Grouped(grouping) => grouping.digits.max(1),
+            Scale::Indian => match magnitude {
+                0 => 3,
+                1 | 2 => 2,
+                _ => 7,
+            },
+        }
+    }
+
+    /// The scale used to read a group wider than three digits.
+    pub(crate) fn group_scale(self) -> Scale {
+        match self {
+            Scale::Indian => Scale::Indian,
+            _ => Scale::Short,
+        }
+    }
+
+    /// The name of the group at the given magnitude (e.g. 1 -> "thousand").
+    pub(crate) fn magnitude_name(self, magnitude: usize) -> Cow<'static, str> {
+        match self {
+            Scale::Short => illion_name(magnitude - 1),
+            Scale::Long => illion_name(magnitude),
+            Scale::LongMilliard => {
+                let name = illion_name(magnitude / 2);
+                if magnitude.is_multiple_of(2) || magnitude == 1 {
+                    name
+                } else {
+                    Cow::Owned(name.replace("illion", "illiard"))
+                }
+            }
+            Scale::Grouped(grouping) => match grouping.names.get(magnitude - 1) {
+                Some(name) => Cow::Borrowed(name),
+                // without any names, the group is named by its power of ten (e.g. "ten thousand")
+                None if grouping.names.is_empty() => {
+                    let mut power = String::from("1");
+                    power.extend(repeat_n('0', magnitude * self.group_digits(1) as usize));
+                    let name = convert_integer_to_english(&power, &Options::default());
+                    Cow::Owned(String::from(name.trim_start_matches("one ")))
+                }
+                // names past the end of the list are stacked on the largest name (e.g. "wan zhao")
+                None => {
+                    let largest = grouping.names.len();
+                    let mut name = self.magnitude_name(magnitude - largest).into_owned();
+                    name.push(' ');
+                    name.push_str(grouping.names[largest - 1]);
+                    Cow::Owned(name)
+                }
+            },
+            Scale::Indian => match magnitude {
+                1 => Cow::Borrowed("thousand"),
+                2 => Cow::Borrowed("lakh"),
+                3 => Cow::Borrowed("crore"),
+                _ => {
+                    let mut name = String::from("crore");
+                    for _ in 3..magnitude {
+                        name.push_str(" crore");
+                    }
+                    Cow::Owned(name)
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,817 @@
+use crate::{
+    scales::{DECIMALS, MAGNITUDES, ONE_TO_NINETEEN, TENS},
+    split_number, Currency, Error, SplitNumber,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use num_bigint::BigInt;
+
+/// Parse the English name of an integer, as written by [`to_english`].
+///
+/// Letter case, a British "and", an informal "a hundred" and colloquial hundreds
+/// ("twelve hundred") are also accepted, as are spoken forms such as "a dozen", "a couple",
+/// "half a million" and "two and a half thousand".
+///
+/// # Errors
+/// Returns [`Error::InvalidWords`] if the text is not the name of an integer.
+///
+/// # Examples
+/// ```
+/// use num2english::{parse_english, Error};
+/// assert_eq!(parse_english("one thousand two hundred thirty-four"), Ok(1_234.into()));
+/// assert_eq!(parse_english("Negative Twelve Hundred"), Ok((-1_200).into()));
+/// assert_eq!(parse_english("two and a half thousand"), Ok(2_500.into()));
+/// assert_eq!(parse_english("five tenths"), Err(Error::InvalidWords));
+/// ```
+///
+/// [`to_english`]: trait.NumberToEnglish.html#tymethod.to_english
+/// [`Error::InvalidWords`]: enum.Error.html#variant.InvalidWords
+pub fn parse_english(words: &str) -> Result<BigInt, Error> {
+    let number = parse_english_decimal(words)?;
+    if number.contains('.') {
+        return Err(Error::InvalidWords);
+    }
+    BigInt::parse_bytes(number.as_bytes(), 10).ok_or(Error::InvalidWords)
+}
+
+/// Parse the English name of a number with a decimal part into its integer and decimal parts.
+///
+/// The decimal part may be read as a fraction ("sixty and two hundred twelve thousandths") or
+/// digit by digit ("sixty point two one two"). The sign is carried by the integer part, or by
+/// the decimal part if the integer part is zero.
+///
+/// A British "and" is accepted, but a single "and" before a fraction always splits off the
+/// decimal part, so "five hundred and five thousandths" is 500.005, not 0.505.
+///
+/// # Errors
+/// Returns [`Error::InvalidWords`] if the text is not the name of a number, or if it has more
+/// than one "and" and could be split into its integer and decimal parts in more than one way.
+///
+/// # Examples
+/// ```
+/// use num2english::{parse_english_split, SplitNumber};
+/// assert_eq!(
+///     parse_english_split("sixty and two hundred twelve thousandths"),
+///     Ok(SplitNumber {
+///         integer: Some(60.into()),
+///         decimal: Some(212.into()),
+///         decimal_places: 3,
+///     })
+/// );
+/// assert_eq!(
+///     parse_english_split("negative zero point zero five"),
+///     Ok(SplitNumber {
+///         integer: None,
+///         decimal: Some((-5).into()),
+///         decimal_places: 2,
+///     })
+/// );
+/// ```
+///
+/// [`Error::InvalidWords`]: enum.Error.html#variant.InvalidWords
+pub fn parse_english_split(words: &str) -> Result<SplitNumber, Error> {
+    let number = parse_english_decimal(words)?;
+    let (negative, unsigned) = match number.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, number.as_str()),
+    };
+    let mut split = split_number(unsigned);
+    if negative {
+        if let Some(integer) = split.integer.as_mut() {
+            *integer = -integer.clone();
+        } else if let Some(decimal) = split.decimal.as_mut() {
+            *decimal = -decimal.clone();
+        }
+    }
+    Ok(split)
+}
+
+/// An integer read from its English name, as returned by [`parse_english_ordinal`].
+///
+/// # Examples
+/// ```
+/// use num2english::ParsedInteger;
+/// let parsed = ParsedInteger {
+///     value: 21.into(),
+///     ordinal: true,
+/// };
+/// assert_eq!(parsed.value, 21.into());
+/// assert!(parsed.ordinal);
+/// ```
+///
+/// [`parse_english_ordinal`]: fn.parse_english_ordinal.html
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParsedInteger {
+    /// The value of the integer.
+    pub value: BigInt,
+    /// Whether the integer was named as an ordinal (e.g. "twenty-first").
+    pub ordinal: bool,
+}
+
+/// Parse the English name of an integer written either as a cardinal ("twenty-one") or as an
+/// ordinal ("twenty-first"), as written by [`to_english_ordinal`].
+///
+/// A bare ordinal magnitude ("hundredth", "thousandth") is read as one of it. The words are
+/// otherwise read as they are by [`parse_english`].
+///
+/// # Errors
+/// Returns [`Error::InvalidWords`] if the text is not the name of an integer.
+///
+/// # Examples
+/// ```
+/// use num2english::{parse_english_ordinal, ParsedInteger};
+/// assert_eq!(
+///     parse_english_ordinal("twenty-first"),
+///     Ok(ParsedInteger {
+///         value: 21.into(),
+///         ordinal: true,
+///     })
+/// );
+/// assert_eq!(parse_english_ordinal("hundredth").map(|n| n.value), Ok(100.into()));
+/// assert_eq!(parse_english_ordinal("twelve").map(|n| n.ordinal), Ok(false));
+/// ```
+///
+/// [`to_english_ordinal`]: trait.NumberToEnglish.html#tymethod.to_english_ordinal
+/// [`parse_english`]: fn.parse_english.html
+/// [`Error::InvalidWords`]: enum.Error.html#variant.InvalidWords
+pub fn parse_english_ordinal(words: &str) -> Result<ParsedInteger, Error> {
+    let lowercase = words.to_lowercase();
+    let mut words = lowercase.split_whitespace().collect::<Vec<&str>>();
+    let Some(last) = words.pop() else {
+        return Err(Error::InvalidWords);
+    };
+
+    // only the last word, or the last part of a compound, names the ordinal
+    let (head, tail) = match last.rsplit_once('-') {
+        Some((head, tail)) => (&last[..head.len() + 1], tail),
+        None => ("", last),
+    };
+    let Some(cardinal) = cardinal_word(tail) else {
+        return Ok(ParsedInteger {
+            value: parse_english(&lowercase)?,
+            ordinal: false,
+        });
+    };
+
+    let sign = match words.first() {
+        Some(&("negative" | "minus")) => 1,
+        _ => 0,
+    };
+    let mut cardinal_words = String::new();
+    if words.len() == sign && (cardinal == "hundred" || magnitude_power(&cardinal).is_some()) {
+        words.push("a");
+    }
+    for word in words {
+        cardinal_words.push_str(word);
+        cardinal_words.push(' ');
+    }
+    cardinal_words.push_str(head);
+    cardinal_words.push_str(&cardinal);
+    Ok(ParsedInteger {
+        value: parse_english(&cardinal_words)?,
+        ordinal: true,
+    })
+}
+
+/// Get the cardinal word for an ordinal word (e.g. "twelfth" -> "twelve", "fortieth" -> "forty").
+fn cardinal_word(word: &str) -> Option<String> {
+    let irregular = [
+        ("first", "one"),
+        ("second", "two"),
+        ("third", "three"),
+        ("fifth", "five"),
+        ("eighth", "eight"),
+        ("ninth", "nine"),
+        ("twelfth", "twelve"),
+    ];
+    if let Some((_, cardinal)) = irregular.iter().find(|(ordinal, _)| *ordinal == word) {
+        return Some(String::from(*cardinal));
+    }
+    if let Some(stem) = word.strip_suffix("ieth") {
+        let mut cardinal = String::from(stem);
+        cardinal.push('y');
+        return Some(cardinal);
+    }
+    word.strip_suffix("th")
+        .filter(|stem| !stem.is_empty())
+        .map(String::from)
+}
+
+/// An amount of money read from its English name, as returned by [`parse_english_currency`].
+///
+/// # Examples
+/// ```
+/// use num2english::{Currency, ParsedAmount};
+/// let amount = ParsedAmount {
+///     currency: Currency::USD,
+///     minor_units: 10_005.into(),
+/// };
+/// assert_eq!(amount.minor_units, 10_005.into());
+/// ```
+///
+/// [`parse_english_currency`]: fn.parse_english_currency.html
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParsedAmount {
+    /// The currency named by the units.
+    pub currency: Currency,
+    /// The amount counted in the currency's minor unit (e.g. cents), negative for a negative amount.
+    pub minor_units: BigInt,
+}
+
+/// Parse an amount of money written in English, as written by [`to_english_currency`] or
+/// [`to_english_check`], detecting its currency among the given [`Currency`] units.
+///
+/// The minor amount may follow the major unit without its own unit ("twelve pounds fifty").
+/// If only a minor unit is named, the first currency with that unit is chosen.
+///
+/// # Errors
+/// Returns [`Error::InvalidWords`] if the text is not an amount in one of the currencies, or if
+/// the minor amount is not less than one major unit.
+///
+/// # Examples
+/// ```
+/// use num2english::{parse_english_currency, Currency};
+/// let currencies = [Currency::USD, Currency::GBP];
+/// let amount = parse_english_currency("one hundred dollars and five cents", &currencies).unwrap();
+/// assert_eq!(amount.currency, Currency::USD);
+/// assert_eq!(amount.minor_units, 10_005.into());
+///
+/// let amount = parse_english_currency("twelve pounds fifty", &currencies).unwrap();
+/// assert_eq!(amount.currency, Currency::GBP);
+/// assert_eq!(amount.minor_units, 1_250.into());
+/// ```
+///
+/// [`to_english_currency`]: trait.NumberToEnglish.html#tymethod.to_english_currency
+/// [`to_english_check`]: trait.NumberToEnglish.html#tymethod.to_english_check
+/// [`Currency`]: struct.Currency.html
+/// [`Error::InvalidWords`]: enum.Error.html#variant.InvalidWords
+pub fn parse_english_currency(words: &str, currencies: &[Currency]) -> Result<ParsedAmount, Error> {
+    let lowercase = words.to_lowercase();
+    let mut words = lowercase.split_whitespace().collect::<Vec<&str>>();
+    let negative = matches!(words.first(), Some(&("negative" | "minus")));
+    if negative {
+        words.remove(0);
+    }
+
+    let major_unit = words.iter().enumerate().find_map(|(i, word)| {
+        currencies
+            .iter()
+            .find(|c| *word == c.major_singular || *word == c.major_plural)
+            .map(|currency| (i, currency))
+    });
+    let (currency, major, mut minor_words) = match major_unit {
+        // the amount line of a check (e.g. "twelve and 50/100 dollars")
+        Some((i, currency)) if i + 1 == words.len() && i >= 2 && words[i - 2] == "and" => {
+            let (numerator, denominator) =
+                words[i - 1].split_once('/').ok_or(Error::InvalidWords)?;
+            if denominator != BigInt::from(10).pow(currency.minor_digits).to_string() {
+                return Err(Error::InvalidWords);
+            }
+            let minor = BigInt::parse_bytes(numerator.as_bytes(), 10).ok_or(Error::InvalidWords)?;
+            let major = parse_integer(&words[..i - 2])?;
+            let unit = BigInt::from(10).pow(currency.minor_digits);
+            return signed_amount(currency, major * unit, minor, negative);
+        }
+        Some((i, currency)) => (currency, parse_count(&words[..i])?, &words[i + 1..]),
+        None => {
+            let currency = words
+                .last()
+                .and_then(|word| {
+                    currencies
+                        .iter()
+                        .find(|c| *word == c.minor_singular || *word == c.minor_plural)
+                })
+                .ok_or(Error::InvalidWords)?;
+            (currency, BigInt::from(0), &words[..])
+        }
+    };
+
+    if let Some((&"and", rest)) = minor_words.split_first() {
+        minor_words = rest;
+    }
+    if let Some((&last, rest)) = minor_words.split_last() {
+        if last == currency.minor_singular || last == currency.minor_plural {
+            if rest.is_empty() {
+                return Err(Error::InvalidWords);
+            }
+            minor_words = rest;
+        }
+    }
+    let minor = if minor_words.is_empty() {
+        BigInt::from(0)
+    } else {
+        parse_count(minor_words)?
+    };
+    let unit = BigInt::from(10).pow(currency.minor_digits);
+    signed_amount(currency, major * unit, minor, negative)
+}
+
+/// Read the count of a unit, where "a" alone counts one (e.g. "a dollar").
+fn parse_count(words: &[&str]) -> Result<BigInt, Error> {
+    if words == ["a"] {
+        return Ok(BigInt::from(1));
+    }
+    parse_integer(words)
+}
+
+/// Add a minor amount to a major amount counted in minor units, checking that it is less than one
+/// major unit.
+fn signed_amount(
+    currency: &Currency,
+    major: BigInt,
+    minor: BigInt,
+    negative: bool,
+) -> Result<ParsedAmount, Error> {
+    if minor >= BigInt::from(10).pow(currency.minor_digits) {
+        return Err(Error::InvalidWords);
+    }
+    let total = major + minor;
+    Ok(ParsedAmount {
+        currency: currency.clone(),
+        minor_units: if negative { -total } else { total },
+    })
+}
+
+/// Read the English name of a number back into a decimal number string
+/// (e.g. "negative sixty and two hundred twelve thousandths" -> "-60.212").
+///
+/// Names are read as the default [`Options`] write them, with the decimal part read as a
+/// fraction ("five tenths") or digit by digit ("point five"). Letter case, a British "and",
+/// an informal "a hundred", colloquial hundreds ("twelve hundred") and spoken forms such as
+/// "half a million" are also accepted.
+///
+/// A single "and" before a fraction always splits off the decimal part, so "five hundred and
+/// five thousandths" is 500.005. With more than one "and", the split must be the only one that
+/// reads as a number, and text that could be split more than one way is rejected.
+///
+/// [`Options`]: struct.Options.html
+pub(crate) fn parse_english_decimal(words: &str) -> Result<String, Error> {
+    let lowercase = words.to_lowercase();
+    let mut words = lowercase.split_whitespace().collect::<Vec<&str>>();
+
+    let negative = matches!(words.first(), Some(&("negative" | "minus")));
+    if negative {
+        words.remove(0);
+    }
+    if words.is_empty() {
+        return Err(Error::InvalidWords);
+    }
+
+    let (integer, decimal) = if let Some(point) = words.iter().position(|w| *w == "point") {
+        let digits = words[point + 1..]
+            .iter()
+            .map(|word| parse_digit(word))
+            .collect::<Option<String>>()
+            .filter(|digits| !digits.is_empty())
+            .ok_or(Error::InvalidWords)?;
+        (parse_integer(&words[..point])?, Some(digits))
+    } else if let Some(places) = words.last().and_then(|word| decimal_places(word)) {
+        let words = &words[..words.len() - 1];
+        let ands = words
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| **w == "and")
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+        match ands[..] {
+            // the decimal part is the whole number
+            [] => (BigInt::from(0), Some(parse_fraction(words, places)?)),
+            // a single "and" splits off the decimal part
+            [split] => (
+                parse_integer(&words[..split])?,
+                Some(parse_fraction(&words[split + 1..], places)?),
+            ),
+            // any "and" may be British, so the split must be the only one that reads
+            _ => {
+                let whole = parse_fraction(words, places)
+                    .ok()
+                    .map(|digits| (BigInt::from(0), digits));
+                let splits = ands.iter().filter_map(|&split| {
+                    let integer = parse_integer(&words[..split]).ok()?;
+                    Some((integer, parse_fraction(&words[split + 1..], places).ok()?))
+                });
+                let mut readings = whole.into_iter().chain(splits);
+                match (readings.next(), readings.next()) {
+                    (Some((integer, digits)), None) => (integer, Some(digits)),
+                    _ => return Err(Error::InvalidWords),
+                }
+            }
+        }
+    } else {
+        (parse_integer(&words)?, None)
+    };
+
+    let mut result = String::new();
+    if negative {
+        result.push('-');
+    }
+    result.push_str(&integer.to_string());
+    if let Some(decimal) = decimal {
+        result.push('.');
+        result.push_str(&decimal);
+    }
+    Ok(result)
+}
+
+/// Read the numerator of a decimal fraction as its digits, padded to the number of decimal places
+/// (e.g. ["five"], 3 -> "005").
+fn parse_fraction(words: &[&str], places: usize) -> Result<String, Error> {
+    if words.is_empty() {
+        return Err(Error::InvalidWords);
+    }
+    let numerator = parse_integer(words)?.to_string();
+    if numerator.len() > places {
+        return Err(Error::InvalidWords);
+    }
+    let mut digits = "0".repeat(places - numerator.len());
+    digits.push_str(&numerator);
+    Ok(digits)
+}
+
+/// Read the words of an integer (e.g. ["one", "thousand", "twenty-three"] -> 1023).
+fn parse_integer(words: &[&str]) -> Result<BigInt, Error> {
+    if words == ["zero"] {
+        return Ok(BigInt::from(0));
+    }
+    if words.is_empty() {
+        return Err(Error::InvalidWords);
+    }
+
+    let mut total = BigInt::from(0);
+    let mut group = 0_u64;
+    // whether half of the next hundred or magnitude is added to the group (e.g. "half a million")
+    let mut half = false;
+    // the power of the last magnitude read, which must fall from one group to the next
+    let mut last_power = None;
+    // whether the group has been counted in a colloquial unit (e.g. "three dozen")
+    let mut unit = false;
+    let mut i = 0;
+    while i < words.len() {
+        let word = words[i];
+        let next = words.get(i + 1).copied();
+        i += 1;
+        if word == "and" && words.get(i..i + 2) == Some(&["a", "half"]) {
+            // "two and a half thousand"
+            if group == 0 || half {
+                return Err(Error::InvalidWords);
+            }
+            half = true;
+            i += 2;
+        } else if word == "and" && i > 1 && i < words.len() {
+            continue;
+        } else if word == "a" && i == 1 {
+            // "a" counts one of what follows it (e.g. "a hundred", "a dozen", "a half million")
+            match next {
+                Some("half" | "couple") => {}
+                Some(next)
+                    if next == "hundred"
+                        || magnitude_power(next).is_some()
+                        || colloquial_unit_size(next).is_some() =>
+                {
+                    group = 1;
+                }
+                _ => return Err(Error::InvalidWords),
+            }
+        } else if word == "half" {
+            if group != 0 || half {
+                return Err(Error::InvalidWords);
+            }
+            half = true;
+            if next == Some("a") {
+                i += 1;
+            }
+        } else if word == "couple" {
+            if group != 0 {
+                return Err(Error::InvalidWords);
+            }
+            group = 2;
+            if next == Some("of") {
+                i += 1;
+            }
+        } else if let Some(size) = colloquial_unit_size(word) {
+            // "three dozen", "half a gross", but not a unit of units ("a dozen dozen")
+            if (group == 0 && !half) || unit {
+                return Err(Error::InvalidWords);
+            }
+            group = group
+                .checked_mul(size)
+                .and_then(|group| group.checked_add(if half { size / 2 } else { 0 }))
+                .ok_or(Error::InvalidWords)?;
+            half = false;
+            unit = true;
+        } else if word == "hundred" {
+            // a group above nine is read in hundreds (e.g. "twelve hundred")
+            if (group == 0 && !half) || group >= 100 {
+                return Err(Error::InvalidWords);
+            }
+            group *= 100;
+            if half {
+                group += 50;
+                half = false;
+            }
+        } else if let Some(power) = magnitude_power(word) {
+            if (group == 0 && !half) || last_power.is_some_and(|last| power >= last) {
+                return Err(Error::InvalidWords);
+            }
+            let halves = BigInt::from(group) * 2 + u8::from(half);
+            total += halves * BigInt::from(10).pow(power) / 2;
+            group = 0;
+            half = false;
+            unit = false;
+            last_power = Some(power);
+        } else {
+            let value = parse_below_hundred(word).ok_or(Error::InvalidWords)?;
+            if !group.is_multiple_of(100) || half {
+                return Err(Error::InvalidWords);
+            }
+            group += value;
+        }
+    }
+    if half {
+        return Err(Error::InvalidWords);
+    }
+    Ok(total + group)
+}
+
+/// Read a number from one to ninety-nine written as a single word (e.g. "forty-two" -> 42).
+fn parse_below_hundred(word: &str) -> Option<u64> {
+    if let Some((tens, unit)) = word.split_once('-') {
+        let tens = TENS.iter().position(|w| *w == tens).filter(|&t| t > 0)?;
+        let unit = ONE_TO_NINETEEN[..9].iter().position(|w| *w == unit)?;
+        return Some((tens as u64 + 1) * 10 + unit as u64 + 1);
+    }
+    if let Some(n) = ONE_TO_NINETEEN.iter().position(|w| *w == word) {
+        return Some(n as u64 + 1);
+    }
+    TENS.iter()
+        .position(|w| *w == word)
+        .map(|t| (t as u64 + 1) * 10)
+}
+
+/// Get the size of a colloquial unit (e.g. "dozen" -> 12, "gross" -> 144).
+fn colloquial_unit_size(word: &str) -> Option<u64> {
+    match word {
+        "dozen" => Some(12),
+        "score" => Some(20),
+        "gross" => Some(144),
+        _ => None,
+    }
+}
+
+/// Get the power of ten named by a magnitude (e.g. "thousand" -> 3, "million" -> 6).
+fn magnitude_power(word: &str) -> Option<u32> {
+    if word != "thousand" && !word.ends_with("illion") {
+        return None;
+    }
+    let index = MAGNITUDES.iter().position(|w| *w == word)?;
+    Some(3 * (index as u32 + 1))
+}
+
+/// Get the number of decimal places named by a decimal suffix (e.g. "hundredths" -> 2).
+fn decimal_places(word: &str) -> Option<usize> {
+    let singular = word.strip_suffix('s').unwrap_or(word);
+    DECIMALS
+        .iter()
+        .position(|w| *w == singular)
+        .map(|places| places + 1)
+}
+
+/// Read the name of a single digit (e.g. "seven" -> '7').
+fn parse_digit(word: &str) -> Option<char> {
+    if word == "zero" {
+        return Some('0');
+    }
+    let digit = ONE_TO_NINETEEN[..9].iter().position(|w| *w == word)?;
+    char::from_digit(digit as u32 + 1, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_english_decimal as parse_english;
+    use crate::{
+        parse_english as parse_integer, parse_english_currency, parse_english_ordinal,
+        parse_english_split, Currency, DecimalStyle, Error, NumberToEnglish, Options,
+        ParsedInteger, SplitNumber,
+    };
+    use alloc::string::{String, ToString};
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_parse_english() {
+        assert_eq!(parse_english("zero"), Ok("0".into()));
+        assert_eq!(parse_english("Forty-Two"), Ok("42".into()));
+        assert_eq!(
+            parse_english("negative sixty and two hundred twelve thousandths"),
+            Ok("-60.212".into())
+        );
+        assert_eq!(parse_english("one and fifty hundredths"), Ok("1.50".into()));
+        assert_eq!(parse_english("five tenths"), Ok("0.5".into()));
+        assert_eq!(parse_english("zero point zero five"), Ok("0.05".into()));
+        assert_eq!(
+            parse_english("one hundred and five thousand and six"),
+            Ok("105006".into())
+        );
+        assert_eq!(parse_english("a thousand"), Ok("1000".into()));
+        assert_eq!(
+            parse_english("twelve hundred thirty-four"),
+            Ok("1234".into())
+        );
+
+        for (words, expected) in [
+            ("a dozen", "12"),
+            ("half a dozen", "6"),
+            ("three dozen", "36"),
+            ("four score", "80"),
+            ("two gross", "288"),
+            ("three dozen thousand two dozen", "36024"),
+            ("a couple", "2"),
+            ("a couple of hundred", "200"),
+            ("half a million", "500000"),
+            ("a half billion", "500000000"),
+            ("two and a half thousand", "2500"),
+            ("one and a half hundred", "150"),
+            ("one million and two and a half thousand", "1002500"),
+        ] {
+            assert_eq!(parse_english(words), Ok(expected.into()), "{}", words);
+        }
+
+        for n in [1, 19, 100, 1_005, 987_654_321, i64::MIN as i128, i128::MAX] {
+            assert_eq!(parse_english(&n.to_english()), Ok(n.to_string()));
+        }
+        let point = Options {
+            decimal_style: DecimalStyle::Point,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_english(&(-3.25).to_english_with(&point)),
+            Ok("-3.25".into())
+        );
+
+        // a British "and" may also be written inside the decimal part
+        let british = Options {
+            british_and: true,
+            ..Default::default()
+        };
+        for (n, expected) in [
+            (105.25, Ok("105.25")),
+            (-1_100.125, Ok("-1100.125")),
+            (105.25e3, Ok("105250")),
+            (0.505, Ok("500.005")),
+            (0.1234567890123, Err(Error::InvalidWords)),
+        ] {
+            let words = n.to_english_with(&british);
+            assert_eq!(
+                parse_english(&words),
+                expected.map(String::from),
+                "{}",
+                words
+            );
+        }
+        assert_eq!(
+            parse_english(&105.25.to_english_with(&Options {
+                decimal_style: DecimalStyle::Point,
+                ..british
+            })),
+            Ok("105.25".into())
+        );
+
+        for words in [
+            "",
+            "negative",
+            "one hundred hundred",
+            "thousand",
+            "one million one billion",
+            "two three",
+            "twenty-ten",
+            "point",
+            "and five tenths",
+            "one hundred tenths",
+            "eleventy",
+            "half",
+            "two and a half",
+            "a couple dozen half",
+            "half a half a million",
+            "a dozen dozen",
+            "a",
+            "negative a",
+            "a five",
+            "half a dozen score",
+            "a gross gross gross gross gross gross gross gross gross gross",
+        ] {
+            assert_eq!(parse_english(words), Err(Error::InvalidWords), "{}", words);
+        }
+    }
+
+    #[test]
+    fn test_parse_english_public() {
+        let huge: BigInt = BigInt::from(10).pow(300) * 7 + 1;
+        assert_eq!(parse_integer(&huge.to_english()), Ok(huge));
+        assert_eq!(parse_integer("minus six"), Ok(BigInt::from(-6)));
+        assert_eq!(parse_integer("zero point five"), Err(Error::InvalidWords));
+        assert!(parse_integer("a").is_err());
+        assert_eq!(parse_integer("a hundred"), Ok(BigInt::from(100)));
+
+        assert_eq!(
+            parse_english_split("negative one and one tenth"),
+            Ok(SplitNumber {
+                integer: Some(BigInt::from(-1)),
+                decimal: Some(BigInt::from(1)),
+                decimal_places: 1,
+            })
+        );
+        assert_eq!(
+            parse_english_split("seven"),
+            Ok(SplitNumber {
+                integer: Some(BigInt::from(7)),
+                decimal: None,
+                decimal_places: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_english_ordinal() {
+        let ordinal = |value: i64| {
+            Ok(ParsedInteger {
+                value: BigInt::from(value),
+                ordinal: true,
+            })
+        };
+        assert_eq!(parse_english_ordinal("First"), ordinal(1));
+        assert_eq!(parse_english_ordinal("zeroth"), ordinal(0));
+        assert_eq!(parse_english_ordinal("ninety-ninth"), ordinal(99));
+        assert_eq!(parse_english_ordinal("thousandth"), ordinal(1_000));
+        assert_eq!(parse_english_ordinal("negative hundredth"), ordinal(-100));
+        assert_eq!(
+            parse_english_ordinal("one hundred and twelfth"),
+            ordinal(112)
+        );
+        assert_eq!(
+            parse_english_ordinal("forty-two"),
+            Ok(ParsedInteger {
+                value: BigInt::from(42),
+                ordinal: false,
+            })
+        );
+
+        for n in [2, 8, 15, 40, 1_000_000, 123_456_789] {
+            assert_eq!(
+                parse_english_ordinal(&n.to_english_ordinal().unwrap()),
+                ordinal(n)
+            );
+        }
+
+        for words in ["", "th", "one tenth", "first second", "five tenths"] {
+            assert_eq!(
+                parse_english_ordinal(words),
+                Err(Error::InvalidWords),
+                "{}",
+                words
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_english_currency() {
+        let currencies = [Currency::USD, Currency::EUR, Currency::GBP];
+        let parse = |words: &str| {
+            parse_english_currency(words, &currencies)
+                .map(|amount| (amount.currency, amount.minor_units))
+        };
+        assert_eq!(parse("a dollar"), Ok((Currency::USD, BigInt::from(100))));
+        assert_eq!(parse("Five Cents"), Ok((Currency::USD, BigInt::from(5))));
+        assert_eq!(
+            parse("two euros ten"),
+            Ok((Currency::EUR, BigInt::from(210)))
+        );
+        assert_eq!(
+            parse("one pound and one penny"),
+            Ok((Currency::GBP, BigInt::from(101)))
+        );
+
+        for n in [0.0, 0.5, 1.0, -1.99, 1_234.56] {
+            let expected = BigInt::from((n * 100.0_f64).round() as i64);
+            assert_eq!(
+                parse(&n.to_english_currency(&Currency::GBP)),
+                Ok((Currency::GBP, expected.clone()))
+            );
+            assert_eq!(
+                parse(&n.to_english_check(&Currency::EUR)),
+                Ok((Currency::EUR, expected))
+            );
+        }
+
+        for words in [
+            "",
+            "dollars",
+            "one hundred cents",
+            "five yen",
+            "one dollar two hundred",
+            "twelve and 50/1000 dollars",
+        ] {
+            assert_eq!(parse(words), Err(Error::InvalidWords), "{}", words);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{digit_to_english, Options};
+use alloc::{string::String, vec::Vec};
+
+/// How a phone number is read.
+///
+/// # Examples
+/// ```
+/// use num2english::{phone_number_to_english, PhoneStyle};
+/// let style = PhoneStyle {
+///     groups: &[3, 3, 4],
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     phone_number_to_english("2125550123", &style),
+///     "two one two, five five five, oh one two three"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PhoneStyle {
+    /// The number of digits in each group, or empty to keep the groups written in the number.
+    /// Any digits left over after the last group are read as one more group, and sizes of zero
+    /// are skipped.
+    pub groups: &'static [usize],
+    /// The pause read between groups (e.g. ", ").
+    pub pause: &'static str,
+    /// The word used for zero digits (e.g. "oh" or "zero").
+    pub zero_word: &'static str,
+}
+
+impl Default for PhoneStyle {
+    fn default() -> Self {
+        PhoneStyle {
+            groups: &[],
+            pause: ", ",
+            zero_word: "oh",
+        }
+    }
+}
+
+/// Read a phone number digit by digit, pausing between groups of digits.
+///
+/// A leading "+" is read as "plus". Without explicit group sizes, the groups are taken from
+/// the spaces, dashes, dots and parentheses written in the number.
+///
+/// # Examples
+/// ```
+/// use num2english::{phone_number_to_english, PhoneStyle};
+/// assert_eq!(
+///     phone_number_to_english("555-0123", &PhoneStyle::default()),
+///     "five five five, oh one two three"
+/// );
+/// assert_eq!(
+///     phone_number_to_english("+44 (20) 7946 0000", &PhoneStyle::default()),
+///     "plus four four, two oh, seven nine four six, oh oh oh oh"
+/// );
+/// ```
+pub fn phone_number_to_english(number: &str, style: &PhoneStyle) -> String {
+    let options = Options {
+        zero_word: style.zero_word,
+        ..Default::default()
+    };
+
+    let plus = number.trim_start().starts_with('+');
+    let written_groups = number
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|group| !group.is_empty())
+        .collect::<Vec<&str>>();
+
+    let digits = written_groups.concat();
+    let groups = if style.groups.is_empty() {
+        written_groups
+    } else {
+        let mut groups = Vec::new();
+        let mut rest = digits.as_str();
+        for &size in style.groups.iter().filter(|&&size| size > 0) {
+            if rest.is_empty() {
+                break;
+            }
+            let (group, tail) = rest.split_at(size.min(rest.len()));
+            groups.push(group);
+            rest = tail;
+        }
+        if !rest.is_empty() {
+            groups.push(rest);
+        }
+        groups
+    };
+
+    read_groups(plus, &groups, style, &options)
+}
+
+/// Read groups of digits with a pause between each group (e.g. ["555", "0123"] -> "five five five, oh one two three")
+fn read_groups(plus: bool, groups: &[&str], style: &PhoneStyle, options: &Options) -> String {
+    let mut result = String::new();
+    if plus {
+        result.push_str("plus ");
+    }
+
+    for (i, group) in groups.iter().enumerate() {
+        if i > 0 {
+            result.push_str(style.pause);
+        }
+        for (j, digit) in group.bytes().enumerate() {
+            if j > 0 {
+                result.push(' ');
+            }
+            result.push_str(digit_to_english(digit - b'0', options));
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{phone_number_to_english, PhoneStyle};
+
+    #[test]
+    fn test_phone_number() {
+        let style = PhoneStyle::default();
+        assert_eq!(
+            phone_number_to_english("555-0123", &style),
+            "five five five, oh one two three"
+        );
+        assert_eq!(
+            phone_number_to_english("(212) 555.0100", &style),
+            "two one two, five five five, oh one oh oh"
+        );
+        let grouped = PhoneStyle {
+            groups: &[3, 3],
+            pause: " ... ",
+            zero_word: "zero",
+        };
+        assert_eq!(
+            phone_number_to_english("212-555-0100", &grouped),
+            "two one two ... five five five ... zero one zero zero"
+        );
+        assert_eq!(phone_number_to_english("12", &grouped), "one two");
+        assert_eq!(phone_number_to_english("", &style), "");
+
+        let empty_groups = PhoneStyle {
+            groups: &[0, 3, 0],
+            ..Default::default()
+        };
+        assert_eq!(
+            phone_number_to_english("555-0123", &empty_groups),
+            "five five five, oh one two three"
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{Gender, Language, PluralCategory};
+use alloc::{borrow::Cow, format, string::String};
+use core::fmt::{self, Write};
+
+/// The masculine names of the numbers from one to nineteen.
+const UNITS: [&str; 19] = [
+    "jeden",
+    "dwa",
+    "trzy",
+    "cztery",
+    "pięć",
+    "sześć",
+    "siedem",
+    "osiem",
+    "dziewięć",
+    "dziesięć",
+    "jedenaście",
+    "dwanaście",
+    "trzynaście",
+    "czternaście",
+    "piętnaście",
+    "szesnaście",
+    "siedemnaście",
+    "osiemnaście",
+    "dziewiętnaście",
+];
+
+/// The names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "dziesięć",
+    "dwadzieścia",
+    "trzydzieści",
+    "czterdzieści",
+    "pięćdziesiąt",
+    "sześćdziesiąt",
+    "siedemdziesiąt",
+    "osiemdziesiąt",
+    "dziewięćdziesiąt",
+];
+
+/// The names of the hundreds from one hundred to nine hundred.
+const HUNDREDS: [&str; 9] = [
+    "sto",
+    "dwieście",
+    "trzysta",
+    "czterysta",
+    "pięćset",
+    "sześćset",
+    "siedemset",
+    "osiemset",
+    "dziewięćset",
+];
+
+/// The names of each power of one thousand above "tysiąc", in the long scale with "-iard" names.
+const MAGNITUDES: [&str; 11] = [
+    "milion",
+    "miliard",
+    "bilion",
+    "biliard",
+    "trylion",
+    "tryliard",
+    "kwadrylion",
+    "kwadryliard",
+    "kwintylion",
+    "kwintyliard",
+    "sekstylion",
+];
+
+/// Numbers in Polish (e.g. 123 -> "sto dwadzieścia trzy").
+///
+/// "Tysiąc", "milion" and above take one of three forms by their count: one (e.g. "tysiąc"),
+/// a few (e.g. "dwa tysiące", "dwadzieścia dwa tysiące") or many (e.g. "pięć tysięcy",
+/// "dwadzieścia jeden tysięcy"). One thousand and one million are not counted with "jeden".
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Gender, Polish};
+/// let polish = Polish::default();
+/// assert_eq!(number_to_words(&123, &polish), "sto dwadzieścia trzy");
+/// assert_eq!(number_to_words(&1_000, &polish), "tysiąc");
+/// assert_eq!(number_to_words(&3_000, &polish), "trzy tysiące");
+/// assert_eq!(number_to_words(&5_000_000, &polish), "pięć milionów");
+///
+/// let feminine = Polish {
+///     gender: Gender::Feminine,
+/// };
+/// assert_eq!(number_to_words(&2, &feminine), "dwie");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Polish {
+    /// The gender of the noun being counted, which a final one or two agrees with
+    /// (e.g. "jeden", "jedna", "jedno", "dwie").
+    pub gender: Gender,
+}
+
+impl Polish {
+    /// The plural category of a number. Only one itself is [`One`]; numbers ending in two to
+    /// four are [`Few`], except twelve to fourteen; all others are [`Many`] (e.g. 21, 25, 112).
+    ///
+    /// [`One`]: enum.PluralCategory.html#variant.One
+    /// [`Few`]: enum.PluralCategory.html#variant.Few
+    /// [`Many`]: enum.PluralCategory.html#variant.Many
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{PluralCategory, Polish};
+    /// assert_eq!(Polish::plural_category(1), PluralCategory::One);
+    /// assert_eq!(Polish::plural_category(22), PluralCategory::Few);
+    /// assert_eq!(Polish::plural_category(21), PluralCategory::Many);
+    /// ```
+    pub fn plural_category(number: u128) -> PluralCategory {
+        match (number, number % 10, number % 100) {
+            (1, _, _) => PluralCategory::One,
+            (_, _, 12..=14) => PluralCategory::Many,
+            (_, 2..=4, _) => PluralCategory::Few,
+            _ => PluralCategory::Many,
+        }
+    }
+
+    /// Write a number from one to nine hundred ninety-nine, agreeing with a gender.
+    fn write_hundreds_as<W: Write>(&self, out: &mut W, number: u64, gender: Gender) -> fmt::Result {
+        let hundreds = number / 100;
+        let rest = number % 100;
+        let mut separator = "";
+        if hundreds > 0 {
+            out.write_str(HUNDREDS[hundreds as usize - 1])?;
+            separator = " ";
+        }
+        let unit = match rest {
+            10..=19 => rest,
+            _ => {
+                if rest >= 20 {
+                    out.write_str(separator)?;
+                    out.write_str(TENS[rest as usize / 10 - 1])?;
+                    separator = " ";
+                }
+                rest % 10
+            }
+        };
+        if unit == 0 {
+            return Ok(());
+        }
+        out.write_str(separator)?;
+        match (unit, gender) {
+            (1, Gender::Feminine) => out.write_str("jedna"),
+            (1, Gender::Neuter) => out.write_str("jedno"),
+            (2, Gender::Feminine) => out.write_str("dwie"),
+            _ => out.write_str(UNITS[unit as usize - 1]),
+        }
+    }
+}
+
+impl Language for Polish {
+    fn zero(&self) -> &str {
+        "zero"
+    }
+
+    fn negative(&self) -> &str {
+        "minus"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "przecinek"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "sto"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str> {
+        let category = Self::plural_category(group);
+        if magnitude == 1 {
+            return Cow::Borrowed(match category {
+                PluralCategory::One => "tysiąc",
+                PluralCategory::Few => "tysiące",
+                PluralCategory::Many => "tysięcy",
+            });
+        }
+        let largest = MAGNITUDES.len() + 1;
+        let Some(name) = MAGNITUDES.get(magnitude - 2) else {
+            // past the named powers, the largest name is counted (e.g. "tysiąc sekstylionów")
+            let inner = self.magnitude_name(magnitude - largest, group);
+            return Cow::Owned(format!("{} sekstylionów", inner));
+        };
+        // "milion" -> "miliony", "milionów"
+        let mut name = String::from(*name);
+        match category {
+            PluralCategory::One => {}
+            PluralCategory::Few => name.push('y'),
+            PluralCategory::Many => name.push_str("ów"),
+        }
+        Cow::Owned(name)
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        self.write_hundreds_as(out, number, self.gender)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        if magnitude == 0 {
+            return self.write_hundreds(out, group as u64);
+        }
+        // "tysiąc" and above are masculine nouns, named alone for one (e.g. "tysiąc", "milion")
+        if group > 1 {
+            self.write_hundreds_as(out, group as u64, Gender::Masculine)?;
+            out.write_char(' ')?;
+        }
+        out.write_str(&self.magnitude_name(magnitude, group))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Gender, Polish};
+
+    #[test]
+    fn test_polish() {
+        let polish = Polish::default();
+        for (number, words) in [
+            (0_i64, "zero"),
+            (1, "jeden"),
+            (2, "dwa"),
+            (12, "dwanaście"),
+            (22, "dwadzieścia dwa"),
+            (100, "sto"),
+            (123, "sto dwadzieścia trzy"),
+            (200, "dwieście"),
+            (1_000, "tysiąc"),
+            (1_001, "tysiąc jeden"),
+            (2_000, "dwa tysiące"),
+            (5_000, "pięć tysięcy"),
+            (12_000, "dwanaście tysięcy"),
+            (21_000, "dwadzieścia jeden tysięcy"),
+            (22_000, "dwadzieścia dwa tysiące"),
+            (1_000_000, "milion"),
+            (3_000_000, "trzy miliony"),
+            (5_000_000, "pięć milionów"),
+            (
+                1_234_567,
+                "milion dwieście trzydzieści cztery tysiące pięćset sześćdziesiąt siedem",
+            ),
+            (1_000_000_000, "miliard"),
+            (2_000_000_000, "dwa miliardy"),
+            (1_000_000_000_000, "bilion"),
+            (-9, "minus dziewięć"),
+        ] {
+            assert_eq!(number_to_words(&number, &polish), words);
+        }
+        assert_eq!(number_to_words(&0.5, &polish), "zero przecinek pięć");
+
+        let feminine = Polish {
+            gender: Gender::Feminine,
+        };
+        assert_eq!(number_to_words(&1, &feminine), "jedna");
+        assert_eq!(number_to_words(&22, &feminine), "dwadzieścia dwie");
+        assert_eq!(number_to_words(&2_000, &feminine), "dwa tysiące");
+        let neuter = Polish {
+            gender: Gender::Neuter,
+        };
+        assert_eq!(number_to_words(&1, &neuter), "jedno");
+    }
+}
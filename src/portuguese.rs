@@ -0,0 +1,375 @@
+use crate::{Gender, Language};
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{self, Write};
+
+/// The names of the numbers from one to nineteen in Brazilian Portuguese.
+const BRAZILIAN_UNITS: [&str; 19] = [
+    "um",
+    "dois",
+    "três",
+    "quatro",
+    "cinco",
+    "seis",
+    "sete",
+    "oito",
+    "nove",
+    "dez",
+    "onze",
+    "doze",
+    "treze",
+    "quatorze",
+    "quinze",
+    "dezesseis",
+    "dezessete",
+    "dezoito",
+    "dezenove",
+];
+
+/// The names of the numbers from one to nineteen in European Portuguese.
+const EUROPEAN_UNITS: [&str; 19] = [
+    "um",
+    "dois",
+    "três",
+    "quatro",
+    "cinco",
+    "seis",
+    "sete",
+    "oito",
+    "nove",
+    "dez",
+    "onze",
+    "doze",
+    "treze",
+    "catorze",
+    "quinze",
+    "dezasseis",
+    "dezassete",
+    "dezoito",
+    "dezanove",
+];
+
+/// The names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "dez",
+    "vinte",
+    "trinta",
+    "quarenta",
+    "cinquenta",
+    "sessenta",
+    "setenta",
+    "oitenta",
+    "noventa",
+];
+
+/// The masculine names of the hundreds from one hundred to nine hundred, with more below them.
+const HUNDREDS: [&str; 9] = [
+    "cento",
+    "duzentos",
+    "trezentos",
+    "quatrocentos",
+    "quinhentos",
+    "seiscentos",
+    "setecentos",
+    "oitocentos",
+    "novecentos",
+];
+
+/// The names of each power of one thousand above "mil" in Brazil, in the short scale.
+const BRAZILIAN_MAGNITUDES: [&str; 11] = [
+    "milhão",
+    "bilhão",
+    "trilhão",
+    "quatrilhão",
+    "quintilhão",
+    "sextilhão",
+    "septilhão",
+    "octilhão",
+    "nonilhão",
+    "decilhão",
+    "undecilhão",
+];
+
+/// The names of each power of one million in Portugal, in the long scale.
+const EUROPEAN_MAGNITUDES: [&str; 6] = [
+    "milhão",
+    "bilião",
+    "trilião",
+    "quatrilião",
+    "quintilião",
+    "sextilião",
+];
+
+/// The variety of Portuguese that numbers are named in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum PortugueseVariant {
+    /// Brazilian Portuguese, in the short scale (e.g. 10^9 is "um bilhão", 16 is "dezesseis").
+    #[default]
+    Brazilian,
+    /// European Portuguese, in the long scale (e.g. 10^9 is "mil milhões", 16 is "dezasseis").
+    European,
+}
+
+/// Numbers in Portuguese (e.g. 123 -> "cento e vinte e três").
+///
+/// The hundreds, tens and units are joined with "e". The last group is also joined with "e"
+/// if it is below one hundred or a whole number of hundreds (e.g. "mil e duzentos", but
+/// "mil duzentos e trinta").
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Gender, Portuguese, PortugueseVariant};
+/// let brazilian = Portuguese::default();
+/// assert_eq!(number_to_words(&123, &brazilian), "cento e vinte e três");
+/// assert_eq!(number_to_words(&1_200, &brazilian), "mil e duzentos");
+/// assert_eq!(number_to_words(&1_000_000_000, &brazilian), "um bilhão");
+///
+/// let european = Portuguese {
+///     variant: PortugueseVariant::European,
+///     gender: Gender::Feminine,
+/// };
+/// assert_eq!(number_to_words(&1_000_000_000, &european), "mil milhões");
+/// assert_eq!(number_to_words(&202, &european), "duzentas e duas");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Portuguese {
+    /// The variety of Portuguese.
+    pub variant: PortugueseVariant,
+    /// The gender of the noun being counted, which "um", "dois" and the hundreds agree with
+    /// (e.g. "uma", "duas", "duzentas").
+    pub gender: Gender,
+}
+
+impl Portuguese {
+    /// The names of the numbers from one to nineteen in the variant.
+    fn units_table(&self) -> &'static [&'static str; 19] {
+        match self.variant {
+            PortugueseVariant::Brazilian => &BRAZILIAN_UNITS,
+            PortugueseVariant::European => &EUROPEAN_UNITS,
+        }
+    }
+
+    /// Write a number from one to nine hundred ninety-nine, agreeing with a gender.
+    fn write_hundreds_as<W: Write>(&self, out: &mut W, number: u64, feminine: bool) -> fmt::Result {
+        let hundreds = number / 100;
+        let rest = number % 100;
+        if number == 100 {
+            return out.write_str("cem");
+        }
+        if hundreds > 0 {
+            let name = HUNDREDS[hundreds as usize - 1];
+            match name.strip_suffix("os").filter(|_| feminine) {
+                Some(stem) => write!(out, "{}as", stem)?,
+                None => out.write_str(name)?,
+            }
+            if rest > 0 {
+                out.write_str(" e ")?;
+            }
+        }
+
+        let unit = |unit: u64| match unit {
+            1 if feminine => "uma",
+            2 if feminine => "duas",
+            _ => self.units_table()[unit as usize - 1],
+        };
+        match rest {
+            0 => Ok(()),
+            1..=19 => out.write_str(unit(rest)),
+            _ => {
+                out.write_str(TENS[rest as usize / 10 - 1])?;
+                match rest % 10 {
+                    0 => Ok(()),
+                    units => write!(out, " e {}", unit(units)),
+                }
+            }
+        }
+    }
+
+    /// The separator before a group that ends a number (e.g. " e " before "duzentos").
+    fn joiner(group: u64) -> &'static str {
+        if group < 100 || group.is_multiple_of(100) {
+            " e "
+        } else {
+            " "
+        }
+    }
+}
+
+impl Language for Portuguese {
+    fn zero(&self) -> &str {
+        "zero"
+    }
+
+    fn negative(&self) -> &str {
+        "menos"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "vírgula"
+    }
+
+    fn units(&self) -> &[&str] {
+        self.units_table()
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "cem"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str> {
+        let names: &[&str] = match self.variant {
+            PortugueseVariant::Brazilian if magnitude == 1 => return Cow::Borrowed("mil"),
+            PortugueseVariant::Brazilian => &BRAZILIAN_MAGNITUDES[..],
+            PortugueseVariant::European => &EUROPEAN_MAGNITUDES[..],
+        };
+        let offset = match self.variant {
+            PortugueseVariant::Brazilian => 2,
+            PortugueseVariant::European => 1,
+        };
+        let Some(name) = names.get(magnitude - offset) else {
+            // past the named powers, the largest name is counted (e.g. "mil de sextiliões")
+            let mut name = String::from(self.magnitude_name(magnitude - names.len(), group));
+            name.push_str(" de ");
+            name.push_str(&self.magnitude_name(names.len() + offset - 1, 2));
+            return Cow::Owned(name);
+        };
+        if group == 1 {
+            Cow::Borrowed(name)
+        } else {
+            // "milhão" -> "milhões"
+            let mut name = String::from(name.trim_end_matches("ão"));
+            name.push_str("ões");
+            Cow::Owned(name)
+        }
+    }
+
+    fn group_digits(&self, _magnitude: usize) -> u32 {
+        match self.variant {
+            PortugueseVariant::Brazilian => 3,
+            // each group is six digits, read as thousands within the group (e.g. "mil milhões")
+            PortugueseVariant::European => 6,
+        }
+    }
+
+    fn tens_separator(&self) -> &str {
+        " e "
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        self.write_hundreds_as(out, number, self.gender == Gender::Feminine)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        // the millions are masculine nouns (e.g. "duzentos milhões")
+        let feminine = self.gender == Gender::Feminine
+            && (magnitude == 0 || (magnitude == 1 && self.variant == PortugueseVariant::Brazilian));
+        let (thousands, rest) = match self.variant {
+            PortugueseVariant::Brazilian if magnitude == 1 => (group as u64, 0),
+            PortugueseVariant::Brazilian => (0, group as u64),
+            PortugueseVariant::European => ((group / 1000) as u64, (group % 1000) as u64),
+        };
+
+        if thousands > 0 {
+            // "mil" is not counted with "um" (e.g. "mil", "dois mil")
+            if thousands > 1 {
+                self.write_hundreds_as(out, thousands, feminine)?;
+                out.write_char(' ')?;
+            }
+            out.write_str("mil")?;
+            if rest > 0 {
+                out.write_str(Self::joiner(rest))?;
+            }
+        }
+        if rest > 0 {
+            self.write_hundreds_as(out, rest, feminine)?;
+        }
+        let named = match self.variant {
+            PortugueseVariant::Brazilian => magnitude > 1,
+            PortugueseVariant::European => magnitude > 0,
+        };
+        if named {
+            out.write_char(' ')?;
+            out.write_str(&self.magnitude_name(magnitude, group))?;
+        }
+        Ok(())
+    }
+
+    fn write_group_separator<W: Write>(
+        &self,
+        out: &mut W,
+        _above: usize,
+        magnitude: usize,
+        group: u128,
+    ) -> fmt::Result {
+        // only the last group is joined with "e" (e.g. "dois milhões e cinco")
+        if magnitude == 0 && group < 1000 {
+            out.write_str(Self::joiner(group as u64))
+        } else {
+            out.write_char(' ')
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Gender, Portuguese, PortugueseVariant};
+
+    #[test]
+    fn test_portuguese() {
+        let brazilian = Portuguese::default();
+        for (number, words) in [
+            (0_i64, "zero"),
+            (16, "dezesseis"),
+            (21, "vinte e um"),
+            (100, "cem"),
+            (101, "cento e um"),
+            (123, "cento e vinte e três"),
+            (500, "quinhentos"),
+            (1_000, "mil"),
+            (1_001, "mil e um"),
+            (1_200, "mil e duzentos"),
+            (1_230, "mil duzentos e trinta"),
+            (2_000, "dois mil"),
+            (1_000_000, "um milhão"),
+            (2_000_005, "dois milhões e cinco"),
+            (
+                1_234_567,
+                "um milhão duzentos e trinta e quatro mil quinhentos e sessenta e sete",
+            ),
+            (1_000_000_000, "um bilhão"),
+            (3_000_000_000, "três bilhões"),
+            (-2, "menos dois"),
+        ] {
+            assert_eq!(number_to_words(&number, &brazilian), words);
+        }
+        assert_eq!(number_to_words(&2.5, &brazilian), "dois vírgula cinco");
+
+        let feminine = Portuguese {
+            gender: Gender::Feminine,
+            ..Default::default()
+        };
+        assert_eq!(number_to_words(&2, &feminine), "duas");
+        assert_eq!(number_to_words(&21, &feminine), "vinte e uma");
+        assert_eq!(number_to_words(&200_000, &feminine), "duzentas mil");
+        assert_eq!(number_to_words(&2_000_000, &feminine), "dois milhões");
+
+        let european = Portuguese {
+            variant: PortugueseVariant::European,
+            ..Default::default()
+        };
+        for (number, words) in [
+            (14_i64, "catorze"),
+            (16, "dezasseis"),
+            (1_200, "mil e duzentos"),
+            (1_000_000_000, "mil milhões"),
+            (2_500_000_000, "dois mil e quinhentos milhões"),
+            (1_000_000_000_000, "um bilião"),
+            (2_000_000_000_005, "dois biliões e cinco"),
+        ] {
+            assert_eq!(number_to_words(&number, &european), words);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! Python bindings for converting numbers to English.
+//!
+//! Build a Python extension module with `maturin build --release --features python` (or
+//! `cargo rustc --release --features python --crate-type cdylib`, renaming the library to
+//! `num2english.so`), and call it from Python:
+//!
+//! ```python
+//! from decimal import Decimal
+//! import num2english
+//!
+//! num2english.to_english(105, british_and=True)  # "one hundred and five"
+//! num2english.to_english(Decimal("1.25"), decimal_style="point")  # "one point two five"
+//! num2english.to_english_ordinal(21, case="title")  # "Twenty-First"
+//! num2english.to_english_currency("1.5", "EUR")  # "one euro and fifty cents"
+//! ```
+//!
+//! A number is read from its `str()`, so integers of any size and `Decimal`s are read exactly.
+//! Each function takes the fields of [`Options`] as keyword arguments, with the names of enum
+//! values in lowercase (e.g. `scale="long-milliard"`, `rounding="half-even"`). Invalid numbers
+//! and option values raise `ValueError`, and unknown options raise `TypeError`.
+//!
+//! [`Options`]: ../struct.Options.html
+
+use crate::{
+    convert_str, convert_str_currency, convert_str_ordinal, Case, Currency, DecimalStyle, Options,
+    Rounding, Scale,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::PyDict,
+};
+
+/// Convert a number to English, as by [`convert_str`].
+///
+/// [`convert_str`]: ../fn.convert_str.html
+#[pyfunction]
+#[pyo3(signature = (number, **options))]
+pub fn to_english(
+    number: &Bound<'_, PyAny>,
+    options: Option<&Bound<'_, PyDict>>,
+) -> PyResult<String> {
+    let number = number.str()?.to_string();
+    convert_str(&number, &read_options(options)?).map_err(value_error)
+}
+
+/// Convert an integer to English as an ordinal, as by [`convert_str_ordinal`].
+///
+/// [`convert_str_ordinal`]: ../fn.convert_str_ordinal.html
+#[pyfunction]
+#[pyo3(signature = (number, **options))]
+pub fn to_english_ordinal(
+    number: &Bound<'_, PyAny>,
+    options: Option<&Bound<'_, PyDict>>,
+) -> PyResult<String> {
+    let number = number.str()?.to_string();
+    convert_str_ordinal(&number, &read_options(options)?).map_err(value_error)
+}
+
+/// Convert an amount of money to English in the currency with the given code ("USD", "EUR" or
+/// "GBP"), as by [`convert_str_currency`].
+///
+/// [`convert_str_currency`]: ../fn.convert_str_currency.html
+#[pyfunction]
+#[pyo3(signature = (number, currency, **options))]
+pub fn to_english_currency(
+    number: &Bound<'_, PyAny>,
+    currency: &str,
+    options: Option<&Bound<'_, PyDict>>,
+) -> PyResult<String> {
+    let currency = Currency::from_code(currency)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown currency {:?}", currency)))?;
+    let number = number.str()?.to_string();
+    convert_str_currency(&number, &currency, &read_options(options)?).map_err(value_error)
+}
+
+/// The `num2english` Python module.
+#[pymodule]
+pub fn num2english(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(to_english, module)?)?;
+    module.add_function(wrap_pyfunction!(to_english_ordinal, module)?)?;
+    module.add_function(wrap_pyfunction!(to_english_currency, module)?)?;
+    Ok(())
+}
+
+/// Read the options given as keyword arguments over the defaults.
+fn read_options(kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<Options> {
+    let mut options = Options::default();
+    for (name, value) in kwargs.into_iter().flatten() {
+        let name: String = name.extract()?;
+        set_option(&mut options, &name, &value)?;
+    }
+    Ok(options)
+}
+
+/// Set one option by the name of its field.
+fn set_option(options: &mut Options, name: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+    match name {
+        "british_and" => options.british_and = value.extract()?,
+        "negative_word" => options.negative_word = choose(name, value, &["negative", "minus"])?,
+        "decimal_style" => options.decimal_style = named(name, value, DecimalStyle::from_name)?,
+        "case" => options.case = named(name, value, Case::from_name)?,
+        "compound_separator" => options.compound_separator = choose(name, value, &["-", " "])?,
+        "group_separator" => options.group_separator = choose(name, value, &[" ", ", "])?,
+        "scale" => options.scale = named(name, value, Scale::from_name)?,
+        "zero_word" => {
+            options.zero_word = choose(name, value, &["zero", "oh", "naught", "nought", "nil"])?
+        }
+        "informal_article" => options.informal_article = value.extract()?,
+        "colloquial_hundreds" => options.colloquial_hundreds = value.extract()?,
+        "colloquial_units" => options.colloquial_units = value.extract()?,
+        "max_decimal_places" => options.max_decimal_places = value.extract()?,
+        "rounding" => options.rounding = named(name, value, Rounding::from_name)?,
+        _ => {
+            return Err(PyTypeError::new_err(format!(
+                "unexpected keyword argument {:?}",
+                name
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Read an option whose value is the name of an enum value.
+fn named<T>(
+    option: &str,
+    value: &Bound<'_, PyAny>,
+    from_name: fn(&str) -> Option<T>,
+) -> PyResult<T> {
+    let value: String = value.extract()?;
+    from_name(&value).ok_or_else(|| unknown(option, &value))
+}
+
+/// Find the static value among the choices for an option that is equal to the given one.
+fn choose(
+    option: &str,
+    value: &Bound<'_, PyAny>,
+    choices: &[&'static str],
+) -> PyResult<&'static str> {
+    let value: String = value.extract()?;
+    choices
+        .iter()
+        .find(|&&choice| choice == value)
+        .copied()
+        .ok_or_else(|| unknown(option, &value))
+}
+
+/// The error for a value that an option does not take.
+fn unknown(option: &str, value: &str) -> PyErr {
+    PyValueError::new_err(format!("unknown {} {:?}", option, value))
+}
+
+/// The error for a number that cannot be converted.
+fn value_error(error: crate::Error) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_english, to_english_currency, to_english_ordinal};
+    use pyo3::{
+        exceptions::{PyTypeError, PyValueError},
+        prelude::*,
+        types::{IntoPyDict, PyDict},
+    };
+
+    #[test]
+    fn test_python() {
+        Python::initialize();
+        Python::attach(|py| {
+            let number = |source: &str| {
+                py.eval(&std::ffi::CString::new(source).unwrap(), None, None)
+                    .unwrap()
+            };
+            let no_options = None::<&Bound<'_, PyDict>>;
+
+            assert_eq!(
+                to_english(&number("10**30 + 5"), no_options).unwrap(),
+                "one nonillion five"
+            );
+            assert_eq!(
+                to_english(&number("1e16"), no_options).unwrap(),
+                "ten quadrillion"
+            );
+            assert_eq!(
+                to_english(&number("__import__('decimal').Decimal('1.25')"), no_options).unwrap(),
+                "one and twenty-five hundredths"
+            );
+
+            let options = [
+                ("british_and", number("True")),
+                ("case", number("'title'")),
+                ("decimal_style", number("'point'")),
+                ("negative_word", number("'minus'")),
+            ]
+            .into_py_dict(py)
+            .unwrap();
+            assert_eq!(
+                to_english(&number("-105.25"), Some(&options)).unwrap(),
+                "Minus One Hundred and Five Point Two Five"
+            );
+            assert_eq!(
+                to_english_ordinal(&number("21"), Some(&options)).unwrap(),
+                "Twenty-First"
+            );
+
+            let options = [
+                ("scale", number("'long'")),
+                ("rounding", number("'truncate'")),
+                ("max_decimal_places", number("1")),
+            ]
+            .into_py_dict(py)
+            .unwrap();
+            assert_eq!(
+                to_english(&number("'1000000000.29'"), Some(&options)).unwrap(),
+                "one thousand million and two tenths"
+            );
+            assert_eq!(
+                to_english_currency(&number("'2000000000.5'"), "usd", Some(&options)).unwrap(),
+                "two thousand million dollars and fifty cents"
+            );
+
+            let error = to_english(&number("'x'"), no_options).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+            let error = to_english_ordinal(&number("1.5"), no_options).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+            let error = to_english_currency(&number("1"), "JPY", no_options).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+            let options = [("scale", number("'huge'"))].into_py_dict(py).unwrap();
+            let error = to_english(&number("1"), Some(&options)).unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+            let options = [("colour", number("True"))].into_py_dict(py).unwrap();
+            let error = to_english(&number("1"), Some(&options)).unwrap_err();
+            assert!(error.is_instance_of::<PyTypeError>(py));
+        });
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{convert_number_to_english, decimal::Decimal, DecimalStyle, Options};
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+
+/// The singular and plural names of a unit of measurement.
+///
+/// # Examples
+/// ```
+/// use num2english::{si_quantity_to_english, Unit};
+/// const PARSEC: Unit = Unit {
+///     singular: "parsec",
+///     plural: "parsecs",
+/// };
+/// assert_eq!(si_quantity_to_english(&2_000, &PARSEC), "two kiloparsecs");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Unit {
+    /// The singular name of the unit (e.g. "meter").
+    pub singular: &'static str,
+    /// The plural name of the unit (e.g. "meters").
+    pub plural: &'static str,
+}
+
+impl Unit {
+    /// Meters of length.
+    pub const METER: Unit = Unit {
+        singular: "meter",
+        plural: "meters",
+    };
+    /// Grams of mass.
+    pub const GRAM: Unit = Unit {
+        singular: "gram",
+        plural: "grams",
+    };
+    /// Seconds of time.
+    pub const SECOND: Unit = Unit {
+        singular: "second",
+        plural: "seconds",
+    };
+    /// Liters of volume.
+    pub const LITER: Unit = Unit {
+        singular: "liter",
+        plural: "liters",
+    };
+    /// Amperes of electric current.
+    pub const AMPERE: Unit = Unit {
+        singular: "ampere",
+        plural: "amperes",
+    };
+    /// Volts of electric potential.
+    pub const VOLT: Unit = Unit {
+        singular: "volt",
+        plural: "volts",
+    };
+    /// Watts of power.
+    pub const WATT: Unit = Unit {
+        singular: "watt",
+        plural: "watts",
+    };
+    /// Hertz of frequency.
+    pub const HERTZ: Unit = Unit {
+        singular: "hertz",
+        plural: "hertz",
+    };
+    /// Farads of capacitance.
+    pub const FARAD: Unit = Unit {
+        singular: "farad",
+        plural: "farads",
+    };
+}
+
+/// The SI prefixes for each power of one thousand, from 10^-30 to 10^30.
+pub(crate) const SI_PREFIXES: [&str; 21] = [
+    "quecto", "ronto", "yocto", "zepto", "atto", "femto", "pico", "nano", "micro", "milli", "",
+    "kilo", "mega", "giga", "tera", "peta", "exa", "zetta", "yotta", "ronna", "quetta",
+];
+
+/// Read a quantity with the SI prefix that keeps its number between one and one thousand.
+///
+/// The number is read in point style (e.g. "three point two kilometers").
+///
+/// # Examples
+/// ```
+/// use num2english::{si_quantity_to_english, Unit};
+/// assert_eq!(si_quantity_to_english(&3_200, &Unit::METER), "three point two kilometers");
+/// assert_eq!(si_quantity_to_english(&0.002, &Unit::SECOND), "two milliseconds");
+/// assert_eq!(si_quantity_to_english(&1, &Unit::GRAM), "one gram");
+/// ```
+pub fn si_quantity_to_english<T: Display>(value: &T, unit: &Unit) -> String {
+    let string = value.to_string();
+    let Some(number) = Decimal::parse(&string) else {
+        let mut result = convert_number_to_english(string, &Options::default());
+        result.push(' ');
+        result.push_str(unit.plural);
+        return result;
+    };
+
+    let index = if number.is_zero() {
+        10
+    } else {
+        (number.leading_power().div_euclid(3) + 10).clamp(0, 20)
+    };
+    let mantissa = number.shift(-(index - 10) * 3);
+
+    let options = Options {
+        decimal_style: DecimalStyle::Point,
+        ..Default::default()
+    };
+    let mantissa = mantissa.to_plain_string();
+    let mut result = convert_number_to_english(mantissa.clone(), &options);
+    result.push(' ');
+    result.push_str(SI_PREFIXES[index as usize]);
+    result.push_str(if mantissa == "1" {
+        unit.singular
+    } else {
+        unit.plural
+    });
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{si_quantity_to_english, Unit};
+
+    #[test]
+    fn test_si_quantity() {
+        assert_eq!(
+            si_quantity_to_english(&3_200, &Unit::METER),
+            "three point two kilometers"
+        );
+        assert_eq!(
+            si_quantity_to_english(&0.002, &Unit::SECOND),
+            "two milliseconds"
+        );
+        assert_eq!(si_quantity_to_english(&1_000, &Unit::GRAM), "one kilogram");
+        assert_eq!(
+            si_quantity_to_english(&999, &Unit::WATT),
+            "nine hundred ninety-nine watts"
+        );
+        assert_eq!(
+            si_quantity_to_english(&2_400_000_000_u64, &Unit::HERTZ),
+            "two point four gigahertz"
+        );
+        assert_eq!(
+            si_quantity_to_english(&0.000_000_047, &Unit::FARAD),
+            "forty-seven nanofarads"
+        );
+        assert_eq!(si_quantity_to_english(&0, &Unit::VOLT), "zero volts");
+        assert_eq!(
+            si_quantity_to_english(&-1_500, &Unit::METER),
+            "negative one point five kilometers"
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Error, NumberToEnglish};
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+use num_traits::Num;
+
+/// Options for writing Roman numerals.
+///
+/// # Examples
+/// ```
+/// use num2english::{NumberToRoman, RomanOptions};
+/// let additive = RomanOptions {
+///     subtractive: false,
+///     ..Default::default()
+/// };
+/// assert_eq!(1999.to_roman_with(&additive), Some("MDCCCCLXXXXVIIII".to_string()));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RomanOptions {
+    /// Write 4 and 9 with a smaller numeral before a larger one (e.g. "IV" instead of "IIII").
+    pub subtractive: bool,
+    /// Write thousands above 3999 with an overline (e.g. 5000 -> "V̅"), using combining overlines.
+    pub overline: bool,
+}
+
+impl Default for RomanOptions {
+    fn default() -> Self {
+        RomanOptions {
+            subtractive: true,
+            overline: false,
+        }
+    }
+}
+
+/// Convert any number type to Roman numerals.
+///
+/// Only whole numbers from 1 to 3999 can be written, or up to 3,999,999 with overlines.
+///
+/// # Examples
+/// ```
+/// use num2english::NumberToRoman;
+/// assert_eq!(2024.to_roman(), Some("MMXXIV".to_string()));
+/// assert_eq!(0.to_roman(), None);
+/// assert_eq!(1.5.to_roman(), None);
+/// ```
+pub trait NumberToRoman<T>
+where
+    T: Num + Display,
+{
+    /// Convert a number to Roman numerals, or `None` if it can't be written as one.
+    fn to_roman(&self) -> Option<String>;
+
+    /// Convert a number to Roman numerals using the given [`RomanOptions`], or `None` if it can't be written as one.
+    ///
+    /// [`RomanOptions`]: struct.RomanOptions.html
+    fn to_roman_with(&self, options: &RomanOptions) -> Option<String>;
+}
+
+impl<T> NumberToRoman<T> for T
+where
+    T: Num + Display,
+{
+    fn to_roman(&self) -> Option<String> {
+        self.to_roman_with(&RomanOptions::default())
+    }
+
+    fn to_roman_with(&self, options: &RomanOptions) -> Option<String> {
+        let number = self.to_string().parse::<u32>().ok()?;
+        convert_number_to_roman(number, options)
+    }
+}
+
+/// Parse a Roman numeral from 1 to 3999, in either subtractive or additive form.
+///
+/// # Examples
+/// ```
+/// use num2english::{parse_roman, Error};
+/// assert_eq!(parse_roman("MCMLXXXIV"), Ok(1984));
+/// assert_eq!(parse_roman("iiii"), Ok(4));
+/// assert_eq!(parse_roman("IIX"), Err(Error::InvalidRomanNumeral));
+/// ```
+pub fn parse_roman(numeral: &str) -> Result<u32, Error> {
+    let numeral = numeral.trim().to_ascii_uppercase();
+
+    let mut number = 0;
+    let mut rest = numeral.as_str();
+    for &(value, symbol) in NUMERALS.iter() {
+        while let Some(tail) = rest.strip_prefix(symbol) {
+            number += value;
+            rest = tail;
+        }
+    }
+
+    if number == 0 || !rest.is_empty() {
+        return Err(Error::InvalidRomanNumeral);
+    }
+
+    // only the canonical way of writing the number is accepted (e.g. "IV" or "IIII", but not "IIX")
+    let subtractive = convert_below_four_thousand(number, &RomanOptions::default());
+    let additive = RomanOptions {
+        subtractive: false,
+        ..Default::default()
+    };
+    if number >= 4000
+        || (numeral != subtractive && numeral != convert_below_four_thousand(number, &additive))
+    {
+        return Err(Error::InvalidRomanNumeral);
+    }
+
+    Ok(number)
+}
+
+/// Convert a Roman numeral to its name in English.
+///
+/// # Examples
+/// ```
+/// use num2english::{roman_to_english, Error};
+/// assert_eq!(
+///     roman_to_english("MCMLXXXIV"),
+///     Ok("one thousand nine hundred eighty-four".to_string())
+/// );
+/// assert_eq!(roman_to_english("MMXQ"), Err(Error::InvalidRomanNumeral));
+/// ```
+pub fn roman_to_english(numeral: &str) -> Result<String, Error> {
+    parse_roman(numeral).map(|number| number.to_english())
+}
+
+/// The value of each Roman numeral, including the subtractive pairs, from largest to smallest.
+const NUMERALS: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Convert a number to Roman numerals (e.g. 2024 -> "MMXXIV")
+fn convert_number_to_roman(number: u32, options: &RomanOptions) -> Option<String> {
+    if number == 0 {
+        return None;
+    }
+
+    if number < 4000 {
+        return Some(convert_below_four_thousand(number, options));
+    }
+
+    if !options.overline || number >= 4_000_000 {
+        return None;
+    }
+
+    let mut result = String::new();
+    for c in convert_below_four_thousand(number / 1000, options).chars() {
+        result.push(c);
+        result.push('\u{0305}');
+    }
+    result.push_str(&convert_below_four_thousand(number % 1000, options));
+    Some(result)
+}
+
+/// Convert a number below 4000 to Roman numerals, or an empty string for zero.
+fn convert_below_four_thousand(mut number: u32, options: &RomanOptions) -> String {
+    let mut result = String::new();
+
+    for &(value, numeral) in NUMERALS.iter() {
+        // the additive form skips the subtractive pairs (e.g. "IIII" instead of "IV")
+        if !options.subtractive && numeral.len() > 1 {
+            continue;
+        }
+        while number >= value {
+            result.push_str(numeral);
+            number -= value;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_roman, roman_to_english, Error, NumberToRoman, RomanOptions};
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_parse_roman() {
+        assert_eq!(parse_roman("I"), Ok(1));
+        assert_eq!(parse_roman("XIV"), Ok(14));
+        assert_eq!(parse_roman("mmxxiv"), Ok(2024));
+        assert_eq!(parse_roman(" MMMCMXCIX "), Ok(3999));
+        assert_eq!(parse_roman("XXXXVIIII"), Ok(49));
+        assert_eq!(parse_roman(""), Err(Error::InvalidRomanNumeral));
+        assert_eq!(parse_roman("IC"), Err(Error::InvalidRomanNumeral));
+        assert_eq!(parse_roman("VV"), Err(Error::InvalidRomanNumeral));
+        assert_eq!(parse_roman("MMMM"), Err(Error::InvalidRomanNumeral));
+        assert_eq!(parse_roman("XIIII"), Ok(14));
+        assert_eq!(parse_roman("XIVI"), Err(Error::InvalidRomanNumeral));
+        assert_eq!(parse_roman("12"), Err(Error::InvalidRomanNumeral));
+        assert_eq!(
+            roman_to_english("MCMLXXXIV"),
+            Ok("one thousand nine hundred eighty-four".to_string())
+        );
+    }
+
+    #[test]
+    fn test_roman() {
+        assert_eq!(1.to_roman(), Some("I".to_string()));
+        assert_eq!(4.to_roman(), Some("IV".to_string()));
+        assert_eq!(9.to_roman(), Some("IX".to_string()));
+        assert_eq!(14.to_roman(), Some("XIV".to_string()));
+        assert_eq!(40.to_roman(), Some("XL".to_string()));
+        assert_eq!(90.to_roman(), Some("XC".to_string()));
+        assert_eq!(400.to_roman(), Some("CD".to_string()));
+        assert_eq!(1984.to_roman(), Some("MCMLXXXIV".to_string()));
+        assert_eq!(2024.to_roman(), Some("MMXXIV".to_string()));
+        assert_eq!(3999.to_roman(), Some("MMMCMXCIX".to_string()));
+        assert_eq!(4000.to_roman(), None);
+        assert_eq!((-5).to_roman(), None);
+        assert_eq!(0.to_roman(), None);
+
+        let additive = RomanOptions {
+            subtractive: false,
+            ..Default::default()
+        };
+        assert_eq!(4.to_roman_with(&additive), Some("IIII".to_string()));
+        assert_eq!(49.to_roman_with(&additive), Some("XXXXVIIII".to_string()));
+
+        let overline = RomanOptions {
+            overline: true,
+            ..Default::default()
+        };
+        assert_eq!(5000.to_roman_with(&overline), Some("V\u{0305}".to_string()));
+        assert_eq!(
+            4001.to_roman_with(&overline),
+            Some("I\u{0305}V\u{0305}I".to_string())
+        );
+        assert_eq!(4_000_000.to_roman_with(&overline), None);
+    }
+}
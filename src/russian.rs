@@ -0,0 +1,471 @@
+use crate::{
+    slavic::{self, Declensions},
+    Gender, GrammaticalCase, Language, NounAgreement, PluralCategory,
+};
+use alloc::borrow::Cow;
+use core::fmt::{self, Write};
+
+/// The nominative names of the numbers from one to nineteen.
+const UNITS: [&str; 19] = [
+    "один",
+    "два",
+    "три",
+    "четыре",
+    "пять",
+    "шесть",
+    "семь",
+    "восемь",
+    "девять",
+    "десять",
+    "одиннадцать",
+    "двенадцать",
+    "тринадцать",
+    "четырнадцать",
+    "пятнадцать",
+    "шестнадцать",
+    "семнадцать",
+    "восемнадцать",
+    "девятнадцать",
+];
+
+/// The nominative names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "десять",
+    "двадцать",
+    "тридцать",
+    "сорок",
+    "пятьдесят",
+    "шестьдесят",
+    "семьдесят",
+    "восемьдесят",
+    "девяносто",
+];
+
+/// The masculine names of one to ten in each case, in the order of [`GrammaticalCase`].
+///
+/// [`GrammaticalCase`]: enum.GrammaticalCase.html
+const DECLINED_UNITS: [[&str; 6]; 10] = [
+    ["один", "одного", "одному", "один", "одним", "одном"],
+    ["два", "двух", "двум", "два", "двумя", "двух"],
+    ["три", "трёх", "трём", "три", "тремя", "трёх"],
+    [
+        "четыре",
+        "четырёх",
+        "четырём",
+        "четыре",
+        "четырьмя",
+        "четырёх",
+    ],
+    ["пять", "пяти", "пяти", "пять", "пятью", "пяти"],
+    ["шесть", "шести", "шести", "шесть", "шестью", "шести"],
+    ["семь", "семи", "семи", "семь", "семью", "семи"],
+    ["восемь", "восьми", "восьми", "восемь", "восемью", "восьми"],
+    ["девять", "девяти", "девяти", "девять", "девятью", "девяти"],
+    ["десять", "десяти", "десяти", "десять", "десятью", "десяти"],
+];
+
+/// The feminine and neuter names of one in each case.
+const ONE: [[&str; 6]; 2] = [
+    ["одна", "одной", "одной", "одну", "одной", "одной"],
+    ["одно", "одного", "одному", "одно", "одним", "одном"],
+];
+
+/// The names of the tens in each case.
+const DECLINED_TENS: [[&str; 6]; 9] = [
+    ["десять", "десяти", "десяти", "десять", "десятью", "десяти"],
+    [
+        "двадцать",
+        "двадцати",
+        "двадцати",
+        "двадцать",
+        "двадцатью",
+        "двадцати",
+    ],
+    [
+        "тридцать",
+        "тридцати",
+        "тридцати",
+        "тридцать",
+        "тридцатью",
+        "тридцати",
+    ],
+    ["сорок", "сорока", "сорока", "сорок", "сорока", "сорока"],
+    [
+        "пятьдесят",
+        "пятидесяти",
+        "пятидесяти",
+        "пятьдесят",
+        "пятьюдесятью",
+        "пятидесяти",
+    ],
+    [
+        "шестьдесят",
+        "шестидесяти",
+        "шестидесяти",
+        "шестьдесят",
+        "шестьюдесятью",
+        "шестидесяти",
+    ],
+    [
+        "семьдесят",
+        "семидесяти",
+        "семидесяти",
+        "семьдесят",
+        "семьюдесятью",
+        "семидесяти",
+    ],
+    [
+        "восемьдесят",
+        "восьмидесяти",
+        "восьмидесяти",
+        "восемьдесят",
+        "восемьюдесятью",
+        "восьмидесяти",
+    ],
+    [
+        "девяносто",
+        "девяноста",
+        "девяноста",
+        "девяносто",
+        "девяноста",
+        "девяноста",
+    ],
+];
+
+/// The names of the hundreds in each case.
+const HUNDREDS: [[&str; 6]; 9] = [
+    ["сто", "ста", "ста", "сто", "ста", "ста"],
+    [
+        "двести",
+        "двухсот",
+        "двумстам",
+        "двести",
+        "двумястами",
+        "двухстах",
+    ],
+    [
+        "триста",
+        "трёхсот",
+        "трёмстам",
+        "триста",
+        "тремястами",
+        "трёхстах",
+    ],
+    [
+        "четыреста",
+        "четырёхсот",
+        "четырёмстам",
+        "четыреста",
+        "четырьмястами",
+        "четырёхстах",
+    ],
+    [
+        "пятьсот",
+        "пятисот",
+        "пятистам",
+        "пятьсот",
+        "пятьюстами",
+        "пятистах",
+    ],
+    [
+        "шестьсот",
+        "шестисот",
+        "шестистам",
+        "шестьсот",
+        "шестьюстами",
+        "шестистах",
+    ],
+    [
+        "семьсот",
+        "семисот",
+        "семистам",
+        "семьсот",
+        "семьюстами",
+        "семистах",
+    ],
+    [
+        "восемьсот",
+        "восьмисот",
+        "восьмистам",
+        "восемьсот",
+        "восемьюстами",
+        "восьмистах",
+    ],
+    [
+        "девятьсот",
+        "девятисот",
+        "девятистам",
+        "девятьсот",
+        "девятьюстами",
+        "девятистах",
+    ],
+];
+
+/// The singular and plural forms of "тысяча" in each case.
+const THOUSAND: [[&str; 6]; 2] = [
+    ["тысяча", "тысячи", "тысяче", "тысячу", "тысячей", "тысяче"],
+    [
+        "тысячи",
+        "тысяч",
+        "тысячам",
+        "тысячи",
+        "тысячами",
+        "тысячах",
+    ],
+];
+
+/// The names of each power of one thousand above "тысяча", in the short scale.
+const MAGNITUDES: [&str; 10] = [
+    "миллион",
+    "миллиард",
+    "триллион",
+    "квадриллион",
+    "квинтиллион",
+    "секстиллион",
+    "септиллион",
+    "октиллион",
+    "нониллион",
+    "дециллион",
+];
+
+/// The singular and plural endings of "миллион" and above in each case.
+const MAGNITUDE_ENDINGS: [[&str; 6]; 2] = [
+    ["", "а", "у", "", "ом", "е"],
+    ["ы", "ов", "ам", "ы", "ами", "ах"],
+];
+
+/// The declined words of Russian.
+const RUSSIAN: Declensions = Declensions {
+    units: UNITS,
+    declined_units: DECLINED_UNITS,
+    one: ONE,
+    two_feminine: "две",
+    teen_instrumental: "ю",
+    declined_tens: DECLINED_TENS,
+    hundreds: HUNDREDS,
+    thousand: THOUSAND,
+    magnitudes: &MAGNITUDES,
+    magnitude_endings: MAGNITUDE_ENDINGS,
+    few_genitive: true,
+};
+
+/// Numbers in Russian (e.g. 123 -> "сто двадцать три").
+///
+/// Every word of a number is declined in its [`case`], and a final one or two agrees with the
+/// [`gender`] of the noun it counts. "Тысяча" and "миллион" and above agree with their count
+/// (e.g. "две тысячи", "пять тысяч", "два миллиона"), and [`noun_agreement`] gives the form
+/// of any other noun that follows the number.
+///
+/// [`case`]: #structfield.case
+/// [`gender`]: #structfield.gender
+/// [`noun_agreement`]: #method.noun_agreement
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Gender, GrammaticalCase, Russian};
+/// let russian = Russian::default();
+/// assert_eq!(number_to_words(&123, &russian), "сто двадцать три");
+/// assert_eq!(number_to_words(&2_000, &russian), "две тысячи");
+/// assert_eq!(number_to_words(&5_000_000, &russian), "пять миллионов");
+///
+/// let genitive = Russian {
+///     gender: Gender::Feminine,
+///     case: GrammaticalCase::Genitive,
+/// };
+/// assert_eq!(number_to_words(&21, &genitive), "двадцати одной");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Russian {
+    /// The gender of the noun being counted, which a final one or two agrees with
+    /// (e.g. "один", "одна", "одно", "две").
+    pub gender: Gender,
+    /// The case the number is declined in (e.g. "двух" in the genitive).
+    pub case: GrammaticalCase,
+}
+
+impl Russian {
+    /// The plural category of a number, which depends only on its last two digits
+    /// (e.g. 21 is [`One`], 23 is [`Few`], and 11 and 25 are [`Many`]).
+    ///
+    /// [`One`]: enum.PluralCategory.html#variant.One
+    /// [`Few`]: enum.PluralCategory.html#variant.Few
+    /// [`Many`]: enum.PluralCategory.html#variant.Many
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{PluralCategory, Russian};
+    /// assert_eq!(Russian::plural_category(21), PluralCategory::One);
+    /// assert_eq!(Russian::plural_category(12), PluralCategory::Many);
+    /// ```
+    pub fn plural_category(number: u128) -> PluralCategory {
+        slavic::plural_category(number)
+    }
+
+    /// The form of a noun counted by a number in the [`case`] of the number. In the nominative
+    /// and accusative, a noun after a few is genitive singular and after many is genitive plural;
+    /// in the other cases, it is plural in the same case.
+    ///
+    /// [`case`]: #structfield.case
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{GrammaticalCase, NounAgreement, Russian};
+    /// assert_eq!(
+    ///     Russian::default().noun_agreement(5),
+    ///     NounAgreement {
+    ///         case: GrammaticalCase::Genitive,
+    ///         plural: true,
+    ///     }
+    /// );
+    /// ```
+    pub fn noun_agreement(&self, number: u128) -> NounAgreement {
+        slavic::noun_agreement(&RUSSIAN, self.case, number)
+    }
+}
+
+impl Language for Russian {
+    fn zero(&self) -> &str {
+        "ноль"
+    }
+
+    fn negative(&self) -> &str {
+        "минус"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "запятая"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "сто"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str> {
+        slavic::declined_magnitude(&RUSSIAN, self.case, magnitude, group)
+    }
+
+    fn write_below_hundred<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        slavic::write_hundreds_as(&RUSSIAN, out, number, self.gender, self.case)
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        slavic::write_hundreds_as(&RUSSIAN, out, number, self.gender, self.case)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        slavic::write_group(&RUSSIAN, out, group, magnitude, self.gender, self.case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Gender, GrammaticalCase, NounAgreement, Russian};
+
+    #[test]
+    fn test_russian() {
+        let russian = Russian::default();
+        for (number, words) in [
+            (0_i64, "ноль"),
+            (1, "один"),
+            (12, "двенадцать"),
+            (21, "двадцать один"),
+            (40, "сорок"),
+            (100, "сто"),
+            (123, "сто двадцать три"),
+            (999, "девятьсот девяносто девять"),
+            (1_000, "одна тысяча"),
+            (2_000, "две тысячи"),
+            (5_000, "пять тысяч"),
+            (11_000, "одиннадцать тысяч"),
+            (21_000, "двадцать одна тысяча"),
+            (1_000_000, "один миллион"),
+            (3_000_000, "три миллиона"),
+            (5_000_000, "пять миллионов"),
+            (2_000_000_000, "два миллиарда"),
+            (
+                1_234_567,
+                "один миллион двести тридцать четыре тысячи пятьсот шестьдесят семь",
+            ),
+            (-3, "минус три"),
+        ] {
+            assert_eq!(number_to_words(&number, &russian), words);
+        }
+        assert_eq!(number_to_words(&1.5, &russian), "один запятая пять");
+        for (number, words) in [
+            (1e36, "одна тысяча дециллионов"),
+            (2e36, "две тысячи дециллионов"),
+            (5e36, "пять тысяч дециллионов"),
+            (1e39, "один миллион дециллионов"),
+        ] {
+            assert_eq!(number_to_words(&number, &russian), words);
+        }
+
+        let feminine = Russian {
+            gender: Gender::Feminine,
+            ..Default::default()
+        };
+        assert_eq!(number_to_words(&2, &feminine), "две");
+        assert_eq!(number_to_words(&31, &feminine), "тридцать одна");
+        let neuter = Russian {
+            gender: Gender::Neuter,
+            ..Default::default()
+        };
+        assert_eq!(number_to_words(&1, &neuter), "одно");
+        assert_eq!(number_to_words(&2, &neuter), "два");
+
+        for (case, two_thousand, words) in [
+            (GrammaticalCase::Genitive, "двух тысяч", "пятисот восьми"),
+            (GrammaticalCase::Dative, "двум тысячам", "пятистам восьми"),
+            (GrammaticalCase::Accusative, "две тысячи", "пятьсот восемь"),
+            (
+                GrammaticalCase::Instrumental,
+                "двумя тысячами",
+                "пятьюстами восемью",
+            ),
+            (GrammaticalCase::Locative, "двух тысячах", "пятистах восьми"),
+        ] {
+            let declined = Russian {
+                case,
+                ..Default::default()
+            };
+            assert_eq!(number_to_words(&2_000, &declined), two_thousand);
+            assert_eq!(number_to_words(&508, &declined), words);
+        }
+        let accusative = Russian {
+            case: GrammaticalCase::Accusative,
+            ..Default::default()
+        };
+        assert_eq!(number_to_words(&1_000, &accusative), "одну тысячу");
+        let instrumental = Russian {
+            case: GrammaticalCase::Instrumental,
+            ..Default::default()
+        };
+        assert_eq!(
+            number_to_words(&1_000_000, &instrumental),
+            "одним миллионом"
+        );
+
+        assert_eq!(
+            russian.noun_agreement(3),
+            NounAgreement {
+                case: GrammaticalCase::Genitive,
+                plural: false,
+            }
+        );
+        assert_eq!(
+            instrumental.noun_agreement(5),
+            NounAgreement {
+                case: GrammaticalCase::Instrumental,
+                plural: true,
+            }
+        );
+    }
+}
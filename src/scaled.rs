@@ -0,0 +1,261 @@
+use crate::{convert_number_to_english, Error, Options};
+use alloc::string::{String, ToString};
+use num_bigint::{BigInt, Sign};
+
+/// A decimal number stored as an integer mantissa and a scale, such as the parts of a `rust_decimal::Decimal`.
+///
+/// The value is `mantissa / 10^scale`. Every digit of the scale is read, so trailing zeros are kept
+/// (e.g. a mantissa of 150 with a scale of 2 is "one and fifty hundredths"). A negative scale
+/// multiplies the mantissa by a power of ten instead.
+///
+/// # Examples
+/// ```
+/// use num2english::ScaledDecimal;
+/// assert_eq!(ScaledDecimal::new(150, 2).to_english(), "one and fifty hundredths");
+/// assert_eq!(ScaledDecimal::new(-5, 1).to_english(), "negative five tenths");
+/// assert_eq!(ScaledDecimal::new(12, -3).to_english(), "twelve thousand");
+/// ```
+///
+/// A `rust_decimal::Decimal` can be converted from its parts:
+/// ```ignore
+/// let decimal = rust_decimal::Decimal::new(150, 2);
+/// let scaled = ScaledDecimal::new(decimal.mantissa(), decimal.scale() as i64);
+/// assert_eq!(scaled.to_english(), "one and fifty hundredths");
+/// ```
+///
+/// So can a `bigdecimal::BigDecimal`, whose exponent is a scale:
+/// ```ignore
+/// let decimal: bigdecimal::BigDecimal = "1.5e-60".parse().unwrap();
+/// let (mantissa, scale) = decimal.as_bigint_and_exponent();
+/// assert_eq!(
+///     ScaledDecimal::new(mantissa, scale).to_english(),
+///     "fifteen ten-novemdecillionths"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ScaledDecimal {
+    /// The digits of the number as an integer.
+    pub mantissa: BigInt,
+    /// The number of those digits after the decimal point.
+    pub scale: i64,
+}
+
+impl ScaledDecimal {
+    /// Create a decimal from a mantissa and a scale.
+    pub fn new(mantissa: impl Into<BigInt>, scale: i64) -> Self {
+        ScaledDecimal {
+            mantissa: mantissa.into(),
+            scale,
+        }
+    }
+
+    /// Create the exact decimal value of a binary fixed-point number, such as the bits of a `fixed::FixedI64`.
+    ///
+    /// The value is `bits / 2^fractional_bits`, which always has a finite decimal expansion.
+    /// Trailing zeros of the expansion are dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::ScaledDecimal;
+    /// assert_eq!(ScaledDecimal::from_fixed(0x18, 4).to_english(), "one and five tenths");
+    /// assert_eq!(
+    ///     ScaledDecimal::from_fixed(-1, 8).to_english(),
+    ///     "negative three hundred ninety thousand six hundred twenty-five hundred-millionths"
+    /// );
+    /// ```
+    ///
+    /// A `fixed` number can be converted from its bits:
+    /// ```ignore
+    /// let number = fixed::types::I16F16::from_num(2.75);
+    /// let scaled = ScaledDecimal::from_fixed(number.to_bits(), 16);
+    /// assert_eq!(scaled.to_english(), "two and seventy-five hundredths");
+    /// ```
+    pub fn from_fixed(bits: impl Into<BigInt>, fractional_bits: u32) -> Self {
+        ScaledDecimal::from_halves(bits.into(), fractional_bits)
+    }
+
+    /// Create the exact decimal value of `mantissa * 2^exponent`, such as the parts of a `rug::Float`.
+    ///
+    /// Trailing zeros of the expansion are dropped.
+    ///
+    /// # Errors
+    /// Returns [`Error::MagnitudeOverflow`] if the exponent is outside the range of a `u32` in
+    /// either direction.
+    ///
+    /// [`Error::MagnitudeOverflow`]: enum.Error.html#variant.MagnitudeOverflow
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{Error, ScaledDecimal};
+    /// assert_eq!(ScaledDecimal::from_binary(3, 4).unwrap().to_english(), "forty-eight");
+    /// assert_eq!(
+    ///     ScaledDecimal::from_binary(3, -2).unwrap().to_english(),
+    ///     "seventy-five hundredths"
+    /// );
+    /// assert_eq!(ScaledDecimal::from_binary(1, -(1 << 40)), Err(Error::MagnitudeOverflow));
+    /// ```
+    ///
+    /// A `rug::Float` can be converted from its integer and exponent:
+    /// ```ignore
+    /// let float = rug::Float::with_val(64, 2.5);
+    /// let (mantissa, exponent) = float.to_integer_exp().unwrap();
+    /// let mantissa: BigInt = mantissa.to_string().parse().unwrap();
+    /// let scaled = ScaledDecimal::from_binary(mantissa, exponent as i64).unwrap();
+    /// assert_eq!(scaled.to_english(), "two and five tenths");
+    /// ```
+    pub fn from_binary(mantissa: impl Into<BigInt>, exponent: i64) -> Result<Self, Error> {
+        let mantissa = mantissa.into();
+        let power = u32::try_from(exponent.unsigned_abs()).map_err(|_| Error::MagnitudeOverflow)?;
+        if exponent >= 0 {
+            return Ok(ScaledDecimal {
+                mantissa: mantissa << power as usize,
+                scale: 0,
+            });
+        }
+        Ok(ScaledDecimal::from_halves(mantissa, power))
+    }
+
+    /// Create the exact decimal value of `mantissa / 2^power`.
+    fn from_halves(mantissa: BigInt, power: u32) -> Self {
+        // m / 2^n is the same as m * 5^n / 10^n
+        let mut mantissa = mantissa * BigInt::from(5).pow(power);
+        let mut scale = power as i64;
+        let ten = BigInt::from(10);
+        while scale > 0 && (mantissa.clone() % ten.clone()) == BigInt::from(0) {
+            mantissa /= ten.clone();
+            scale -= 1;
+        }
+        ScaledDecimal { mantissa, scale }
+    }
+
+    /// Convert the decimal to its English representation.
+    pub fn to_english(&self) -> String {
+        self.to_english_with(&Options::default())
+    }
+
+    /// Convert the decimal to its English representation using the given [`Options`].
+    ///
+    /// [`Options`]: struct.Options.html
+    pub fn to_english_with(&self, options: &Options) -> String {
+        options
+            .case
+            .apply(&convert_scaled_to_english(self, options))
+    }
+}
+
+/// Convert a scaled decimal to its name in English (e.g. 150, 2 -> "one and fifty hundredths")
+pub(crate) fn convert_scaled_to_english(decimal: &ScaledDecimal, options: &Options) -> String {
+    let mut result = String::new();
+    let mut mantissa = decimal.mantissa.clone();
+    if let Sign::Minus = mantissa.sign() {
+        result.push_str(options.negative_word);
+        result.push(' ');
+        mantissa = -mantissa;
+    }
+
+    result.push_str(&convert_number_to_english(
+        to_plain_string(mantissa, decimal.scale),
+        options,
+    ));
+    result
+}
+
+/// Write a non-negative mantissa and scale in positional notation, keeping trailing zeros (e.g. 150, 2 -> "1.50")
+fn to_plain_string(mantissa: BigInt, scale: i64) -> String {
+    let mut digits = mantissa.to_string();
+    if scale <= 0 {
+        if mantissa != BigInt::from(0) {
+            for _ in 0..scale.unsigned_abs() {
+                digits.push('0');
+            }
+        }
+        return digits;
+    }
+
+    let scale = scale as usize;
+    if digits.len() <= scale {
+        let mut padded = String::with_capacity(scale + 1);
+        for _ in digits.len()..=scale {
+            padded.push('0');
+        }
+        padded.push_str(&digits);
+        digits = padded;
+    }
+    digits.insert(digits.len() - scale, '.');
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DecimalStyle, Error, Options, ScaledDecimal};
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_scaled_decimal() {
+        assert_eq!(ScaledDecimal::new(0, 2).to_english(), "zero");
+        assert_eq!(ScaledDecimal::new(100, 2).to_english(), "one");
+        assert_eq!(ScaledDecimal::new(5, 0).to_english(), "five");
+        assert_eq!(ScaledDecimal::new(7, 3).to_english(), "seven thousandths");
+        assert_eq!(
+            ScaledDecimal::new(-123_450, 3).to_english(),
+            "negative one hundred twenty-three and four hundred fifty thousandths"
+        );
+        assert_eq!(
+            ScaledDecimal::new(10_i128.pow(28) + 1, 28).to_english(),
+            "one and one ten-octillionth"
+        );
+
+        assert_eq!(
+            ScaledDecimal::new(15, 61).to_english(),
+            "fifteen ten-novemdecillionths"
+        );
+        assert_eq!(
+            ScaledDecimal::new(1, 52).to_english(),
+            "one ten-sexdecillionth"
+        );
+        assert_eq!(
+            ScaledDecimal::new(BigInt::from(10).pow(100) + 1, 100).to_english(),
+            "one and one ten-duotrigintillionth"
+        );
+
+        assert_eq!(
+            ScaledDecimal::from_binary(0, 100).unwrap().to_english(),
+            "zero"
+        );
+        assert_eq!(
+            ScaledDecimal::from_binary(-5, -1).unwrap().to_english(),
+            "negative two and five tenths"
+        );
+        assert_eq!(
+            ScaledDecimal::from_binary(1, i64::MIN),
+            Err(Error::MagnitudeOverflow)
+        );
+        assert_eq!(
+            ScaledDecimal::from_binary(1, 1 << 32),
+            Err(Error::MagnitudeOverflow)
+        );
+        assert_eq!(ScaledDecimal::from_fixed(0, 16).to_english(), "zero");
+        assert_eq!(ScaledDecimal::from_fixed(3 << 16, 16).to_english(), "three");
+        assert_eq!(ScaledDecimal::from_fixed(1, 2), ScaledDecimal::new(25, 2));
+        assert_eq!(
+            ScaledDecimal::from_fixed(u64::MAX, 64).to_english_with(&Options {
+                decimal_style: DecimalStyle::Mixed,
+                ..Default::default()
+            }),
+            "eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four \
+             trillion seventy-three billion seven hundred nine million five hundred fifty-one \
+             thousand six hundred fifteen eighteen quintillion four hundred forty-six \
+             quadrillion seven hundred forty-four trillion seventy-three billion seven hundred \
+             nine million five hundred fifty-one thousand six hundred sixteenths"
+        );
+
+        let point = Options {
+            decimal_style: DecimalStyle::Point,
+            ..Default::default()
+        };
+        assert_eq!(
+            ScaledDecimal::new(150, 2).to_english_with(&point),
+            "one point five zero"
+        );
+    }
+}
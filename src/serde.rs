@@ -0,0 +1,133 @@
+//! Helpers for writing number fields as English words with serde, and reading them back.
+//!
+//! # Examples
+//! ```ignore
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Report {
+//!     #[serde(with = "num2english::serde")]
+//!     count: u32,
+//! }
+//! let json = serde_json::to_string(&Report { count: 42 }).unwrap();
+//! assert_eq!(json, r#"{"count":"forty-two"}"#);
+//! let report: Report = serde_json::from_str(&json).unwrap();
+//! assert_eq!(report.count, 42);
+//! ```
+
+use crate::{parse::parse_english_decimal, NumberToEnglish};
+use ::serde::{
+    de::{Error, Unexpected, Visitor},
+    Deserializer, Serializer,
+};
+use core::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    str::FromStr,
+};
+use num_traits::Num;
+
+/// Serialize a number as its name in English, for use with `#[serde(serialize_with = "...")]`.
+///
+/// # Errors
+/// Returns an error if the serializer fails to write the string.
+///
+/// # Examples
+/// ```
+/// use core::fmt;
+/// struct Words(u32);
+/// impl fmt::Display for Words {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         // a formatter is a serializer that writes strings as they are
+///         num2english::serde::serialize(&self.0, f)
+///     }
+/// }
+/// assert_eq!(Words(42).to_string(), "forty-two");
+/// ```
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Num + Display,
+    S: Serializer,
+{
+    serializer.serialize_str(&value.to_english())
+}
+
+/// Deserialize a number from its name in English, for use with `#[serde(deserialize_with = "...")]`.
+///
+/// The name is read into a decimal number, which is then parsed as the field's type, so
+/// "negative two and five tenths" can fill an `f64` field but not an `i32` one. Names are read
+/// as the default [`Options`] write them, with the decimal part as a fraction ("five tenths")
+/// or digit by digit ("point five").
+///
+/// # Errors
+/// Returns an error if the value is not a string naming a number of the field's type.
+///
+/// # Examples
+/// ```
+/// use serde::de::{value, IntoDeserializer};
+/// let words: value::StrDeserializer<value::Error> = "one thousand and six".into_deserializer();
+/// assert_eq!(num2english::serde::deserialize::<_, u32>(words), Ok(1_006));
+/// ```
+///
+/// [`Options`]: ../struct.Options.html
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    deserializer.deserialize_str(WordsVisitor(PhantomData))
+}
+
+/// A visitor that reads a number from its name in English.
+struct WordsVisitor<T>(PhantomData<T>);
+
+impl<T: FromStr> Visitor<'_> for WordsVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("the name of a number in English")
+    }
+
+    fn visit_str<E: Error>(self, words: &str) -> Result<T, E> {
+        parse_english_decimal(words)
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(words), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deserialize, serialize};
+    use alloc::string::ToString;
+    use core::fmt;
+    use serde::de::{value, IntoDeserializer};
+
+    struct Words<T>(T);
+
+    impl<T: num_traits::Num + fmt::Display> fmt::Display for Words<T> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            serialize(&self.0, f)
+        }
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(Words(0).to_string(), "zero");
+        assert_eq!(Words(-7.5).to_string(), "negative seven and five tenths");
+        assert_eq!(Words(1_000_000_u64).to_string(), "one million");
+    }
+
+    #[test]
+    fn test_deserialize() {
+        fn words<T: core::str::FromStr>(words: &str) -> Result<T, value::Error> {
+            deserialize(IntoDeserializer::<value::Error>::into_deserializer(words))
+        }
+        assert_eq!(words::<u8>("forty-two"), Ok(42));
+        assert_eq!(words::<i64>("Negative One Million"), Ok(-1_000_000));
+        assert_eq!(words::<f64>("negative two and five tenths"), Ok(-2.5));
+        assert_eq!(words::<f32>("zero point two five"), Ok(0.25));
+        assert!(words::<i32>("two and five tenths").is_err());
+        assert!(words::<u8>("three hundred").is_err());
+        assert!(words::<u8>("forty two").is_err());
+        assert!(words::<u8>("42").is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{Gender, GrammaticalCase, NounAgreement, PluralCategory};
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{self, Write};
+
+/// The declined words of an East Slavic language, with each case in the order of
+/// [`GrammaticalCase`] up to the locative.
+///
+/// [`GrammaticalCase`]: enum.GrammaticalCase.html
+pub(crate) struct Declensions {
+    /// The nominative masculine names of the numbers from one to nineteen.
+    pub(crate) units: [&'static str; 19],
+    /// The masculine names of one to ten in each case.
+    pub(crate) declined_units: [[&'static str; 6]; 10],
+    /// The feminine and neuter names of one in each case.
+    pub(crate) one: [[&'static str; 6]; 2],
+    /// The feminine name of two in the nominative and accusative.
+    pub(crate) two_feminine: &'static str,
+    /// The ending of eleven to nineteen in the instrumental, after their nominative.
+    pub(crate) teen_instrumental: &'static str,
+    /// The names of the tens in each case.
+    pub(crate) declined_tens: [[&'static str; 6]; 9],
+    /// The names of the hundreds in each case.
+    pub(crate) hundreds: [[&'static str; 6]; 9],
+    /// The singular and plural forms of a thousand in each case.
+    pub(crate) thousand: [[&'static str; 6]; 2],
+    /// The names of each power of one thousand above the thousands, in the short scale.
+    pub(crate) magnitudes: &'static [&'static str],
+    /// The singular and plural endings of the magnitudes in each case.
+    pub(crate) magnitude_endings: [[&'static str; 6]; 2],
+    /// Whether a noun after a few is genitive singular in the nominative (e.g. Russian "две
+    /// тысячи"), rather than nominative plural (e.g. Ukrainian "дві тисячі").
+    pub(crate) few_genitive: bool,
+}
+
+/// The column of a case in the declension tables. Numbers in the vocative take their
+/// nominative forms.
+fn case_index(case: GrammaticalCase) -> usize {
+    match case {
+        GrammaticalCase::Vocative => 0,
+        _ => case as usize,
+    }
+}
+
+/// The plural category of a number in Russian and Ukrainian, which depends only on its last two
+/// digits (e.g. 21 is one, 23 is a few, and 11 and 25 are many).
+pub(crate) fn plural_category(number: u128) -> PluralCategory {
+    match (number % 10, number % 100) {
+        (_, 11..=14) => PluralCategory::Many,
+        (1, _) => PluralCategory::One,
+        (2..=4, _) => PluralCategory::Few,
+        _ => PluralCategory::Many,
+    }
+}
+
+/// The form of a noun counted by a number in a case.
+pub(crate) fn noun_agreement(
+    words: &Declensions,
+    case: GrammaticalCase,
+    number: u128,
+) -> NounAgreement {
+    let direct = matches!(
+        case,
+        GrammaticalCase::Nominative | GrammaticalCase::Accusative | GrammaticalCase::Vocative
+    );
+    match plural_category(number) {
+        PluralCategory::One => NounAgreement {
+            case,
+            plural: false,
+        },
+        PluralCategory::Few if direct && words.few_genitive => NounAgreement {
+            case: GrammaticalCase::Genitive,
+            plural: false,
+        },
+        PluralCategory::Many if direct => NounAgreement {
+            case: GrammaticalCase::Genitive,
+            plural: true,
+        },
+        _ => NounAgreement { case, plural: true },
+    }
+}
+
+/// The name of a power of one thousand in a case, in the form that agrees with its count.
+pub(crate) fn declined_magnitude(
+    words: &Declensions,
+    case: GrammaticalCase,
+    magnitude: usize,
+    count: u128,
+) -> Cow<'static, str> {
+    let agreement = noun_agreement(words, case, count);
+    let (plural, column) = (agreement.plural as usize, case_index(agreement.case));
+    if magnitude == 1 {
+        return Cow::Borrowed(words.thousand[plural][column]);
+    }
+    let largest = words.magnitudes.len() + 1;
+    let Some(name) = words.magnitudes.get(magnitude - 2) else {
+        // past the named powers, the largest name is counted in the genitive plural
+        // (e.g. "тысяча дециллионов")
+        let mut name = String::from(declined_magnitude(words, case, magnitude - largest, count));
+        name.push(' ');
+        name.push_str(words.magnitudes[words.magnitudes.len() - 1]);
+        name.push_str(words.magnitude_endings[1][1]);
+        return Cow::Owned(name);
+    };
+    let mut name = String::from(*name);
+    name.push_str(words.magnitude_endings[plural][column]);
+    Cow::Owned(name)
+}
+
+/// The gender of the noun that a magnitude's count agrees with. The thousand is a feminine noun
+/// and the named magnitudes above it are masculine, while a magnitude past the named powers
+/// counts its first noun (e.g. "тысяча" in "тысяча дециллионов").
+fn magnitude_gender(words: &Declensions, magnitude: usize) -> Gender {
+    let largest = words.magnitudes.len() + 1;
+    match magnitude {
+        1 => Gender::Feminine,
+        _ if magnitude > largest => magnitude_gender(words, magnitude - largest),
+        _ => Gender::Masculine,
+    }
+}
+
+/// Write a number from one to nine hundred ninety-nine in a case, agreeing with a gender.
+pub(crate) fn write_hundreds_as<W: Write>(
+    words: &Declensions,
+    out: &mut W,
+    number: u64,
+    gender: Gender,
+    case: GrammaticalCase,
+) -> fmt::Result {
+    let column = case_index(case);
+    let hundreds = number / 100;
+    let rest = number % 100;
+    let mut parts = [""; 3];
+    if hundreds > 0 {
+        parts[0] = words.hundreds[hundreds as usize - 1][column];
+    }
+    let unit = match rest {
+        10..=19 => rest,
+        _ => {
+            if rest >= 20 {
+                parts[1] = words.declined_tens[rest as usize / 10 - 1][column];
+            }
+            rest % 10
+        }
+    };
+    let teen;
+    parts[2] = match (unit, gender) {
+        (0, _) => "",
+        (1, Gender::Feminine) => words.one[0][column],
+        (1, Gender::Neuter) => words.one[1][column],
+        (2, Gender::Feminine) if matches!(column, 0 | 3) => words.two_feminine,
+        (1..=10, _) => words.declined_units[unit as usize - 1][column],
+        // eleven to nineteen end in a soft sign (e.g. "одиннадцать" -> "одиннадцати")
+        _ => {
+            let nominative = words.units[unit as usize - 1];
+            teen = match column {
+                0 | 3 => String::from(nominative),
+                4 => {
+                    let mut name = String::from(nominative);
+                    name.push_str(words.teen_instrumental);
+                    name
+                }
+                _ => {
+                    let mut name = String::from(nominative.trim_end_matches('ь'));
+                    name.push('и');
+                    name
+                }
+            };
+            &teen
+        }
+    };
+
+    let mut first = true;
+    for part in parts.iter().filter(|part| !part.is_empty()) {
+        if !first {
+            out.write_char(' ')?;
+        }
+        out.write_str(part)?;
+        first = false;
+    }
+    Ok(())
+}
+
+/// Write a nonzero group in a case, followed by the name of its magnitude. The group agrees with
+/// the gender of the magnitude's head noun.
+pub(crate) fn write_group<W: Write>(
+    words: &Declensions,
+    out: &mut W,
+    group: u128,
+    magnitude: usize,
+    gender: Gender,
+    case: GrammaticalCase,
+) -> fmt::Result {
+    let gender = match magnitude {
+        0 => gender,
+        _ => magnitude_gender(words, magnitude),
+    };
+    write_hundreds_as(words, out, group as u64, gender, case)?;
+    if magnitude > 0 {
+        out.write_char(' ')?;
+        out.write_str(&declined_magnitude(words, case, magnitude, group))?;
+    }
+    Ok(())
+}
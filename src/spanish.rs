@@ -0,0 +1,320 @@
+use crate::{Gender, Language};
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{self, Write};
+
+/// The names of the numbers from one to twenty-nine, which Spanish writes as single words.
+const UNITS: [&str; 29] = [
+    "uno",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+
+/// The names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "diez",
+    "veinte",
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+
+/// The masculine names of the hundreds from one hundred to nine hundred, with more below them.
+const HUNDREDS: [&str; 9] = [
+    "ciento",
+    "doscientos",
+    "trescientos",
+    "cuatrocientos",
+    "quinientos",
+    "seiscientos",
+    "setecientos",
+    "ochocientos",
+    "novecientos",
+];
+
+/// The names of each power of one million, in the long scale.
+const MAGNITUDES: [&str; 10] = [
+    "millón",
+    "billón",
+    "trillón",
+    "cuatrillón",
+    "quintillón",
+    "sextillón",
+    "septillón",
+    "octillón",
+    "nonillón",
+    "decillón",
+];
+
+/// Numbers in Spanish (e.g. 123 -> "ciento veintitrés", 1200 -> "mil doscientos").
+///
+/// Large numbers are named in the long scale (e.g. 10^9 is "mil millones", 10^12 is "un billón").
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Gender, Spanish};
+/// assert_eq!(number_to_words(&1_200, &Spanish::default()), "mil doscientos");
+/// assert_eq!(number_to_words(&21_000, &Spanish::default()), "veintiún mil");
+///
+/// let feminine = Spanish {
+///     gender: Gender::Feminine,
+///     ..Default::default()
+/// };
+/// assert_eq!(number_to_words(&201, &feminine), "doscientas una");
+///
+/// let apocope = Spanish {
+///     apocope: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(number_to_words(&21, &apocope), "veintiún");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Spanish {
+    /// The gender of the noun being counted, which the units and hundreds agree with
+    /// (e.g. "una", "doscientas").
+    pub gender: Gender,
+    /// Shorten a final "uno" to "un", as before a masculine noun (e.g. "veintiún libros").
+    pub apocope: bool,
+}
+
+impl Spanish {
+    /// Write a number from one to nine hundred ninety-nine, agreeing with a gender.
+    fn write_hundreds_as<W: Write>(
+        &self,
+        out: &mut W,
+        number: u64,
+        feminine: bool,
+        apocope: bool,
+    ) -> fmt::Result {
+        let hundreds = number / 100;
+        let rest = number % 100;
+        if number == 100 {
+            return out.write_str("cien");
+        }
+        if hundreds > 0 {
+            let name = HUNDREDS[hundreds as usize - 1];
+            match name.strip_suffix("os").filter(|_| feminine) {
+                Some(stem) => {
+                    out.write_str(stem)?;
+                    out.write_str("as")?;
+                }
+                None => out.write_str(name)?,
+            }
+            if rest > 0 {
+                out.write_char(' ')?;
+            }
+        }
+        if rest == 0 {
+            return Ok(());
+        }
+
+        // a final one agrees with the gender, or is shortened before a noun (e.g. "una", "un")
+        let one = if feminine {
+            "una"
+        } else if apocope {
+            "un"
+        } else {
+            "uno"
+        };
+        match rest {
+            1 => out.write_str(one),
+            21 if feminine => out.write_str("veintiuna"),
+            21 if apocope => out.write_str("veintiún"),
+            2..=29 => out.write_str(UNITS[rest as usize - 1]),
+            _ => {
+                out.write_str(TENS[rest as usize / 10 - 1])?;
+                match rest % 10 {
+                    0 => Ok(()),
+                    1 => write!(out, " y {}", one),
+                    unit => write!(out, " y {}", UNITS[unit as usize - 1]),
+                }
+            }
+        }
+    }
+}
+
+impl Language for Spanish {
+    fn zero(&self) -> &str {
+        "cero"
+    }
+
+    fn negative(&self) -> &str {
+        "menos"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "coma"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "cien"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str> {
+        let Some(name) = MAGNITUDES.get(magnitude - 1) else {
+            // past the named powers, the group counts decillions, which agree with the group
+            // (e.g. "un millón de decillones", "dos billones de decillones")
+            let mut name = self
+                .magnitude_name(magnitude - MAGNITUDES.len(), group)
+                .into_owned();
+            name.push_str(" de ");
+            name.push_str(&self.magnitude_name(MAGNITUDES.len(), 2));
+            return Cow::Owned(name);
+        };
+        if group == 1 {
+            Cow::Borrowed(name)
+        } else {
+            // "millón" -> "millones"
+            let mut name = String::from(name.trim_end_matches("ón"));
+            name.push_str("ones");
+            Cow::Owned(name)
+        }
+    }
+
+    // each group is six digits, read as thousands within the group (e.g. "dos mil quinientos millones")
+    fn group_digits(&self, _magnitude: usize) -> u32 {
+        6
+    }
+
+    fn tens_separator(&self) -> &str {
+        " y "
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        let feminine = self.gender == Gender::Feminine;
+        self.write_hundreds_as(out, number, feminine, self.apocope)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        // the millions are masculine nouns (e.g. "doscientos millones", "veintiún millones")
+        let feminine = magnitude == 0 && self.gender == Gender::Feminine;
+        let thousands = (group / 1000) as u64;
+        let rest = (group % 1000) as u64;
+
+        if thousands > 0 {
+            // "mil" is never counted with "un" (e.g. "mil", "veintiún mil")
+            if thousands > 1 {
+                self.write_hundreds_as(out, thousands, feminine, true)?;
+                out.write_char(' ')?;
+            }
+            out.write_str("mil")?;
+            if rest > 0 {
+                out.write_char(' ')?;
+            }
+        }
+        if rest > 0 {
+            self.write_hundreds_as(out, rest, feminine, magnitude > 0 || self.apocope)?;
+        }
+        if magnitude > 0 {
+            out.write_char(' ')?;
+            out.write_str(&self.magnitude_name(magnitude, group))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Gender, Spanish};
+
+    #[test]
+    fn test_spanish() {
+        let spanish = Spanish::default();
+        for (number, words) in [
+            (0_i64, "cero"),
+            (1, "uno"),
+            (16, "dieciséis"),
+            (21, "veintiuno"),
+            (31, "treinta y uno"),
+            (99, "noventa y nueve"),
+            (100, "cien"),
+            (101, "ciento uno"),
+            (123, "ciento veintitrés"),
+            (500, "quinientos"),
+            (1_001, "mil uno"),
+            (1_200, "mil doscientos"),
+            (21_000, "veintiún mil"),
+            (101_000, "ciento un mil"),
+            (1_000_000, "un millón"),
+            (1_000_001, "un millón uno"),
+            (21_000_000, "veintiún millones"),
+            (2_500_000_000, "dos mil quinientos millones"),
+            (1_000_000_000_000, "un billón"),
+            (-7, "menos siete"),
+        ] {
+            assert_eq!(number_to_words(&number, &spanish), words);
+        }
+        assert_eq!(
+            number_to_words(&-1.05, &spanish),
+            "menos uno coma cero cinco"
+        );
+        assert_eq!(number_to_words(&10_u128.pow(36), &spanish), "un sextillón");
+        for (number, words) in [
+            (1e60, "un decillón"),
+            (1e66, "un millón de decillones"),
+            (2e66, "dos millones de decillones"),
+            (1e72, "un billón de decillones"),
+            (1e126, "un millón de decillones de decillones"),
+        ] {
+            assert_eq!(number_to_words(&number, &spanish), words);
+        }
+
+        let feminine = Spanish {
+            gender: Gender::Feminine,
+            apocope: false,
+        };
+        assert_eq!(number_to_words(&1, &feminine), "una");
+        assert_eq!(number_to_words(&21, &feminine), "veintiuna");
+        assert_eq!(number_to_words(&41, &feminine), "cuarenta y una");
+        assert_eq!(number_to_words(&200_000, &feminine), "doscientas mil");
+        assert_eq!(
+            number_to_words(&300_000_000, &feminine),
+            "trescientos millones"
+        );
+        assert_eq!(number_to_words(&1_100, &feminine), "mil cien");
+
+        let apocope = Spanish {
+            gender: Gender::Masculine,
+            apocope: true,
+        };
+        assert_eq!(number_to_words(&1, &apocope), "un");
+        assert_eq!(number_to_words(&51, &apocope), "cincuenta y un");
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{NumberToEnglish, Options};
+use alloc::string::String;
+use core::fmt::Display;
+use num_traits::Num;
+
+/// Marks the joins between groups while the words are built.
+const GROUP_MARK: &str = "\u{1}";
+
+/// How a number is marked up in SSML for text-to-speech.
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_ssml, SsmlStyle};
+/// let style = SsmlStyle {
+///     group_pause: "",
+///     speak: false,
+///     ..Default::default()
+/// };
+/// assert_eq!(number_to_ssml(&1_500, &style), "one thousand five hundred");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SsmlStyle {
+    /// The length of the pause between groups (e.g. "200ms"), or empty for no pause.
+    pub group_pause: &'static str,
+    /// Wrap the output in a `<speak>` element.
+    pub speak: bool,
+    /// The options used to read the number.
+    pub options: Options,
+}
+
+impl Default for SsmlStyle {
+    fn default() -> Self {
+        SsmlStyle {
+            group_pause: "200ms",
+            speak: true,
+            options: Options::default(),
+        }
+    }
+}
+
+/// Convert a number to its name in English marked up in SSML, with a `<break>` between groups.
+///
+/// The words are escaped for XML, so custom words from the [`Options`] may hold any text.
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_ssml, SsmlStyle};
+/// assert_eq!(
+///     number_to_ssml(&2_000_005, &SsmlStyle::default()),
+///     r#"<speak>two million <break time="200ms"/> five</speak>"#
+/// );
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub fn number_to_ssml<T: Num + Display>(number: &T, style: &SsmlStyle) -> String {
+    let mut options = style.options.clone();
+    if !style.group_pause.is_empty() {
+        options.group_separator = GROUP_MARK;
+    }
+    let words = number.to_english_with(&options);
+
+    let mut result = String::new();
+    if style.speak {
+        result.push_str("<speak>");
+    }
+    for (i, group) in words.split(GROUP_MARK).enumerate() {
+        if i > 0 {
+            result.push_str(" <break time=\"");
+            push_escaped(&mut result, style.group_pause);
+            result.push_str("\"/> ");
+        }
+        push_escaped(&mut result, group);
+    }
+    if style.speak {
+        result.push_str("</speak>");
+    }
+    result
+}
+
+/// Append text to a string, escaping the characters that are special in XML.
+fn push_escaped(result: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            _ => result.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_ssml, Options, SsmlStyle};
+
+    #[test]
+    fn test_ssml() {
+        let style = SsmlStyle::default();
+        assert_eq!(number_to_ssml(&0, &style), "<speak>zero</speak>");
+        assert_eq!(
+            number_to_ssml(&-1_234.5, &style),
+            "<speak>negative one thousand <break time=\"200ms\"/> two hundred thirty-four \
+             and five tenths</speak>"
+        );
+
+        let british = SsmlStyle {
+            group_pause: "1s",
+            speak: false,
+            options: Options {
+                british_and: true,
+                negative_word: "<minus>",
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            number_to_ssml(&-3_200_001, &british),
+            "&lt;minus&gt; three million <break time=\"1s\"/> two hundred thousand and one"
+        );
+        assert_eq!(
+            number_to_ssml(&-3_000_042, &british),
+            "&lt;minus&gt; three million and forty-two"
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use crate::Language;
+use alloc::{borrow::Cow, format, string::String};
+use core::fmt::{self, Write};
+
+/// The names of the numbers from one to nineteen, with one in its neuter form.
+const UNITS: [&str; 19] = [
+    "ett", "två", "tre", "fyra", "fem", "sex", "sju", "åtta", "nio", "tio", "elva", "tolv",
+    "tretton", "fjorton", "femton", "sexton", "sjutton", "arton", "nitton",
+];
+
+/// The names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "tio", "tjugo", "trettio", "fyrtio", "femtio", "sextio", "sjuttio", "åttio", "nittio",
+];
+
+/// The names of each power of one thousand above "tusen", in the long scale with "-iljard" names.
+const MAGNITUDES: [&str; 11] = [
+    "miljon",
+    "miljard",
+    "biljon",
+    "biljard",
+    "triljon",
+    "triljard",
+    "kvadriljon",
+    "kvadriljard",
+    "kvintiljon",
+    "kvintiljard",
+    "sextiljon",
+];
+
+/// Numbers in Swedish (e.g. 123 -> "etthundratjugotre").
+///
+/// Numbers below one million are written as one word, and "tusen" after "ett" drops a "t"
+/// (e.g. "ettusen", "tjugoettusen"). "Miljon" and above are separate words counted with "en"
+/// (e.g. "en miljon", "två miljoner").
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Swedish};
+/// assert_eq!(number_to_words(&123, &Swedish::default()), "etthundratjugotre");
+/// assert_eq!(number_to_words(&2_500, &Swedish::default()), "tvåtusenfemhundra");
+/// assert_eq!(number_to_words(&2_000_000, &Swedish::default()), "två miljoner");
+///
+/// let common = Swedish {
+///     common_gender: true,
+/// };
+/// assert_eq!(number_to_words(&21, &common), "tjugoen");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Swedish {
+    /// Write a final one as "en", as before a noun of common gender (e.g. "en bil",
+    /// "tjugoen bilar"), instead of the neuter "ett" used when counting.
+    pub common_gender: bool,
+}
+
+impl Swedish {
+    /// Write a number from one to nine hundred ninety-nine, with a final one written as `one`.
+    fn write_hundreds_as<W: Write>(&self, out: &mut W, number: u64, one: &str) -> fmt::Result {
+        let hundreds = number / 100;
+        let rest = number % 100;
+        if hundreds > 0 {
+            write!(out, "{}hundra", UNITS[hundreds as usize - 1])?;
+        }
+        let unit = match rest {
+            0..=19 => rest,
+            _ => {
+                out.write_str(TENS[rest as usize / 10 - 1])?;
+                rest % 10
+            }
+        };
+        match unit {
+            0 => Ok(()),
+            1 => out.write_str(one),
+            _ => out.write_str(UNITS[unit as usize - 1]),
+        }
+    }
+}
+
+impl Language for Swedish {
+    fn zero(&self) -> &str {
+        "noll"
+    }
+
+    fn negative(&self) -> &str {
+        "minus"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "komma"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "hundra"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str> {
+        if magnitude == 1 {
+            return Cow::Borrowed("tusen");
+        }
+        let largest = MAGNITUDES.len() + 1;
+        let name = match MAGNITUDES.get(magnitude - 2) {
+            Some(name) => *name,
+            // past the named powers, the largest name is counted (e.g. "tusen sextiljoner")
+            None => {
+                let inner = self.magnitude_name(magnitude - largest, group);
+                return Cow::Owned(format!("{} sextiljoner", inner));
+            }
+        };
+        if group == 1 {
+            Cow::Borrowed(name)
+        } else {
+            // "miljon" -> "miljoner", "miljard" -> "miljarder"
+            let mut name = String::from(name);
+            name.push_str("er");
+            Cow::Owned(name)
+        }
+    }
+
+    fn tens_separator(&self) -> &str {
+        ""
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        let one = if self.common_gender { "en" } else { "ett" };
+        self.write_hundreds_as(out, number, one)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        match magnitude {
+            0 => self.write_hundreds(out, group as u64),
+            1 => {
+                let mut count = String::new();
+                self.write_hundreds_as(&mut count, group as u64, "ett")?;
+                // "ett" and "tusen" share a "t" (e.g. "ettusen")
+                let name = if count.ends_with("tt") {
+                    "usen"
+                } else {
+                    "tusen"
+                };
+                write!(out, "{}{}", count, name)
+            }
+            // "miljon" and above are nouns of common gender (e.g. "en miljon", "tjugoen miljoner")
+            _ => {
+                self.write_hundreds_as(out, group as u64, "en")?;
+                write!(out, " {}", self.magnitude_name(magnitude, group))
+            }
+        }
+    }
+
+    fn write_group_separator<W: Write>(
+        &self,
+        out: &mut W,
+        above: usize,
+        _magnitude: usize,
+        _group: u128,
+    ) -> fmt::Result {
+        // the thousands are joined to the rest of the word (e.g. "tvåtusenfemhundra")
+        if above == 1 {
+            Ok(())
+        } else {
+            out.write_char(' ')
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Swedish};
+
+    #[test]
+    fn test_swedish() {
+        let swedish = Swedish::default();
+        for (number, words) in [
+            (0_i64, "noll"),
+            (1, "ett"),
+            (18, "arton"),
+            (21, "tjugoett"),
+            (100, "etthundra"),
+            (123, "etthundratjugotre"),
+            (1_000, "ettusen"),
+            (1_001, "ettusenett"),
+            (2_500, "tvåtusenfemhundra"),
+            (21_000, "tjugoettusen"),
+            (1_000_000, "en miljon"),
+            (2_000_000, "två miljoner"),
+            (2_300_000, "två miljoner trehundratusen"),
+            (21_000_000, "tjugoen miljoner"),
+            (1_000_000_000, "en miljard"),
+            (-3, "minus tre"),
+        ] {
+            assert_eq!(number_to_words(&number, &swedish), words);
+        }
+        assert_eq!(number_to_words(&2.5, &swedish), "två komma fem");
+        assert_eq!(
+            number_to_words(&(10_u128.pow(36) * 4), &swedish),
+            "fyra sextiljoner"
+        );
+
+        let common = Swedish {
+            common_gender: true,
+        };
+        assert_eq!(number_to_words(&1, &common), "en");
+        assert_eq!(number_to_words(&21, &common), "tjugoen");
+        assert_eq!(number_to_words(&1_001, &common), "ettusenen");
+    }
+}
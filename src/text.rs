@@ -0,0 +1,475 @@
+use crate::{
+    check_expandable, convert_non_finite_to_english, convert_number_to_english,
+    currency::convert_currency_to_english,
+    decimal::{multiply_add_digits, Decimal},
+    expand_scientific, ordinal_words, Case, Currency, Error, Options,
+};
+use alloc::{string::String, vec::Vec};
+
+/// Convert a decimal number written as text to its name in English.
+///
+/// The text is read exactly as written, so numbers held as strings (e.g. from CSV or JSON)
+/// keep all of their digits, including trailing zeros after the decimal point. Text that is not a
+/// number is reported as an error rather than read as some other number, as by [`convert_str`].
+///
+/// # Examples
+/// ```
+/// use num2english::{Error, StrToEnglish};
+/// assert_eq!("60".to_english(), Ok("sixty".into()));
+/// assert_eq!("1.50".to_english(), Ok("one and fifty hundredths".into()));
+/// assert_eq!(
+///     "12345678901234567890.000001".to_english(),
+///     Ok("twelve quintillion three hundred forty-five quadrillion six hundred seventy-eight \
+///         trillion nine hundred one billion two hundred thirty-four million five hundred \
+///         sixty-seven thousand eight hundred ninety and one millionth"
+///         .into())
+/// );
+/// assert_eq!("12abc".to_english(), Err(Error::InvalidNumber));
+/// ```
+///
+/// [`convert_str`]: fn.convert_str.html
+pub trait StrToEnglish {
+    /// Convert the number to its English representation.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`convert_str`].
+    ///
+    /// [`convert_str`]: fn.convert_str.html
+    fn to_english(&self) -> Result<String, Error>;
+
+    /// Convert the number to its English representation using the given [`Options`].
+    ///
+    /// # Errors
+    /// Returns the same errors as [`convert_str`].
+    ///
+    /// [`Options`]: struct.Options.html
+    /// [`convert_str`]: fn.convert_str.html
+    fn to_english_with(&self, options: &Options) -> Result<String, Error>;
+}
+
+impl StrToEnglish for str {
+    fn to_english(&self) -> Result<String, Error> {
+        self.to_english_with(&Options::default())
+    }
+
+    fn to_english_with(&self, options: &Options) -> Result<String, Error> {
+        convert_str(self, options)
+    }
+}
+
+/// Convert a decimal number written as text to English using the given [`Options`],
+/// checking that the text is a well-formed number.
+///
+/// The number may have a sign, a decimal point and an exponent (e.g. "-1.5e3"), and may be
+/// surrounded by whitespace. Its integer part may be split into groups of three digits by one of
+/// the options' thousands separators, and digits may be separated by underscores as in Rust
+/// literals (e.g. "1_000_000"). Integers with a "0x", "0o" or "0b" prefix are read in base 16,
+/// 8 or 2 (e.g. "0xFF" is "two hundred fifty-five"). Infinities and NaN are named as they are for floats.
+///
+/// # Errors
+/// Returns [`Error::InvalidNumber`] if the text is not a number, and
+/// [`Error::MagnitudeOverflow`] if its exponent puts it more than ten thousand digits from the
+/// decimal point (e.g. "1e1000000").
+///
+/// # Examples
+/// ```
+/// use num2english::{convert_str, Error, Options};
+/// assert_eq!(
+///     convert_str("1.50", &Options::default()),
+///     Ok("one and fifty hundredths".into())
+/// );
+/// assert_eq!(
+///     convert_str("1,234", &Options::default()),
+///     Ok("one thousand two hundred thirty-four".into())
+/// );
+/// assert_eq!(convert_str("12abc", &Options::default()), Err(Error::InvalidNumber));
+/// ```
+///
+/// [`Options`]: struct.Options.html
+/// [`Error::InvalidNumber`]: enum.Error.html#variant.InvalidNumber
+/// [`Error::MagnitudeOverflow`]: enum.Error.html#variant.MagnitudeOverflow
+pub fn convert_str(number: &str, options: &Options) -> Result<String, Error> {
+    let number = parse_input(number, options)?;
+    Ok(options
+        .case
+        .apply(&convert_number_to_english(number, options)))
+}
+
+/// Convert a decimal number written as text to an amount of money in English using the given
+/// [`Options`], rounded to the currency's minor unit.
+///
+/// The text is read exactly as by [`convert_str`], so amounts too large for a float keep all of
+/// their digits.
+///
+/// # Errors
+/// Returns the same errors as [`convert_str`].
+///
+/// # Examples
+/// ```
+/// use num2english::{convert_str_currency, Currency, Error, Options};
+/// assert_eq!(
+///     convert_str_currency("1,234.5", &Currency::USD, &Options::default()),
+///     Ok("one thousand two hundred thirty-four dollars and fifty cents".into())
+/// );
+/// assert_eq!(
+///     convert_str_currency("12abc", &Currency::USD, &Options::default()),
+///     Err(Error::InvalidNumber)
+/// );
+/// ```
+///
+/// [`Options`]: struct.Options.html
+/// [`convert_str`]: fn.convert_str.html
+pub fn convert_str_currency(
+    number: &str,
+    currency: &Currency,
+    options: &Options,
+) -> Result<String, Error> {
+    let number = parse_input(number, options)?;
+    Ok(options
+        .case
+        .apply(&convert_currency_to_english(&number, currency, options)))
+}
+
+/// Convert an integer written as text to its English name as an ordinal using the given
+/// [`Options`] (e.g. "21" -> "twenty-first").
+///
+/// The text is read as by [`convert_str`].
+///
+/// # Errors
+/// Returns the same errors as [`convert_str`], [`Error::NonFinite`] for infinities and NaN, and
+/// [`Error::InvalidNumber`] if the number is not an integer.
+///
+/// # Examples
+/// ```
+/// use num2english::{convert_str_ordinal, Error, Options};
+/// assert_eq!(
+///     convert_str_ordinal("1,000,021", &Options::default()),
+///     Ok("one million twenty-first".into())
+/// );
+/// assert_eq!(
+///     convert_str_ordinal("1.5", &Options::default()),
+///     Err(Error::InvalidNumber)
+/// );
+/// ```
+///
+/// [`Options`]: struct.Options.html
+/// [`convert_str`]: fn.convert_str.html
+/// [`Error::NonFinite`]: enum.Error.html#variant.NonFinite
+/// [`Error::InvalidNumber`]: enum.Error.html#variant.InvalidNumber
+pub fn convert_str_ordinal(number: &str, options: &Options) -> Result<String, Error> {
+    let number = parse_input(number, options)?;
+    if convert_non_finite_to_english(&number, options).is_some() {
+        return Err(Error::NonFinite);
+    }
+    // only whole numbers have an ordinal (e.g. not "one and five tenthsth")
+    let decimal = Decimal::parse(&number).ok_or(Error::InvalidNumber)?;
+    if decimal.exponent < 0 {
+        return Err(Error::InvalidNumber);
+    }
+    // the suffix is added to the lowercase words before the case is applied
+    let lower = Options {
+        case: Case::Lower,
+        ..options.clone()
+    };
+    let words = convert_number_to_english(number, &lower);
+    Ok(options.case.apply(&ordinal_words(&words)))
+}
+
+/// Check that a number written as text is well formed and write it in plain positional notation.
+fn parse_input(number: &str, options: &Options) -> Result<String, Error> {
+    let number = normalize_input(number, options)?;
+    if convert_non_finite_to_english(&number, options).is_none() {
+        let decimal = Decimal::parse(&number).ok_or(Error::InvalidNumber)?;
+        check_expandable(&decimal)?;
+    }
+    Ok(expand_scientific(number))
+}
+
+/// Trim a number written as text and remove its leading plus sign and thousands separators.
+fn normalize_input(number: &str, options: &Options) -> Result<String, Error> {
+    let number = number.trim();
+    let number = number.strip_prefix('+').unwrap_or(number);
+    let number = strip_underscores(number)?;
+    if let Some(number) = convert_radix_prefix(&number)? {
+        return Ok(number);
+    }
+    strip_thousands_separators(&number, options.thousands_separators)
+}
+
+/// Rewrite an integer with a "0x", "0o" or "0b" prefix in decimal (e.g. "0xFF" -> "255").
+///
+/// Returns `None` if the number has no radix prefix.
+fn convert_radix_prefix(number: &str) -> Result<Option<String>, Error> {
+    let (negative, unsigned) = match number.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, number),
+    };
+    let radix = match unsigned.get(..2) {
+        Some("0x" | "0X") => 16,
+        Some("0o" | "0O") => 8,
+        Some("0b" | "0B") => 2,
+        _ => return Ok(None),
+    };
+
+    let digits = &unsigned[2..];
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(Error::InvalidNumber);
+    }
+    let mut number = String::from("0");
+    for digit in digits.chars().filter_map(|c| c.to_digit(radix)) {
+        number = multiply_add_digits(&number, radix, digit);
+    }
+    if negative && number != "0" {
+        number.insert(0, '-');
+    }
+    Ok(Some(number))
+}
+
+/// Remove underscores written between digits as in Rust literals (e.g. "1_000_000" -> "1000000").
+///
+/// As in Rust, an underscore may follow a digit, a letter of a radix prefix or hexadecimal
+/// digit, or another underscore, but not a sign or a point.
+fn strip_underscores(number: &str) -> Result<String, Error> {
+    let mut result = String::with_capacity(number.len());
+    let mut previous: Option<char> = None;
+    for c in number.chars() {
+        if c == '_' {
+            if !matches!(previous, Some(p) if p == '_' || p.is_ascii_alphanumeric()) {
+                return Err(Error::InvalidNumber);
+            }
+        } else {
+            result.push(c);
+        }
+        previous = Some(c);
+    }
+    Ok(result)
+}
+
+/// Remove the thousands separators from the integer part of a number, checking that they
+/// split it into groups of three digits (e.g. "1,234,567.89" -> "1234567.89").
+fn strip_thousands_separators(number: &str, separators: &[char]) -> Result<String, Error> {
+    let (sign, unsigned) = match number.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", number),
+    };
+    let end = unsigned.find(['.', 'e', 'E']).unwrap_or(unsigned.len());
+    let (integer, rest) = unsigned.split_at(end);
+    let Some(separator) = integer.chars().find(|c| separators.contains(c)) else {
+        return Ok(String::from(number));
+    };
+    if rest.contains(separators) {
+        return Err(Error::InvalidNumber);
+    }
+
+    let groups = integer.split(separator).collect::<Vec<&str>>();
+    let valid = groups.iter().enumerate().all(|(i, group)| {
+        let size_ok = if i == 0 {
+            (1..=3).contains(&group.len())
+        } else {
+            group.len() == 3
+        };
+        size_ok && group.bytes().all(|b| b.is_ascii_digit())
+    });
+    if !valid {
+        return Err(Error::InvalidNumber);
+    }
+
+    let mut result = String::with_capacity(number.len());
+    result.push_str(sign);
+    for group in groups {
+        result.push_str(group);
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        convert_str, convert_str_currency, convert_str_ordinal, Case, Currency, DecimalStyle,
+        Error, Options, StrToEnglish,
+    };
+
+    #[test]
+    fn test_str() {
+        assert_eq!("0".to_english(), Ok("zero".into()));
+        assert_eq!(" 42 ".to_english(), Ok("forty-two".into()));
+        assert_eq!("+7".to_english(), Ok("seven".into()));
+        assert_eq!(
+            "-12.5".to_english(),
+            Ok("negative twelve and five tenths".into())
+        );
+        assert_eq!("1.5e3".to_english(), Ok("one thousand five hundred".into()));
+        assert_eq!(".25".to_english(), Ok("twenty-five hundredths".into()));
+        // malformed text is an error, not a made-up number
+        for number in ["12abc", "1.2.3", "1,23", "--5", ""] {
+            assert_eq!(number.to_english(), Err(Error::InvalidNumber), "{}", number);
+        }
+
+        let options = Options::default();
+        assert_eq!(convert_str("-0", &options), Ok("zero".into()));
+        assert_eq!(
+            convert_str(" +3.5 ", &options),
+            Ok("three and five tenths".into())
+        );
+        assert_eq!(
+            convert_str("-inf", &options),
+            Ok("negative infinity".into())
+        );
+        assert_eq!(convert_str("", &options), Err(Error::InvalidNumber));
+        assert_eq!(convert_str("-", &options), Err(Error::InvalidNumber));
+        assert_eq!(convert_str("1.2.3", &options), Err(Error::InvalidNumber));
+        assert_eq!(convert_str("1e", &options), Err(Error::InvalidNumber));
+        assert_eq!(convert_str("twelve", &options), Err(Error::InvalidNumber));
+
+        assert_eq!(
+            convert_str("1,234,567.89", &options),
+            Ok(
+                "one million two hundred thirty-four thousand five hundred sixty-seven and \
+                eighty-nine hundredths"
+                    .into()
+            )
+        );
+        assert_eq!(
+            convert_str("-12,000", &options),
+            Ok("negative twelve thousand".into())
+        );
+        assert_eq!(convert_str("1,23,456", &options), Err(Error::InvalidNumber));
+        assert_eq!(convert_str("1234,567", &options), Err(Error::InvalidNumber));
+        assert_eq!(convert_str(",123", &options), Err(Error::InvalidNumber));
+        assert_eq!(
+            convert_str("1,234.5,6", &options),
+            Err(Error::InvalidNumber)
+        );
+        assert_eq!(
+            "9,999".to_english(),
+            Ok("nine thousand nine hundred ninety-nine".into())
+        );
+
+        assert_eq!(convert_str("1_000_000", &options), Ok("one million".into()));
+        assert_eq!(
+            convert_str("3.14_159", &options),
+            Ok("three and fourteen thousand one hundred fifty-nine hundred-thousandths".into())
+        );
+        assert_eq!(convert_str("2e1_0", &options), Ok("twenty billion".into()));
+        assert_eq!(convert_str("_1", &options), Err(Error::InvalidNumber));
+        assert_eq!(convert_str("1._5", &options), Err(Error::InvalidNumber));
+        assert_eq!(convert_str("-_1", &options), Err(Error::InvalidNumber));
+
+        // huge exponents are not written out digit by digit
+        assert_eq!(
+            convert_str("1e1000000", &options),
+            Err(Error::MagnitudeOverflow)
+        );
+        assert_eq!(
+            convert_str("-2.5e-9223372036854775807", &options),
+            Err(Error::MagnitudeOverflow)
+        );
+        assert_eq!("1e1000000".to_english(), Err(Error::MagnitudeOverflow));
+        assert!(convert_str("1e10000", &options).is_ok());
+        for number in ["12e9223372036854775807", "10e9223372036854775807"] {
+            assert_eq!(convert_str(number, &options), Err(Error::MagnitudeOverflow));
+        }
+
+        assert_eq!(
+            convert_str("0xFF", &options),
+            Ok("two hundred fifty-five".into())
+        );
+        assert_eq!(convert_str("0b1010", &options), Ok("ten".into()));
+        assert_eq!(
+            convert_str("0o755", &options),
+            Ok("four hundred ninety-three".into())
+        );
+        assert_eq!(
+            convert_str("-0xDEAD_BEEF", &options),
+            Ok(
+                "negative three billion seven hundred thirty-five million nine hundred \
+                twenty-eight thousand five hundred fifty-nine"
+                    .into()
+            )
+        );
+        assert_eq!("0x1e5".to_english(), Ok("four hundred eighty-five".into()));
+        assert_eq!(convert_str("0x", &options), Err(Error::InvalidNumber));
+        assert_eq!(convert_str("0b102", &options), Err(Error::InvalidNumber));
+        assert_eq!(convert_str("0x1.8", &options), Err(Error::InvalidNumber));
+
+        let swiss = Options {
+            thousands_separators: &['\'', ' '],
+            ..Default::default()
+        };
+        assert_eq!(convert_str("1'000'000", &swiss), Ok("one million".into()));
+        assert_eq!(
+            convert_str("2 500", &swiss),
+            Ok("two thousand five hundred".into())
+        );
+        assert_eq!(convert_str("1,000", &swiss), Err(Error::InvalidNumber));
+
+        let point = Options {
+            decimal_style: DecimalStyle::Point,
+            ..Default::default()
+        };
+        assert_eq!(
+            "3.050".to_english_with(&point),
+            Ok("three point zero five zero".into())
+        );
+    }
+
+    #[test]
+    fn test_str_currency() {
+        let options = Options::default();
+        assert_eq!(
+            convert_str_currency("12345678901234567.89", &Currency::USD, &options),
+            Ok(
+                "twelve quadrillion three hundred forty-five trillion six hundred seventy-eight \
+                billion nine hundred one million two hundred thirty-four thousand five hundred \
+                sixty-seven dollars and eighty-nine cents"
+                    .into()
+            )
+        );
+        assert_eq!(
+            convert_str_currency("0.005", &Currency::EUR, &options),
+            Ok("one cent".into())
+        );
+        assert_eq!(
+            convert_str_currency("-1e-3", &Currency::USD, &options),
+            Ok("zero dollars".into())
+        );
+        assert_eq!(
+            convert_str_currency("1.2.3", &Currency::USD, &options),
+            Err(Error::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn test_str_ordinal() {
+        let options = Options::default();
+        assert_eq!(convert_str_ordinal("1", &options), Ok("first".into()));
+        assert_eq!(
+            convert_str_ordinal(" 12.0 ", &options),
+            Ok("twelfth".into())
+        );
+        assert_eq!(
+            convert_str_ordinal("-3e2", &options),
+            Ok("negative three hundredth".into())
+        );
+        assert_eq!(
+            convert_str_ordinal("0.5", &options),
+            Err(Error::InvalidNumber)
+        );
+        assert_eq!(convert_str_ordinal("inf", &options), Err(Error::NonFinite));
+        assert_eq!(
+            convert_str_ordinal("x", &options),
+            Err(Error::InvalidNumber)
+        );
+
+        let title = Options {
+            british_and: true,
+            case: Case::Title,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_str_ordinal("105", &title),
+            Ok("One Hundred and Fifth".into())
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{convert_hundreds_to_english, Error, Options};
+use alloc::string::String;
+
+/// How a time of day is read.
+///
+/// # Examples
+/// ```
+/// use num2english::{time_to_english, Clock, TimeStyle};
+/// let style = TimeStyle {
+///     clock: Clock::TwentyFourHour,
+///     relative: false,
+/// };
+/// assert_eq!(time_to_english(15, 45, &TimeStyle::default()), Ok("quarter to four".to_string()));
+/// assert_eq!(time_to_english(15, 45, &style), Ok("fifteen forty-five".to_string()));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TimeStyle {
+    /// The clock used to read the hour.
+    pub clock: Clock,
+    /// Read the minutes relative to the hour (e.g. "half past three", "ten to five").
+    /// Otherwise the hour and minutes are read as numbers (e.g. "three thirty").
+    pub relative: bool,
+}
+
+impl Default for TimeStyle {
+    fn default() -> Self {
+        TimeStyle {
+            clock: Clock::TwelveHour,
+            relative: true,
+        }
+    }
+}
+
+/// The clock used to read the hour of a time.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Clock {
+    /// Hours from one to twelve (e.g. "three forty-five").
+    #[default]
+    TwelveHour,
+    /// Hours from one to twelve followed by "a.m." or "p.m." (e.g. "three forty-five p.m.").
+    TwelveHourMeridiem,
+    /// Hours from zero to twenty-three (e.g. "fifteen forty-five").
+    TwentyFourHour,
+}
+
+/// Read a time of day, given as an hour from 0 to 23 and a minute from 0 to 59.
+///
+/// # Examples
+/// ```
+/// use num2english::{time_to_english, Clock, TimeStyle};
+/// let plain = TimeStyle {
+///     clock: Clock::TwelveHourMeridiem,
+///     relative: false,
+/// };
+/// assert_eq!(time_to_english(15, 30, &TimeStyle::default()), Ok("half past three".to_string()));
+/// assert_eq!(time_to_english(15, 45, &plain), Ok("three forty-five p.m.".to_string()));
+/// ```
+pub fn time_to_english(hour: u8, minute: u8, style: &TimeStyle) -> Result<String, Error> {
+    if hour > 23 || minute > 59 {
+        return Err(Error::InvalidTime);
+    }
+
+    let options = Options::default();
+    let words = |n: u8| convert_hundreds_to_english(u64::from(n), &options);
+
+    // relative times to the next hour name that hour (e.g. 3:45 -> "quarter to four")
+    let next_hour = style.relative && minute > 30;
+    let shown_hour = if next_hour { (hour + 1) % 24 } else { hour };
+    let hour_name = match style.clock {
+        // a relative hour zero is midnight (e.g. 23:45 -> "quarter to midnight")
+        Clock::TwentyFourHour if shown_hour == 0 && style.relative => String::from("midnight"),
+        Clock::TwentyFourHour if shown_hour == 0 => String::from(options.zero_word),
+        Clock::TwentyFourHour => words(shown_hour),
+        _ if shown_hour % 12 == 0 => String::from("twelve"),
+        _ => words(shown_hour % 12),
+    };
+
+    let mut result = String::new();
+    if style.relative {
+        match minute {
+            0 => {
+                result.push_str(&hour_name);
+                if hour_name != "midnight" {
+                    result.push_str(" o'clock");
+                }
+            }
+            15 => push_relative(&mut result, "quarter past ", &hour_name),
+            30 => push_relative(&mut result, "half past ", &hour_name),
+            45 => push_relative(&mut result, "quarter to ", &hour_name),
+            1..=29 => {
+                result.push_str(&minutes_to_english(minute, &options));
+                push_relative(&mut result, " past ", &hour_name);
+            }
+            _ => {
+                result.push_str(&minutes_to_english(60 - minute, &options));
+                push_relative(&mut result, " to ", &hour_name);
+            }
+        }
+    } else {
+        result.push_str(&hour_name);
+        match minute {
+            0 if style.clock == Clock::TwentyFourHour => result.push_str(" hundred"),
+            0 => result.push_str(" o'clock"),
+            1..=9 => {
+                result.push_str(" oh ");
+                result.push_str(&words(minute));
+            }
+            _ => {
+                result.push(' ');
+                result.push_str(&words(minute));
+            }
+        }
+    }
+
+    if style.clock == Clock::TwelveHourMeridiem {
+        result.push_str(if hour < 12 { " a.m." } else { " p.m." });
+    }
+
+    Ok(result)
+}
+
+/// Read a number of minutes for a relative time (e.g. 1 -> "one minute", 20 -> "twenty")
+fn minutes_to_english(minutes: u8, options: &Options) -> String {
+    let mut result = convert_hundreds_to_english(u64::from(minutes), options);
+    // only multiples of five are said without "minutes" (e.g. "twenty past", "seven minutes past")
+    if !minutes.is_multiple_of(5) {
+        result.push_str(if minutes == 1 { " minute" } else { " minutes" });
+    }
+    result
+}
+
+/// Push a relative phrase followed by the hour (e.g. "half past " + "three")
+fn push_relative(result: &mut String, phrase: &str, hour_name: &str) {
+    result.push_str(phrase);
+    result.push_str(hour_name);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{time_to_english, Clock, Error, TimeStyle};
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_relative_time() {
+        let style = TimeStyle::default();
+        let time = |hour, minute| time_to_english(hour, minute, &style).unwrap();
+        assert_eq!(time(15, 0), "three o'clock");
+        assert_eq!(time(15, 15), "quarter past three");
+        assert_eq!(time(15, 30), "half past three");
+        assert_eq!(time(16, 45), "quarter to five");
+        assert_eq!(time(9, 20), "twenty past nine");
+        assert_eq!(time(9, 7), "seven minutes past nine");
+        assert_eq!(time(9, 59), "one minute to ten");
+        assert_eq!(time(23, 50), "ten to twelve");
+        assert_eq!(time(0, 10), "ten past twelve");
+        assert_eq!(time_to_english(24, 0, &style), Err(Error::InvalidTime));
+        assert_eq!(time_to_english(1, 60, &style), Err(Error::InvalidTime));
+    }
+
+    #[test]
+    fn test_plain_time() {
+        let twelve = TimeStyle {
+            clock: Clock::TwelveHourMeridiem,
+            relative: false,
+        };
+        assert_eq!(
+            time_to_english(15, 45, &twelve),
+            Ok("three forty-five p.m.".to_string())
+        );
+        assert_eq!(
+            time_to_english(0, 5, &twelve),
+            Ok("twelve oh five a.m.".to_string())
+        );
+        assert_eq!(
+            time_to_english(12, 0, &twelve),
+            Ok("twelve o'clock p.m.".to_string())
+        );
+
+        let twenty_four = TimeStyle {
+            clock: Clock::TwentyFourHour,
+            relative: false,
+        };
+        assert_eq!(
+            time_to_english(18, 0, &twenty_four),
+            Ok("eighteen hundred".to_string())
+        );
+        assert_eq!(
+            time_to_english(0, 30, &twenty_four),
+            Ok("zero thirty".to_string())
+        );
+    }
+
+    #[test]
+    fn test_relative_twenty_four_hour_time() {
+        let style = TimeStyle {
+            clock: Clock::TwentyFourHour,
+            relative: true,
+        };
+        let time = |hour, minute| time_to_english(hour, minute, &style).unwrap();
+        assert_eq!(time(23, 45), "quarter to midnight");
+        assert_eq!(time(0, 0), "midnight");
+        assert_eq!(time(0, 10), "ten past midnight");
+        assert_eq!(time(15, 30), "half past fifteen");
+    }
+}
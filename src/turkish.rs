@@ -0,0 +1,162 @@
+use crate::Language;
+use alloc::{borrow::Cow, format};
+use core::fmt::{self, Write};
+
+/// The names of the numbers from one to nine.
+const UNITS: [&str; 9] = [
+    "bir", "iki", "üç", "dört", "beş", "altı", "yedi", "sekiz", "dokuz",
+];
+
+/// The names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "on", "yirmi", "otuz", "kırk", "elli", "altmış", "yetmiş", "seksen", "doksan",
+];
+
+/// The names of each power of one thousand above "bin", in the short scale.
+const MAGNITUDES: [&str; 10] = [
+    "milyon",
+    "milyar",
+    "trilyon",
+    "katrilyon",
+    "kentilyon",
+    "seksilyon",
+    "septilyon",
+    "oktilyon",
+    "nonilyon",
+    "desilyon",
+];
+
+/// Numbers in Turkish (e.g. 123 -> "yüz yirmi üç").
+///
+/// "Yüz" and "bin" are not counted with "bir" (e.g. "yüz", "bin", but "iki yüz", "yüz bir bin"),
+/// while "milyon" and above are (e.g. "bir milyon").
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, Turkish};
+/// assert_eq!(number_to_words(&123, &Turkish), "yüz yirmi üç");
+/// assert_eq!(number_to_words(&1_100, &Turkish), "bin yüz");
+/// assert_eq!(number_to_words(&1_000_000, &Turkish), "bir milyon");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Turkish;
+
+impl Language for Turkish {
+    fn zero(&self) -> &str {
+        "sıfır"
+    }
+
+    fn negative(&self) -> &str {
+        "eksi"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "virgül"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "yüz"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, _group: u128) -> Cow<'_, str> {
+        if magnitude == 1 {
+            return Cow::Borrowed("bin");
+        }
+        let largest = MAGNITUDES.len() + 1;
+        match MAGNITUDES.get(magnitude - 2) {
+            Some(name) => Cow::Borrowed(name),
+            // past the named powers, the largest name is counted (e.g. "bin desilyon")
+            None => {
+                let inner = self.magnitude_name(magnitude - largest, 1);
+                Cow::Owned(format!("{} desilyon", inner))
+            }
+        }
+    }
+
+    fn tens_separator(&self) -> &str {
+        " "
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        let hundreds = number / 100;
+        let rest = number % 100;
+        if hundreds > 0 {
+            if hundreds > 1 {
+                out.write_str(UNITS[hundreds as usize - 1])?;
+                out.write_char(' ')?;
+            }
+            out.write_str("yüz")?;
+            if rest > 0 {
+                out.write_char(' ')?;
+            }
+        }
+        if rest > 0 {
+            self.write_below_hundred(out, rest)?;
+        }
+        Ok(())
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        if magnitude == 0 {
+            return self.write_hundreds(out, group as u64);
+        }
+        // "bin" alone is one thousand, even when it counts a larger name (e.g. "bin", "iki bin",
+        // "bin desilyon")
+        let name = self.magnitude_name(magnitude, group);
+        if !(group == 1 && name.starts_with("bin")) {
+            self.write_hundreds(out, group as u64)?;
+            out.write_char(' ')?;
+        }
+        out.write_str(&name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Turkish};
+
+    #[test]
+    fn test_turkish() {
+        for (number, words) in [
+            (0_i64, "sıfır"),
+            (1, "bir"),
+            (10, "on"),
+            (23, "yirmi üç"),
+            (100, "yüz"),
+            (101, "yüz bir"),
+            (123, "yüz yirmi üç"),
+            (200, "iki yüz"),
+            (1_000, "bin"),
+            (1_100, "bin yüz"),
+            (2_000, "iki bin"),
+            (101_000, "yüz bir bin"),
+            (1_000_000, "bir milyon"),
+            (
+                1_234_567,
+                "bir milyon iki yüz otuz dört bin beş yüz altmış yedi",
+            ),
+            (3_000_000_000, "üç milyar"),
+            (-4, "eksi dört"),
+        ] {
+            assert_eq!(number_to_words(&number, &Turkish), words);
+        }
+        assert_eq!(number_to_words(&1.5, &Turkish), "bir virgül beş");
+        assert_eq!(
+            number_to_words(&(10_u128.pow(36) * 2), &Turkish),
+            "iki bin desilyon"
+        );
+        assert_eq!(number_to_words(&10_u128.pow(36), &Turkish), "bin desilyon");
+        assert_eq!(
+            number_to_words(&(10_u128.pow(36) + 10_u128.pow(33)), &Turkish),
+            "bin desilyon bir desilyon"
+        );
+    }
+}
@@ -0,0 +1,473 @@
+use crate::{
+    slavic::{self, Declensions},
+    Gender, GrammaticalCase, Language, NounAgreement, PluralCategory,
+};
+use alloc::borrow::Cow;
+use core::fmt::{self, Write};
+
+/// The nominative names of the numbers from one to nineteen.
+const UNITS: [&str; 19] = [
+    "один",
+    "два",
+    "три",
+    "чотири",
+    "п'ять",
+    "шість",
+    "сім",
+    "вісім",
+    "дев'ять",
+    "десять",
+    "одинадцять",
+    "дванадцять",
+    "тринадцять",
+    "чотирнадцять",
+    "п'ятнадцять",
+    "шістнадцять",
+    "сімнадцять",
+    "вісімнадцять",
+    "дев'ятнадцять",
+];
+
+/// The nominative names of the tens from ten to ninety.
+const TENS: [&str; 9] = [
+    "десять",
+    "двадцять",
+    "тридцять",
+    "сорок",
+    "п'ятдесят",
+    "шістдесят",
+    "сімдесят",
+    "вісімдесят",
+    "дев'яносто",
+];
+
+/// The masculine names of one to ten in each case, in the order of [`GrammaticalCase`].
+///
+/// [`GrammaticalCase`]: enum.GrammaticalCase.html
+const DECLINED_UNITS: [[&str; 6]; 10] = [
+    ["один", "одного", "одному", "один", "одним", "одному"],
+    ["два", "двох", "двом", "два", "двома", "двох"],
+    ["три", "трьох", "трьом", "три", "трьома", "трьох"],
+    [
+        "чотири",
+        "чотирьох",
+        "чотирьом",
+        "чотири",
+        "чотирма",
+        "чотирьох",
+    ],
+    ["п'ять", "п'яти", "п'яти", "п'ять", "п'ятьма", "п'яти"],
+    ["шість", "шести", "шести", "шість", "шістьма", "шести"],
+    ["сім", "семи", "семи", "сім", "сьома", "семи"],
+    ["вісім", "восьми", "восьми", "вісім", "вісьма", "восьми"],
+    [
+        "дев'ять",
+        "дев'яти",
+        "дев'яти",
+        "дев'ять",
+        "дев'ятьма",
+        "дев'яти",
+    ],
+    ["десять", "десяти", "десяти", "десять", "десятьма", "десяти"],
+];
+
+/// The feminine and neuter names of one in each case.
+const ONE: [[&str; 6]; 2] = [
+    ["одна", "однієї", "одній", "одну", "однією", "одній"],
+    ["одне", "одного", "одному", "одне", "одним", "одному"],
+];
+
+/// The names of the tens in each case.
+const DECLINED_TENS: [[&str; 6]; 9] = [
+    ["десять", "десяти", "десяти", "десять", "десятьма", "десяти"],
+    [
+        "двадцять",
+        "двадцяти",
+        "двадцяти",
+        "двадцять",
+        "двадцятьма",
+        "двадцяти",
+    ],
+    [
+        "тридцять",
+        "тридцяти",
+        "тридцяти",
+        "тридцять",
+        "тридцятьма",
+        "тридцяти",
+    ],
+    ["сорок", "сорока", "сорока", "сорок", "сорока", "сорока"],
+    [
+        "п'ятдесят",
+        "п'ятдесяти",
+        "п'ятдесяти",
+        "п'ятдесят",
+        "п'ятдесятьма",
+        "п'ятдесяти",
+    ],
+    [
+        "шістдесят",
+        "шістдесяти",
+        "шістдесяти",
+        "шістдесят",
+        "шістдесятьма",
+        "шістдесяти",
+    ],
+    [
+        "сімдесят",
+        "сімдесяти",
+        "сімдесяти",
+        "сімдесят",
+        "сімдесятьма",
+        "сімдесяти",
+    ],
+    [
+        "вісімдесят",
+        "вісімдесяти",
+        "вісімдесяти",
+        "вісімдесят",
+        "вісімдесятьма",
+        "вісімдесяти",
+    ],
+    [
+        "дев'яносто",
+        "дев'яноста",
+        "дев'яноста",
+        "дев'яносто",
+        "дев'яноста",
+        "дев'яноста",
+    ],
+];
+
+/// The names of the hundreds in each case.
+const HUNDREDS: [[&str; 6]; 9] = [
+    ["сто", "ста", "ста", "сто", "ста", "ста"],
+    [
+        "двісті",
+        "двохсот",
+        "двомстам",
+        "двісті",
+        "двомастами",
+        "двохстах",
+    ],
+    [
+        "триста",
+        "трьохсот",
+        "трьомстам",
+        "триста",
+        "трьомастами",
+        "трьохстах",
+    ],
+    [
+        "чотириста",
+        "чотирьохсот",
+        "чотирьомстам",
+        "чотириста",
+        "чотирмастами",
+        "чотирьохстах",
+    ],
+    [
+        "п'ятсот",
+        "п'ятисот",
+        "п'ятистам",
+        "п'ятсот",
+        "п'ятьмастами",
+        "п'ятистах",
+    ],
+    [
+        "шістсот",
+        "шестисот",
+        "шестистам",
+        "шістсот",
+        "шістьмастами",
+        "шестистах",
+    ],
+    [
+        "сімсот",
+        "семисот",
+        "семистам",
+        "сімсот",
+        "сьомастами",
+        "семистах",
+    ],
+    [
+        "вісімсот",
+        "восьмисот",
+        "восьмистам",
+        "вісімсот",
+        "вісьмастами",
+        "восьмистах",
+    ],
+    [
+        "дев'ятсот",
+        "дев'ятисот",
+        "дев'ятистам",
+        "дев'ятсот",
+        "дев'ятьмастами",
+        "дев'ятистах",
+    ],
+];
+
+/// The singular and plural forms of "тисяча" in each case.
+const THOUSAND: [[&str; 6]; 2] = [
+    ["тисяча", "тисячі", "тисячі", "тисячу", "тисячею", "тисячі"],
+    [
+        "тисячі",
+        "тисяч",
+        "тисячам",
+        "тисячі",
+        "тисячами",
+        "тисячах",
+    ],
+];
+
+/// The names of each power of one thousand above "тисяча", in the short scale.
+const MAGNITUDES: [&str; 10] = [
+    "мільйон",
+    "мільярд",
+    "трильйон",
+    "квадрильйон",
+    "квінтильйон",
+    "секстильйон",
+    "септильйон",
+    "октильйон",
+    "нонільйон",
+    "децильйон",
+];
+
+/// The singular and plural endings of "мільйон" and above in each case.
+const MAGNITUDE_ENDINGS: [[&str; 6]; 2] = [
+    ["", "а", "у", "", "ом", "і"],
+    ["и", "ів", "ам", "и", "ами", "ах"],
+];
+
+/// The declined words of Ukrainian.
+const UKRAINIAN: Declensions = Declensions {
+    units: UNITS,
+    declined_units: DECLINED_UNITS,
+    one: ONE,
+    two_feminine: "дві",
+    teen_instrumental: "ма",
+    declined_tens: DECLINED_TENS,
+    hundreds: HUNDREDS,
+    thousand: THOUSAND,
+    magnitudes: &MAGNITUDES,
+    magnitude_endings: MAGNITUDE_ENDINGS,
+    few_genitive: false,
+};
+
+/// Numbers in Ukrainian (e.g. 123 -> "сто двадцять три").
+///
+/// Every word of a number is declined in its [`case`], and a final one or two agrees with the
+/// [`gender`] of the noun it counts. "Тисяча" and "мільйон" and above agree with their count
+/// (e.g. "дві тисячі", "п'ять тисяч", "два мільйони"), and [`noun_agreement`] gives the form
+/// of any other noun that follows the number.
+///
+/// [`case`]: #structfield.case
+/// [`gender`]: #structfield.gender
+/// [`noun_agreement`]: #method.noun_agreement
+///
+/// # Examples
+/// ```
+/// use num2english::{number_to_words, GrammaticalCase, Ukrainian};
+/// let ukrainian = Ukrainian::default();
+/// assert_eq!(number_to_words(&123, &ukrainian), "сто двадцять три");
+/// assert_eq!(number_to_words(&2_000, &ukrainian), "дві тисячі");
+/// assert_eq!(number_to_words(&5_000_000, &ukrainian), "п'ять мільйонів");
+///
+/// let instrumental = Ukrainian {
+///     case: GrammaticalCase::Instrumental,
+///     ..Default::default()
+/// };
+/// assert_eq!(number_to_words(&300, &instrumental), "трьомастами");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Ukrainian {
+    /// The gender of the noun being counted, which a final one or two agrees with
+    /// (e.g. "один", "одна", "одне", "дві").
+    pub gender: Gender,
+    /// The case the number is declined in (e.g. "двох" in the genitive).
+    pub case: GrammaticalCase,
+}
+
+impl Ukrainian {
+    /// The plural category of a number, which depends only on its last two digits
+    /// (e.g. 21 is [`One`], 23 is [`Few`], and 11 and 25 are [`Many`]).
+    ///
+    /// [`One`]: enum.PluralCategory.html#variant.One
+    /// [`Few`]: enum.PluralCategory.html#variant.Few
+    /// [`Many`]: enum.PluralCategory.html#variant.Many
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{PluralCategory, Ukrainian};
+    /// assert_eq!(Ukrainian::plural_category(34), PluralCategory::Few);
+    /// assert_eq!(Ukrainian::plural_category(14), PluralCategory::Many);
+    /// ```
+    pub fn plural_category(number: u128) -> PluralCategory {
+        slavic::plural_category(number)
+    }
+
+    /// The form of a noun counted by a number in the [`case`] of the number. In the nominative
+    /// and accusative, a noun after a few is nominative plural and after many is genitive plural;
+    /// in the other cases, it is plural in the same case.
+    ///
+    /// [`case`]: #structfield.case
+    ///
+    /// # Examples
+    /// ```
+    /// use num2english::{GrammaticalCase, NounAgreement, Ukrainian};
+    /// assert_eq!(
+    ///     Ukrainian::default().noun_agreement(3),
+    ///     NounAgreement {
+    ///         case: GrammaticalCase::Nominative,
+    ///         plural: true,
+    ///     }
+    /// );
+    /// ```
+    pub fn noun_agreement(&self, number: u128) -> NounAgreement {
+        slavic::noun_agreement(&UKRAINIAN, self.case, number)
+    }
+}
+
+impl Language for Ukrainian {
+    fn zero(&self) -> &str {
+        "нуль"
+    }
+
+    fn negative(&self) -> &str {
+        "мінус"
+    }
+
+    fn decimal_point(&self) -> &str {
+        "кома"
+    }
+
+    fn units(&self) -> &[&str] {
+        &UNITS
+    }
+
+    fn tens(&self) -> &[&str] {
+        &TENS
+    }
+
+    fn hundred(&self) -> &str {
+        "сто"
+    }
+
+    fn magnitude_name(&self, magnitude: usize, group: u128) -> Cow<'_, str> {
+        slavic::declined_magnitude(&UKRAINIAN, self.case, magnitude, group)
+    }
+
+    fn write_below_hundred<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        slavic::write_hundreds_as(&UKRAINIAN, out, number, self.gender, self.case)
+    }
+
+    fn write_hundreds<W: Write>(&self, out: &mut W, number: u64) -> fmt::Result {
+        slavic::write_hundreds_as(&UKRAINIAN, out, number, self.gender, self.case)
+    }
+
+    fn write_group<W: Write>(&self, out: &mut W, group: u128, magnitude: usize) -> fmt::Result {
+        slavic::write_group(&UKRAINIAN, out, group, magnitude, self.gender, self.case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{number_to_words, Gender, GrammaticalCase, NounAgreement, Ukrainian};
+
+    #[test]
+    fn test_ukrainian() {
+        let ukrainian = Ukrainian::default();
+        for (number, words) in [
+            (0_i64, "нуль"),
+            (1, "один"),
+            (11, "одинадцять"),
+            (21, "двадцять один"),
+            (40, "сорок"),
+            (123, "сто двадцять три"),
+            (200, "двісті"),
+            (1_000, "одна тисяча"),
+            (2_000, "дві тисячі"),
+            (5_000, "п'ять тисяч"),
+            (12_000, "дванадцять тисяч"),
+            (21_000, "двадцять одна тисяча"),
+            (1_000_000, "один мільйон"),
+            (3_000_000, "три мільйони"),
+            (5_000_000, "п'ять мільйонів"),
+            (2_000_000_000, "два мільярди"),
+            (
+                1_234_567,
+                "один мільйон двісті тридцять чотири тисячі п'ятсот шістдесят сім",
+            ),
+            (-3, "мінус три"),
+        ] {
+            assert_eq!(number_to_words(&number, &ukrainian), words);
+        }
+        assert_eq!(number_to_words(&2.5, &ukrainian), "два кома п'ять");
+        for (number, words) in [
+            (1e36, "одна тисяча децильйонів"),
+            (2e36, "дві тисячі децильйонів"),
+            (5e36, "п'ять тисяч децильйонів"),
+            (1e39, "один мільйон децильйонів"),
+        ] {
+            assert_eq!(number_to_words(&number, &ukrainian), words);
+        }
+
+        let feminine = Ukrainian {
+            gender: Gender::Feminine,
+            ..Default::default()
+        };
+        assert_eq!(number_to_words(&2, &feminine), "дві");
+        let neuter = Ukrainian {
+            gender: Gender::Neuter,
+            ..Default::default()
+        };
+        assert_eq!(number_to_words(&1, &neuter), "одне");
+
+        for (case, two_thousand, words) in [
+            (GrammaticalCase::Genitive, "двох тисяч", "п'ятисот восьми"),
+            (GrammaticalCase::Dative, "двом тисячам", "п'ятистам восьми"),
+            (GrammaticalCase::Accusative, "дві тисячі", "п'ятсот вісім"),
+            (
+                GrammaticalCase::Instrumental,
+                "двома тисячами",
+                "п'ятьмастами вісьма",
+            ),
+            (
+                GrammaticalCase::Locative,
+                "двох тисячах",
+                "п'ятистах восьми",
+            ),
+            (GrammaticalCase::Vocative, "дві тисячі", "п'ятсот вісім"),
+        ] {
+            let declined = Ukrainian {
+                case,
+                ..Default::default()
+            };
+            assert_eq!(number_to_words(&2_000, &declined), two_thousand);
+            assert_eq!(number_to_words(&508, &declined), words);
+        }
+        let genitive = Ukrainian {
+            case: GrammaticalCase::Genitive,
+            ..Default::default()
+        };
+        assert_eq!(number_to_words(&15, &genitive), "п'ятнадцяти");
+        assert_eq!(number_to_words(&1_000_000, &genitive), "одного мільйона");
+
+        assert_eq!(
+            ukrainian.noun_agreement(5),
+            NounAgreement {
+                case: GrammaticalCase::Genitive,
+                plural: true,
+            }
+        );
+        assert_eq!(
+            genitive.noun_agreement(3),
+            NounAgreement {
+                case: GrammaticalCase::Genitive,
+                plural: true,
+            }
+        );
+    }
+}
@@ -0,0 +1,256 @@
+//! JavaScript bindings for converting numbers written as text to English.
+//!
+//! Build a WebAssembly module with
+//! `cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib`
+//! and generate its JavaScript glue with `wasm-bindgen` (or `wasm-pack build --features wasm`).
+//! Numbers are passed as strings, so any size or precision is read exactly:
+//!
+//! ```js
+//! import { EnglishOptions, toEnglish, toEnglishCurrency, toEnglishOrdinal } from "num2english";
+//!
+//! const options = new EnglishOptions();
+//! options.britishAnd = true;
+//! options.case = "title";
+//!
+//! toEnglish("105", options); // "One Hundred and Five"
+//! toEnglishOrdinal("21", options); // "Twenty-First"
+//! toEnglishCurrency("1.5", "EUR", options); // "One Euro and Fifty Cents"
+//! ```
+//!
+//! Functions and setters throw an `Error` with the message of the [`Error`] for invalid input.
+//!
+//! [`Error`]: ../enum.Error.html
+
+use crate::{
+    convert_str, convert_str_currency, convert_str_ordinal, Case, Currency, DecimalStyle, Options,
+    Rounding, Scale,
+};
+use alloc::{format, string::String};
+use wasm_bindgen::prelude::*;
+
+/// The [`Options`] for converting a number, set field by field from JavaScript.
+///
+/// Options that are words or separators take one of a fixed set of values, listed on each
+/// setter.
+///
+/// [`Options`]: ../struct.Options.html
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone)]
+pub struct EnglishOptions {
+    options: Options,
+}
+
+#[wasm_bindgen]
+impl EnglishOptions {
+    /// Create the default options, which produce the same output as [`to_english`].
+    ///
+    /// [`to_english`]: ../trait.NumberToEnglish.html#tymethod.to_english
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> EnglishOptions {
+        EnglishOptions::default()
+    }
+
+    /// Set [`Options::british_and`].
+    ///
+    /// [`Options::british_and`]: ../struct.Options.html#structfield.british_and
+    #[wasm_bindgen(setter = britishAnd)]
+    pub fn set_british_and(&mut self, value: bool) {
+        self.options.british_and = value;
+    }
+
+    /// Set [`Options::negative_word`] to "negative" or "minus".
+    ///
+    /// [`Options::negative_word`]: ../struct.Options.html#structfield.negative_word
+    #[wasm_bindgen(setter = negativeWord)]
+    pub fn set_negative_word(&mut self, value: &str) -> Result<(), JsError> {
+        self.options.negative_word = choose("negativeWord", value, &["negative", "minus"])?;
+        Ok(())
+    }
+
+    /// Set [`Options::decimal_style`] to "fraction", "point" or "mixed".
+    ///
+    /// [`Options::decimal_style`]: ../struct.Options.html#structfield.decimal_style
+    #[wasm_bindgen(setter = decimalStyle)]
+    pub fn set_decimal_style(&mut self, value: &str) -> Result<(), JsError> {
+        self.options.decimal_style =
+            DecimalStyle::from_name(value).ok_or_else(|| unknown("decimalStyle", value))?;
+        Ok(())
+    }
+
+    /// Set [`Options::case`] to "lower", "title", "sentence" or "upper".
+    ///
+    /// [`Options::case`]: ../struct.Options.html#structfield.case
+    #[wasm_bindgen(setter = case)]
+    pub fn set_case(&mut self, value: &str) -> Result<(), JsError> {
+        self.options.case = Case::from_name(value).ok_or_else(|| unknown("case", value))?;
+        Ok(())
+    }
+
+    /// Set [`Options::compound_separator`] to "-" or " ".
+    ///
+    /// [`Options::compound_separator`]: ../struct.Options.html#structfield.compound_separator
+    #[wasm_bindgen(setter = compoundSeparator)]
+    pub fn set_compound_separator(&mut self, value: &str) -> Result<(), JsError> {
+        self.options.compound_separator = choose("compoundSeparator", value, &["-", " "])?;
+        Ok(())
+    }
+
+    /// Set [`Options::group_separator`] to " " or ", ".
+    ///
+    /// [`Options::group_separator`]: ../struct.Options.html#structfield.group_separator
+    #[wasm_bindgen(setter = groupSeparator)]
+    pub fn set_group_separator(&mut self, value: &str) -> Result<(), JsError> {
+        self.options.group_separator = choose("groupSeparator", value, &[" ", ", "])?;
+        Ok(())
+    }
+
+    /// Set [`Options::scale`] to "short", "long", "long-milliard" or "indian".
+    ///
+    /// [`Options::scale`]: ../struct.Options.html#structfield.scale
+    #[wasm_bindgen(setter = scale)]
+    pub fn set_scale(&mut self, value: &str) -> Result<(), JsError> {
+        self.options.scale = Scale::from_name(value).ok_or_else(|| unknown("scale", value))?;
+        Ok(())
+    }
+
+    /// Set [`Options::zero_word`] to "zero", "oh", "naught", "nought" or "nil".
+    ///
+    /// [`Options::zero_word`]: ../struct.Options.html#structfield.zero_word
+    #[wasm_bindgen(setter = zeroWord)]
+    pub fn set_zero_word(&mut self, value: &str) -> Result<(), JsError> {
+        self.options.zero_word = choose(
+            "zeroWord",
+            value,
+            &["zero", "oh", "naught", "nought", "nil"],
+        )?;
+        Ok(())
+    }
+
+    /// Set [`Options::informal_article`].
+    ///
+    /// [`Options::informal_article`]: ../struct.Options.html#structfield.informal_article
+    #[wasm_bindgen(setter = informalArticle)]
+    pub fn set_informal_article(&mut self, value: bool) {
+        self.options.informal_article = value;
+    }
+
+    /// Set [`Options::colloquial_hundreds`].
+    ///
+    /// [`Options::colloquial_hundreds`]: ../struct.Options.html#structfield.colloquial_hundreds
+    #[wasm_bindgen(setter = colloquialHundreds)]
+    pub fn set_colloquial_hundreds(&mut self, value: bool) {
+        self.options.colloquial_hundreds = value;
+    }
+
+    /// Set [`Options::colloquial_units`].
+    ///
+    /// [`Options::colloquial_units`]: ../struct.Options.html#structfield.colloquial_units
+    #[wasm_bindgen(setter = colloquialUnits)]
+    pub fn set_colloquial_units(&mut self, value: bool) {
+        self.options.colloquial_units = value;
+    }
+
+    /// Set [`Options::max_decimal_places`], or clear it with `undefined`.
+    ///
+    /// [`Options::max_decimal_places`]: ../struct.Options.html#structfield.max_decimal_places
+    #[wasm_bindgen(setter = maxDecimalPlaces)]
+    pub fn set_max_decimal_places(&mut self, value: Option<u32>) {
+        self.options.max_decimal_places = value.map(|places| places as usize);
+    }
+
+    /// Set [`Options::rounding`] to "half-up", "half-even" or "truncate".
+    ///
+    /// [`Options::rounding`]: ../struct.Options.html#structfield.rounding
+    #[wasm_bindgen(setter = rounding)]
+    pub fn set_rounding(&mut self, value: &str) -> Result<(), JsError> {
+        self.options.rounding =
+            Rounding::from_name(value).ok_or_else(|| unknown("rounding", value))?;
+        Ok(())
+    }
+}
+
+/// Convert a number written as text to English, as by [`convert_str`].
+///
+/// [`convert_str`]: ../fn.convert_str.html
+#[wasm_bindgen(js_name = toEnglish)]
+pub fn to_english(number: &str, options: &EnglishOptions) -> Result<String, JsError> {
+    convert_str(number, &options.options).map_err(JsError::from)
+}
+
+/// Convert an integer written as text to English as an ordinal, as by [`convert_str_ordinal`].
+///
+/// [`convert_str_ordinal`]: ../fn.convert_str_ordinal.html
+#[wasm_bindgen(js_name = toEnglishOrdinal)]
+pub fn to_english_ordinal(number: &str, options: &EnglishOptions) -> Result<String, JsError> {
+    convert_str_ordinal(number, &options.options).map_err(JsError::from)
+}
+
+/// Convert an amount of money written as text to English in the currency with the given code
+/// ("USD", "EUR" or "GBP"), as by [`convert_str_currency`].
+///
+/// [`convert_str_currency`]: ../fn.convert_str_currency.html
+#[wasm_bindgen(js_name = toEnglishCurrency)]
+pub fn to_english_currency(
+    number: &str,
+    currency: &str,
+    options: &EnglishOptions,
+) -> Result<String, JsError> {
+    let currency = Currency::from_code(currency).ok_or_else(|| unknown("currency", currency))?;
+    convert_str_currency(number, &currency, &options.options).map_err(JsError::from)
+}
+
+/// Find the static value among the choices for an option that is equal to the given one.
+fn choose(option: &str, value: &str, choices: &[&'static str]) -> Result<&'static str, JsError> {
+    choices
+        .iter()
+        .find(|&&choice| choice == value)
+        .copied()
+        .ok_or_else(|| unknown(option, value))
+}
+
+/// The error for a value that an option does not take.
+fn unknown(option: &str, value: &str) -> JsError {
+    JsError::new(&format!("unknown {} {:?}", option, value))
+}
+
+#[cfg(test)]
+mod tests {
+    // the error paths build JavaScript values, which only exist on wasm targets
+    use super::{to_english, to_english_currency, to_english_ordinal, EnglishOptions};
+
+    #[test]
+    fn test_wasm_options() {
+        let mut options = EnglishOptions::new();
+        assert_eq!(
+            to_english("1234.5", &options).ok(),
+            Some("one thousand two hundred thirty-four and five tenths".into())
+        );
+
+        options.set_british_and(true);
+        assert!(options.set_case("title").is_ok());
+        assert!(options.set_decimal_style("point").is_ok());
+        assert!(options.set_negative_word("minus").is_ok());
+        assert!(options.set_compound_separator(" ").is_ok());
+        assert_eq!(
+            to_english("-105.25", &options).ok(),
+            Some("Minus One Hundred and Five Point Two Five".into())
+        );
+        assert_eq!(
+            to_english_ordinal("21", &options).ok(),
+            Some("Twenty First".into())
+        );
+
+        let mut options = EnglishOptions::new();
+        assert!(options.set_scale("long").is_ok());
+        assert!(options.set_rounding("truncate").is_ok());
+        options.set_max_decimal_places(Some(1));
+        assert_eq!(
+            to_english("1000000000.29", &options).ok(),
+            Some("one thousand million and two tenths".into())
+        );
+        assert_eq!(
+            to_english_currency("1.5", "eur", &options).ok(),
+            Some("one euro and fifty cents".into())
+        );
+    }
+}
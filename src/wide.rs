@@ -0,0 +1,88 @@
+use crate::{convert_number_to_english, Options};
+use alloc::string::{String, ToString};
+use num_bigint::BigInt;
+
+/// Convert an unsigned 256-bit integer, given as four 64-bit words from least to most significant,
+/// to English using the given [`Options`].
+///
+/// # Examples
+/// ```
+/// use num2english::{u256_to_english, Options};
+/// assert_eq!(
+///     u256_to_english([0, 1, 0, 0], &Options::default()),
+///     "eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four trillion \
+///      seventy-three billion seven hundred nine million five hundred fifty-one thousand six hundred sixteen"
+/// );
+/// ```
+///
+/// A `primitive_types::U256` stores its words in this order, and an `ethnum::U256` can be split into them:
+/// ```ignore
+/// let amount = primitive_types::U256::from(1_000_000u64);
+/// assert_eq!(u256_to_english(amount.0, &Options::default()), "one million");
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub fn u256_to_english(words: [u64; 4], options: &Options) -> String {
+    let number = words_to_big_int(&words);
+    options
+        .case
+        .apply(&convert_number_to_english(number.to_string(), options))
+}
+
+/// Convert a signed two's complement 256-bit integer, given as four 64-bit words from least to most
+/// significant, to English using the given [`Options`].
+///
+/// # Examples
+/// ```
+/// use num2english::{i256_to_english, Options};
+/// assert_eq!(i256_to_english([42, 0, 0, 0], &Options::default()), "forty-two");
+/// assert_eq!(
+///     i256_to_english([u64::MAX - 1, u64::MAX, u64::MAX, u64::MAX], &Options::default()),
+///     "negative two"
+/// );
+/// ```
+///
+/// [`Options`]: struct.Options.html
+pub fn i256_to_english(words: [u64; 4], options: &Options) -> String {
+    let mut number = words_to_big_int(&words);
+    if words[3] >> 63 == 1 {
+        number -= BigInt::from(1) << 256;
+    }
+    options
+        .case
+        .apply(&convert_number_to_english(number.to_string(), options))
+}
+
+/// Join 64-bit words from least to most significant into an unsigned integer.
+fn words_to_big_int(words: &[u64]) -> BigInt {
+    words
+        .iter()
+        .rev()
+        .fold(BigInt::from(0), |number, &word| (number << 64) + word)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{i256_to_english, u256_to_english, Options};
+    use alloc::format;
+
+    #[test]
+    fn test_wide() {
+        let options = Options::default();
+        assert_eq!(u256_to_english([0; 4], &options), "zero");
+        assert_eq!(
+            u256_to_english([1_000_000, 0, 0, 0], &options),
+            "one million"
+        );
+        let half = u256_to_english([0, 0, 0, 1 << 63], &options);
+        assert!(half.starts_with(
+            "fifty-seven quattuorvigintillion eight hundred ninety-six trevigintillion "
+        ));
+        assert!(half.ends_with(" eight hundred nineteen thousand nine hundred sixty-eight"));
+        assert_eq!(i256_to_english([u64::MAX; 4], &options), "negative one");
+        assert_eq!(
+            i256_to_english([0, 0, 0, 1 << 63], &options),
+            format!("negative {}", half)
+        );
+    }
+}
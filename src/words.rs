@@ -0,0 +1,160 @@
+use crate::{
+    scales::{DECIMALS, MAGNITUDES, ONE_TO_NINETEEN, TENS},
+    Options,
+};
+use alloc::{
+    borrow::Cow,
+    string::String,
+    vec::{self, Vec},
+};
+use core::fmt::{self, Write};
+
+/// An iterator over the words of a number's name in English, as returned by [`to_english_words`].
+///
+/// Words are split at spaces, so a compound like "twenty-three" is a single word. Words taken
+/// whole from the built-in tables or the [`Options`] (e.g. "seven", "hundred", "million") are
+/// borrowed rather than allocated.
+///
+/// # Examples
+/// ```
+/// use num2english::{NumberToEnglish, Options};
+/// let words = 1_200_007.to_english_words(&Options::default());
+/// assert_eq!(
+///     words.collect::<Vec<_>>(),
+///     ["one", "million", "two", "hundred", "thousand", "seven"]
+/// );
+/// ```
+///
+/// [`to_english_words`]: trait.NumberToEnglish.html#tymethod.to_english_words
+/// [`Options`]: struct.Options.html
+#[derive(Debug, Clone)]
+pub struct Words {
+    words: vec::IntoIter<Cow<'static, str>>,
+}
+
+impl Iterator for Words {
+    type Item = Cow<'static, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.words.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.words.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Words {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.words.next_back()
+    }
+}
+
+impl ExactSizeIterator for Words {}
+
+/// A sink that splits the text written to it into words.
+pub(crate) struct WordWriter<'a> {
+    options: &'a Options,
+    words: Vec<Cow<'static, str>>,
+    word: String,
+}
+
+impl<'a> WordWriter<'a> {
+    pub(crate) fn new(options: &'a Options) -> Self {
+        WordWriter {
+            options,
+            words: Vec::new(),
+            word: String::new(),
+        }
+    }
+
+    /// Finish the last word and iterate over the words.
+    pub(crate) fn finish(mut self) -> Words {
+        self.end_word();
+        Words {
+            words: self.words.into_iter(),
+        }
+    }
+
+    fn end_word(&mut self) {
+        if self.word.is_empty() {
+            return;
+        }
+        let word = match static_word(&self.word, self.options) {
+            Some(word) => Cow::Borrowed(word),
+            None => Cow::Owned(self.word.clone()),
+        };
+        self.words.push(word);
+        self.word.clear();
+    }
+}
+
+impl Write for WordWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, part) in s.split(' ').enumerate() {
+            if i > 0 {
+                self.end_word();
+            }
+            self.word.push_str(part);
+        }
+        Ok(())
+    }
+}
+
+/// Find a word in the built-in tables or the options, so it can be borrowed.
+fn static_word(word: &str, options: &Options) -> Option<&'static str> {
+    let words = [
+        options.negative_word,
+        options.zero_word,
+        "hundred",
+        "and",
+        "point",
+        "a",
+    ];
+    let tables = [&words[..], &ONE_TO_NINETEEN, &TENS, &DECIMALS];
+    if let Some(found) = tables
+        .iter()
+        .flat_map(|table| table.iter())
+        .find(|w| **w == word)
+    {
+        return Some(found);
+    }
+    // only a name of a power is worth looking for among the thousand magnitudes
+    if word.ends_with("illion") || word == "thousand" {
+        return MAGNITUDES.iter().find(|w| **w == word).copied();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Case, NumberToEnglish, Options, Words};
+    use alloc::{borrow::Cow, string::String, vec::Vec};
+
+    fn join(words: Words) -> String {
+        words.collect::<Vec<_>>().join(" ")
+    }
+
+    #[test]
+    fn test_words() {
+        let options = Options::default();
+        assert_eq!(0.to_english_words(&options).collect::<Vec<_>>(), ["zero"]);
+        for n in [-42.5, 1e21, 0.001, 987_654_321.0] {
+            assert_eq!(join(n.to_english_words(&options)), n.to_english());
+        }
+
+        let mut words = (-21.7).to_english_words(&options);
+        assert_eq!(words.len(), 5);
+        assert_eq!(words.next_back(), Some(Cow::Owned("tenths".into())));
+        assert!(words.all(|word| match word {
+            Cow::Borrowed(_) => true,
+            Cow::Owned(word) => word == "twenty-one",
+        }));
+
+        let title = Options {
+            case: Case::Title,
+            ..Default::default()
+        };
+        assert_eq!(join(1_005.to_english_words(&title)), "One Thousand Five");
+    }
+}
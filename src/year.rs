@@ -0,0 +1,54 @@
+use crate::{convert_hundreds_to_english, convert_number_to_english, Options};
+use alloc::string::String;
+
+/// Convert a number to its name as a year (e.g. 1984 -> "nineteen eighty-four")
+///
+/// Four-digit years are read as two pairs of digits, except for the first decade of a millennium
+/// (e.g. 2005 -> "two thousand five"). Any other number is read as a cardinal number.
+pub(crate) fn convert_year_to_english(number: String, options: &Options) -> String {
+    let year = match number.parse::<u16>() {
+        Ok(year) if (1000..10_000).contains(&year) => year,
+        _ => return convert_number_to_english(number, options),
+    };
+
+    let century = year / 100;
+    let rest = year % 100;
+
+    if century % 10 == 0 && rest < 10 {
+        return convert_number_to_english(number, options);
+    }
+
+    let mut result = convert_hundreds_to_english(u64::from(century), options);
+    result.push(' ');
+    match rest {
+        0 => result.push_str("hundred"),
+        1..=9 => {
+            result.push_str("oh ");
+            result.push_str(&convert_hundreds_to_english(u64::from(rest), options));
+        }
+        _ => result.push_str(&convert_hundreds_to_english(u64::from(rest), options)),
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NumberToEnglish;
+
+    #[test]
+    fn test_year() {
+        assert_eq!(1984.to_english_year(), "nineteen eighty-four");
+        assert_eq!(2005.to_english_year(), "two thousand five");
+        assert_eq!(2000.to_english_year(), "two thousand");
+        assert_eq!(1700.to_english_year(), "seventeen hundred");
+        assert_eq!(1905.to_english_year(), "nineteen oh five");
+        assert_eq!(2024.to_english_year(), "twenty twenty-four");
+        assert_eq!(1010.to_english_year(), "ten ten");
+        assert_eq!(476.to_english_year(), "four hundred seventy-six");
+        assert_eq!(
+            12_345.to_english_year(),
+            "twelve thousand three hundred forty-five"
+        );
+    }
+}